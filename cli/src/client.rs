@@ -0,0 +1,111 @@
+use anyhow::{ anyhow, Context, Result };
+use base64::{ engine::general_purpose::STANDARD, Engine };
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::config::RpcSimulateTransactionConfig;
+use solana_sdk::{
+    account::Account,
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{ Keypair, Signer },
+    transaction::Transaction,
+};
+
+// @dev rpc connection, fee payer and send mode shared by all commands
+pub struct Client {
+    pub rpc: RpcClient,
+    pub payer: Keypair,
+    pub dry_run: bool,
+}
+
+impl Client {
+    pub fn new(url: &str, payer: Keypair, dry_run: bool) -> Self {
+        Self {
+            rpc: RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed()),
+            payer,
+            dry_run,
+        }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    pub fn account(&self, address: &Pubkey) -> Result<Account> {
+        self.rpc.get_account(address).with_context(|| format!("failed to fetch account {}", address))
+    }
+
+    // @dev simulate a view instruction and return its return data
+    pub fn view(&self, instruction: Instruction) -> Result<Vec<u8>> {
+        let transaction: Transaction = Transaction::new_with_payer(&[instruction], Some(&self.payer()));
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            ..RpcSimulateTransactionConfig::default()
+        };
+        let result = self.rpc
+            .simulate_transaction_with_config(&transaction, config)
+            .context("failed to simulate transaction")?.value;
+        if let Some(err) = result.err {
+            for log in result.logs.unwrap_or_default() {
+                println!("  {}", log);
+            }
+            return Err(anyhow!("simulation failed: {}", err));
+        }
+        let return_data = result.return_data.ok_or_else(|| anyhow!("no return data"))?;
+        STANDARD.decode(&return_data.data.0).context("failed to decode return data")
+    }
+
+    // @dev sign and send, or simulate and print when dry run
+    pub fn send(&self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<()> {
+        let mut all_signers: Vec<&Keypair> = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        let blockhash = self.rpc.get_latest_blockhash().context("failed to fetch blockhash")?;
+        let transaction: Transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer()),
+            &all_signers,
+            blockhash
+        );
+
+        if self.dry_run {
+            print_instructions(instructions);
+            let result = self.rpc
+                .simulate_transaction(&transaction)
+                .context("failed to simulate transaction")?.value;
+            for log in result.logs.unwrap_or_default() {
+                println!("  {}", log);
+            }
+            match result.err {
+                Some(err) => println!("simulation failed: {}", err),
+                None => println!("simulation succeeded"),
+            }
+            return Ok(());
+        }
+
+        let signature = self.rpc
+            .send_and_confirm_transaction(&transaction)
+            .context("failed to send transaction")?;
+        println!("signature: {}", signature);
+        Ok(())
+    }
+}
+
+fn print_instructions(instructions: &[Instruction]) {
+    for (index, instruction) in instructions.iter().enumerate() {
+        println!("instruction {}: program {}", index, instruction.program_id);
+        for meta in &instruction.accounts {
+            println!(
+                "  {} signer={} writable={}",
+                meta.pubkey,
+                meta.is_signer,
+                meta.is_writable
+            );
+        }
+        let data: String = instruction.data
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        println!("  data {}", data);
+    }
+}
@@ -0,0 +1,795 @@
+use std::path::PathBuf;
+
+use anyhow::{ anyhow, Result };
+use clap::Subcommand;
+use paid_sdk::{
+    amm,
+    decode,
+    ignition::{ self, Proposer },
+    paid_amm,
+    paid_ignition::{ MetadataArgs, Milestone, ProposalKind, Tier, UnsoldPolicy },
+    pda,
+};
+use serde_json::json;
+use solana_sdk::{ instruction::Instruction, pubkey::Pubkey, signature::{ Keypair, Signer } };
+
+use crate::{ client::Client, read_keypair, spec::{ parse_content_hash, PoolSpec } };
+
+#[derive(Subcommand)]
+pub enum PoolCommand {
+    /// Create a pool from a toml or json spec
+    Create {
+        #[arg(long)]
+        spec: PathBuf,
+        /// Keypair of the new pool account, generated if omitted
+        #[arg(long)]
+        pool_keypair: Option<PathBuf>,
+    },
+    /// Fund the offer vault with ido token
+    Fund {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        amount: u64,
+        /// Ido token account of owner, associated account if omitted
+        #[arg(long)]
+        owner_token: Option<Pubkey>,
+    },
+    /// Update early and open pool close times
+    UpdateTime {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        early_pool_close_time: i64,
+        #[arg(long)]
+        open_pool_close_time: i64,
+    },
+    /// Update tge date
+    UpdateTge {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        tge_date: i64,
+        /// Approved extend tge proposal, required if the pool asks for one
+        #[arg(long)]
+        proposal: Option<Pubkey>,
+    },
+    /// Make tge date updates require an approved proposal, before the sale opens
+    TgeGovernance {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Omit to drop the requirement
+        #[arg(long)]
+        requires_proposal: bool,
+    },
+    /// Open a buyer proposal as pool owner or super admin, or a refund proposal as buyer
+    Propose {
+        #[arg(long)]
+        pool: Pubkey,
+        /// extend-tge:unix_time or refund-milestones
+        #[arg(long, value_parser = parse_proposal_kind)]
+        kind: ProposalKind,
+        /// Unix time voting ends, at least 3 days out
+        #[arg(long)]
+        deadline: i64,
+        /// Share of purchased amount that must vote, 10000 is 100%, at least 2000, 5000 for refunds
+        #[arg(long)]
+        quorum: u16,
+        /// Open as super admin instead of pool owner
+        #[arg(long)]
+        admin: bool,
+        /// Open as buyer of the pool, refund proposals only
+        #[arg(long, conflicts_with = "admin")]
+        buyer: bool,
+    },
+    /// Vote on a proposal, weighted by purchase of payer
+    Vote {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        proposal: Pubkey,
+        /// Vote against instead of for
+        #[arg(long)]
+        against: bool,
+    },
+    /// Record the outcome of a proposal after its deadline, anyone can run it
+    FinalizeProposal {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        proposal: Pubkey,
+    },
+    /// Allow buyers to claim ido token
+    EnableClaims {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Cancel the pool
+    Cancel {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Set share of raise seeded into the amm after close, zero turns seeding off
+    LiquidityConfig {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Share of raise, 10000 is 100%
+        #[arg(long)]
+        percentage: u16,
+        /// Seconds lp token stays locked
+        #[arg(long)]
+        lock_duration: i64,
+    },
+    /// Set post tge price protection, zero window and percentage turn it off
+    ProtectionConfig {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Seconds after tge buyers may return their allocation
+        #[arg(long)]
+        window: i64,
+        /// Share of raise reserved for refunds, 10000 is 100%
+        #[arg(long)]
+        percentage: u16,
+    },
+    /// Return the whole ido token allocation of payer for its purchase token
+    Protect {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Withdraw unused protection reserve after the window ends
+    ReclaimProtection {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Seed the amm with share of raise and matching ido token, amm pool is created if missing
+    SeedLiquidity {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Minimum lp token to accept
+        #[arg(long, default_value_t = 0)]
+        min_lp: u64,
+    },
+    /// Withdraw lp token after the lock ends
+    WithdrawLp {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Declare release schedule of the raise before the sale opens
+    DeclareMilestones {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Release time and share as unix_time:share, shares sum to 10000
+        #[arg(long = "milestone", value_parser = parse_milestone, num_args = 1.., required = true)]
+        milestones: Vec<Milestone>,
+    },
+    /// Set stake tiers of the early pool before registration starts, none turns tiers off
+    TierTable {
+        #[arg(long)]
+        pool: Pubkey,
+        /// paidStake pool whose stakers qualify
+        #[arg(long)]
+        stake_pool: Pubkey,
+        /// Stake threshold and weight as threshold:weight, ordered by threshold
+        #[arg(long = "tier", value_parser = parse_tier, num_args = 1..)]
+        tiers: Vec<Tier>,
+        /// Unix time stake is measured at, before the early pool opens
+        #[arg(long)]
+        snapshot_time: i64,
+        /// Require stake to be held from the snapshot until registration
+        #[arg(long)]
+        continuous_stake: bool,
+    },
+    /// Replace the display metadata of a pool before the early pool opens
+    Metadata {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        name: String,
+        #[arg(long, default_value = "")]
+        symbol: String,
+        /// Off-chain json with description, logo and links
+        #[arg(long, default_value = "")]
+        uri: String,
+        #[arg(long, default_value = "")]
+        website: String,
+        /// Hex sha256 of the json behind uri
+        #[arg(long, value_parser = parse_content_hash)]
+        content_hash: Option<[u8; 32]>,
+    },
+    /// Lock in the stake tier of payer before the early pool opens
+    RegisterTier {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Release the next due milestone of the raise to the owner
+    ReleaseMilestone {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Make the unreleased raise refundable after buyers approved a refund proposal
+    ApproveRefund {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        proposal: Pubkey,
+    },
+    /// Withdraw share of the unreleased raise after buyers approved refund
+    MilestoneRefund {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Carry out the unsold policy of a closed pool, anyone can run it
+    FinalizeUnsold {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Move what a finalized pool no longer owes anyone to the owner
+    Sweep {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Push vested ido token to buyers, paid from tip vault if funded
+    Crank {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Buyer wallets, those without an ido token associated account are skipped
+        #[arg(long, num_args = 1.., required = true)]
+        wallets: Vec<Pubkey>,
+    },
+    /// Deposit lamports to the tip vault and set tip per claim
+    FundTips {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Lamports paid to cranker per pushed claim
+        #[arg(long)]
+        tip_per_claim: u64,
+        /// Lamports to deposit
+        #[arg(long, default_value_t = 0)]
+        amount: u64,
+    },
+    /// Close the tip vault and reclaim unpaid tips
+    CloseTips {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Claim vested ido token for a buyer wallet, payer must be the wallet or its delegate
+    Claim {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Amount of ido token, up to the vested maximum
+        #[arg(long)]
+        amount: u64,
+        /// Wallet of buyer, payer if omitted
+        #[arg(long)]
+        wallet: Option<Pubkey>,
+    },
+    /// Set claim delegate and default destination wallet of payer, omitted ones are cleared
+    ClaimSettings {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        delegate: Option<Pubkey>,
+        #[arg(long)]
+        destination: Option<Pubkey>,
+    },
+    /// Print pool state
+    Show {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Quote a purchase at current time
+    Quote {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Purchase amount, based on purchase token
+        #[arg(long)]
+        amount: u64,
+        /// Wallet to quote per-wallet remaining amount for
+        #[arg(long)]
+        wallet: Option<Pubkey>,
+    },
+    /// Print buyer state
+    Buyer {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Wallet of buyer
+        #[arg(long)]
+        wallet: Pubkey,
+    },
+    /// Print milestone plan and refund state of pool
+    Milestones {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Print stake tiers and registration of pool
+    Tiers {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Print proposal state
+    Proposal {
+        #[arg(long)]
+        proposal: Pubkey,
+    },
+    /// Print claim history of buyer
+    Claims {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Wallet of buyer
+        #[arg(long)]
+        wallet: Pubkey,
+    },
+}
+
+pub fn run(client: &Client, command: PoolCommand) -> Result<()> {
+    let payer: Pubkey = client.payer();
+    match command {
+        PoolCommand::Create { spec, pool_keypair } => {
+            let spec: PoolSpec = PoolSpec::load(&spec)?;
+            let pool: Keypair = match pool_keypair {
+                Some(path) => read_keypair(&path)?,
+                None => Keypair::new(),
+            };
+            println!("pool: {}", pool.pubkey());
+            let instruction = ignition::create_pool(
+                &payer,
+                &pool.pubkey(),
+                &spec.purchase_mint()?,
+                &spec.offer_mint()?,
+                spec.params.to_uints(),
+                spec.unsold_policy(),
+                &spec.unsold_recipient()?,
+                spec.metadata.to_args()?
+            );
+            client.send(&[instruction], &[&pool])
+        }
+        PoolCommand::Fund { pool, amount, owner_token } => {
+            let offer_mint: Pubkey = decode::ignition_pool(&client.account(&pool)?.data)?
+                .offered_currency.mint;
+            let owner_token: Pubkey = owner_token.unwrap_or_else(||
+                anchor_spl::associated_token::get_associated_token_address(&payer, &offer_mint)
+            );
+            client.send(
+                &[ignition::fund_offer(&payer, &pool, &offer_mint, &owner_token, amount)],
+                &[]
+            )
+        }
+        PoolCommand::UpdateTime { pool, early_pool_close_time, open_pool_close_time } => {
+            let operator: bool = is_operator(client, &payer, &pool)?;
+            client.send(
+                &[
+                    ignition::update_time(
+                        &payer,
+                        &pool,
+                        early_pool_close_time,
+                        open_pool_close_time,
+                        operator
+                    ),
+                ],
+                &[]
+            )
+        }
+        PoolCommand::UpdateTge { pool, tge_date, proposal } => {
+            let operator: bool = is_operator(client, &payer, &pool)?;
+            client.send(
+                &[ignition::update_tge_date(&payer, &pool, tge_date, proposal.as_ref(), operator)],
+                &[]
+            )
+        }
+        PoolCommand::TgeGovernance { pool, requires_proposal } =>
+            client.send(&[ignition::set_tge_governance(&payer, &pool, requires_proposal)], &[]),
+        PoolCommand::Propose { pool, kind, deadline, quorum, admin, buyer } => {
+            let role: Proposer = match (admin, buyer) {
+                (true, _) => Proposer::SuperAdmin,
+                (_, true) => Proposer::Buyer,
+                _ => Proposer::Owner,
+            };
+            let index: u32 = decode::ignition_pool(&client.account(&pool)?.data)?.proposal_count;
+            println!("proposal: {}", pda::proposal(&pool, index).0);
+            client.send(
+                &[ignition::create_proposal(&payer, &pool, index, role, kind, deadline, quorum)],
+                &[]
+            )
+        }
+        PoolCommand::Vote { pool, proposal, against } =>
+            client.send(&[ignition::cast_vote(&payer, &pool, &proposal, !against)], &[]),
+        PoolCommand::FinalizeProposal { pool, proposal } =>
+            client.send(&[ignition::finalize_proposal(&pool, &proposal)], &[]),
+        PoolCommand::EnableClaims { pool } => {
+            let operator: bool = is_operator(client, &payer, &pool)?;
+            client.send(&[ignition::enable_claims(&payer, &pool, operator)], &[])
+        }
+        PoolCommand::Cancel { pool } => {
+            let operator: bool = is_operator(client, &payer, &pool)?;
+            client.send(&[ignition::cancel_pool(&payer, &pool, operator)], &[])
+        }
+        PoolCommand::LiquidityConfig { pool, percentage, lock_duration } => {
+            let amm_program: Pubkey = if percentage == 0 { Pubkey::default() } else { paid_amm::ID };
+            client.send(
+                &[
+                    ignition::set_liquidity_config(
+                        &payer,
+                        &pool,
+                        percentage,
+                        &amm_program,
+                        lock_duration
+                    ),
+                ],
+                &[]
+            )
+        }
+        PoolCommand::ProtectionConfig { pool, window, percentage } =>
+            client.send(
+                &[ignition::set_protection_config(&payer, &pool, window, percentage)],
+                &[]
+            ),
+        PoolCommand::Protect { pool } => {
+            let purchase_mint: Pubkey = decode::ignition_pool(&client.account(&pool)?.data)?
+                .purchase_currency.mint;
+            client.send(&[ignition::claim_price_protection(&payer, &pool, &purchase_mint)], &[])
+        }
+        PoolCommand::ReclaimProtection { pool } => {
+            let purchase_mint: Pubkey = decode::ignition_pool(&client.account(&pool)?.data)?
+                .purchase_currency.mint;
+            client.send(
+                &[ignition::reclaim_protection_reserve(&payer, &pool, &purchase_mint)],
+                &[]
+            )
+        }
+        PoolCommand::SeedLiquidity { pool, min_lp } => {
+            let account = decode::ignition_pool(&client.account(&pool)?.data)?;
+            let purchase_mint: Pubkey = account.purchase_currency.mint;
+            let offer_mint: Pubkey = account.offered_currency.mint;
+            let mut instructions: Vec<Instruction> = vec![];
+            if client.account(&pda::amm_pool(&purchase_mint, &offer_mint).0).is_err() {
+                instructions.push(amm::create_pool(&payer, &purchase_mint, &offer_mint));
+            }
+            instructions.push(
+                ignition::seed_liquidity(&payer, &pool, &purchase_mint, &offer_mint, min_lp)
+            );
+            client.send(&instructions, &[])
+        }
+        PoolCommand::WithdrawLp { pool } => {
+            let lp_mint: Pubkey = decode::lp_lock(&client.account(&pda::lp_lock(&pool).0)?.data)?
+                .lp_mint;
+            client.send(&[ignition::withdraw_locked_lp(&payer, &pool, &lp_mint)], &[])
+        }
+        PoolCommand::DeclareMilestones { pool, milestones } =>
+            client.send(&[ignition::declare_milestones(&payer, &pool, milestones)], &[]),
+        PoolCommand::TierTable { pool, stake_pool, tiers, snapshot_time, continuous_stake } =>
+            client.send(
+                &[
+                    ignition::set_tier_table(
+                        &payer,
+                        &pool,
+                        &stake_pool,
+                        tiers,
+                        snapshot_time,
+                        continuous_stake
+                    ),
+                ],
+                &[]
+            ),
+        PoolCommand::Metadata { pool, name, symbol, uri, website, content_hash } => {
+            let metadata = MetadataArgs {
+                name,
+                symbol,
+                uri,
+                website,
+                content_hash: content_hash.unwrap_or_default(),
+            };
+            client.send(&[ignition::update_pool_metadata(&payer, &pool, metadata)], &[])
+        }
+        PoolCommand::RegisterTier { pool } => {
+            let stake_pool: Pubkey = decode::tier_table(
+                &client.account(&pda::tier_table(&pool).0)?.data
+            )?.stake_pool;
+            let checkpoints: bool = client
+                .account(&pda::stake_checkpoints(&stake_pool, &payer).0)
+                .is_ok();
+            client.send(&[ignition::register_tier(&payer, &pool, &stake_pool, checkpoints)], &[])
+        }
+        PoolCommand::ReleaseMilestone { pool } => {
+            let purchase_mint: Pubkey = decode::ignition_pool(&client.account(&pool)?.data)?
+                .purchase_currency.mint;
+            client.send(&[ignition::release_milestone(&payer, &pool, &purchase_mint)], &[])
+        }
+        PoolCommand::ApproveRefund { pool, proposal } =>
+            client.send(&[ignition::approve_refund(&payer, &pool, &proposal)], &[]),
+        PoolCommand::MilestoneRefund { pool } => {
+            let purchase_mint: Pubkey = decode::ignition_pool(&client.account(&pool)?.data)?
+                .purchase_currency.mint;
+            client.send(&[ignition::claim_milestone_refund(&payer, &pool, &purchase_mint)], &[])
+        }
+        PoolCommand::FinalizeUnsold { pool } => {
+            let account = decode::ignition_pool(&client.account(&pool)?.data)?;
+            let offer_mint: Pubkey = account.offered_currency.mint;
+            // burned unsold token needs no destination
+            let destination: Option<Pubkey> = (account.unsold_policy != UnsoldPolicy::Burn).then(||
+                anchor_spl::associated_token::get_associated_token_address(
+                    &account.unsold_destination(),
+                    &offer_mint
+                )
+            );
+            client.send(
+                &[ignition::finalize_unsold(&pool, &offer_mint, destination.as_ref())],
+                &[]
+            )
+        }
+        PoolCommand::Sweep { pool } => {
+            let account = decode::ignition_pool(&client.account(&pool)?.data)?;
+            let milestone_plan: bool = client.account(&pda::milestone_plan(&pool).0).is_ok();
+            client.send(
+                &[
+                    ignition::sweep_vaults(
+                        &payer,
+                        &pool,
+                        &account.purchase_currency.mint,
+                        &account.offered_currency.mint,
+                        milestone_plan
+                    ),
+                ],
+                &[]
+            )
+        }
+        PoolCommand::Crank { pool, wallets } => {
+            let offer_mint: Pubkey = decode::ignition_pool(&client.account(&pool)?.data)?
+                .offered_currency.mint;
+            let tip_vault: bool = client.account(&pda::tip_vault(&pool).0).is_ok();
+            client.send(
+                &[ignition::crank_claims(&payer, &pool, &offer_mint, &wallets, tip_vault)],
+                &[]
+            )
+        }
+        PoolCommand::FundTips { pool, tip_per_claim, amount } =>
+            client.send(&[ignition::fund_tip_vault(&payer, &pool, tip_per_claim, amount)], &[]),
+        PoolCommand::CloseTips { pool } =>
+            client.send(&[ignition::close_tip_vault(&payer, &pool)], &[]),
+        PoolCommand::Claim { pool, amount, wallet } => {
+            let wallet: Pubkey = wallet.unwrap_or(payer);
+            let offer_mint: Pubkey = decode::ignition_pool(&client.account(&pool)?.data)?
+                .offered_currency.mint;
+            let destination: Pubkey = decode::buyer(
+                &client.account(&pda::buyer(&pool, &wallet).0)?.data
+            )?.claim_destination();
+            client.send(
+                &[ignition::claim_offer(&payer, &wallet, &destination, &pool, &offer_mint, amount)],
+                &[]
+            )
+        }
+        PoolCommand::ClaimSettings { pool, delegate, destination } =>
+            client.send(
+                &[
+                    ignition::set_claim_settings(
+                        &payer,
+                        &pool,
+                        &delegate.unwrap_or_default(),
+                        &destination.unwrap_or_default()
+                    ),
+                ],
+                &[]
+            ),
+        PoolCommand::Show { pool } => show_pool(client, &pool),
+        PoolCommand::Quote { pool, amount, wallet } => show_quote(client, &pool, amount, wallet.as_ref()),
+        PoolCommand::Buyer { pool, wallet } => show_buyer(client, &pool, &wallet),
+        PoolCommand::Milestones { pool } => show_milestones(client, &pool),
+        PoolCommand::Tiers { pool } => show_tiers(client, &pool),
+        PoolCommand::Proposal { proposal } => show_proposal(client, &proposal),
+        PoolCommand::Claims { pool, wallet } => show_claims(client, &pool, &wallet),
+    }
+}
+
+// @dev payer signs through its pool operator role unless it owns the pool
+fn is_operator(client: &Client, payer: &Pubkey, pool: &Pubkey) -> Result<bool> {
+    Ok(decode::ignition_pool(&client.account(pool)?.data)?.owner != *payer)
+}
+
+fn parse_milestone(value: &str) -> Result<Milestone> {
+    let (release_time, share) = value
+        .split_once(':')
+        .ok_or_else(|| anyhow!("expected unix_time:share, got {value}"))?;
+    Ok(Milestone { release_time: release_time.parse()?, share: share.parse()? })
+}
+
+fn parse_tier(value: &str) -> Result<Tier> {
+    let (threshold, weight) = value
+        .split_once(':')
+        .ok_or_else(|| anyhow!("expected threshold:weight, got {value}"))?;
+    Ok(Tier { threshold: threshold.parse()?, weight: weight.parse()? })
+}
+
+fn parse_proposal_kind(value: &str) -> Result<ProposalKind> {
+    if value == "refund-milestones" {
+        return Ok(ProposalKind::RefundMilestones);
+    }
+    match value.split_once(':') {
+        Some(("extend-tge", tge_date)) => Ok(ProposalKind::ExtendTge { tge_date: tge_date.parse()? }),
+        _ => Err(anyhow!("unknown proposal kind {value}")),
+    }
+}
+
+fn show_pool(client: &Client, address: &Pubkey) -> Result<()> {
+    let pool = decode::ignition_pool(&client.account(address)?.data)?;
+    let metadata = client
+        .account(&pda::pool_metadata(address).0)
+        .ok()
+        .and_then(|account| decode::pool_metadata(&account.data).ok())
+        .map(|metadata| json!({
+            "name": metadata.name,
+            "symbol": metadata.symbol,
+            "uri": metadata.uri,
+            "website": metadata.website,
+            "content_hash": metadata.content_hash
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>(),
+        }));
+    let view = json!({
+        "address": address.to_string(),
+        "metadata": metadata,
+        "owner": pool.owner.to_string(),
+        "pending_owner": pool.pending_owner.to_string(),
+        "status": format!("{:?}", pool.status),
+        "paused": pool.paused,
+        "purchase_mint": pool.purchase_currency.mint.to_string(),
+        "offer_mint": pool.offered_currency.mint.to_string(),
+        "unsold_policy": format!("{:?}", pool.unsold_policy),
+        "unsold_recipient": pool.unsold_recipient.to_string(),
+        "unsold_finalized_amount": pool.unsold_finalized_amount,
+        "liquidity_percentage": pool.liquidity_percentage,
+        "liquidity_amm_program": pool.liquidity_amm_program.to_string(),
+        "lp_lock_duration": pool.lp_lock_duration,
+        "liquidity_seeded": pool.liquidity_seeded,
+        "proposal_count": pool.proposal_count,
+        "tge_update_requires_proposal": pool.tge_update_requires_proposal,
+        "protection_window": pool.protection_window,
+        "protection_percentage": pool.protection_percentage,
+        "protection_reserve": pool.protection_reserve(),
+        "protected_amount": pool.protected_amount,
+        "protection_reserve_reclaimed": pool.protection_reserve_reclaimed,
+        "tiered": pool.tiered,
+        "rate": pool.offered_currency.rate,
+        "total_raise_amount": pool.total_raise_amount,
+        "purchased_amount": pool.purchased_amount,
+        "purchased_amount_in_early_access": pool.purchased_amount_in_early_access,
+        "purchased_amount_in_open_pool": pool.purchased_amount_in_open_pool,
+        "total_funded_amount": pool.total_funded_amount,
+        "token_fee_percentage": pool.token_fee_percentage,
+        "early_pool_participation_fee_percentage": pool.early_pool_participation_fee_percentage,
+        "open_pool_participation_fee_percentage": pool.open_pool_participation_fee_percentage,
+        "early_pool_open_time": pool.early_pool_open_time,
+        "open_pool_open_time": pool.open_pool_open_time,
+        "open_pool_close_time": pool.open_pool_close_time,
+        "tge_date": pool.tge_date,
+        "original_tge_date": pool.original_tge_date,
+        "tge_update_attempts": pool.tge_update_attempts,
+        "tge_percentage": pool.tge_percentage,
+        "vesting_cliff": pool.vesting_cliff,
+        "vesting_frequency": pool.vesting_frequency,
+        "number_of_vesting": pool.number_of_vesting,
+        "purchase_vault": pda::purchase_vault(address).0.to_string(),
+        "offer_vault": pda::offer_vault(address).0.to_string(),
+    });
+    println!("{}", serde_json::to_string_pretty(&view)?);
+    Ok(())
+}
+
+fn show_quote(client: &Client, pool: &Pubkey, amount: u64, wallet: Option<&Pubkey>) -> Result<()> {
+    let exists = |address: Pubkey| client.account(&address).is_ok();
+    let bought: bool = wallet.is_some_and(|wallet| exists(pda::buyer(pool, wallet).0));
+    let registered: bool = wallet.is_some_and(|wallet| exists(pda::tier_registration(pool, wallet).0));
+    let quote = decode::purchase_quote(
+        &client.view(ignition::quote_purchase(pool, amount, wallet, bought, registered))?
+    )?;
+    let view = json!({
+        "status": format!("{:?}", quote.status),
+        "purchase_amount": quote.purchase_amount,
+        "participant_fee": quote.participant_fee,
+        "offered_amount": quote.offered_amount,
+        "remaining_amount": quote.remaining_amount,
+        "wallet_remaining_amount": quote.wallet_remaining_amount,
+    });
+    println!("{}", serde_json::to_string_pretty(&view)?);
+    Ok(())
+}
+
+fn show_buyer(client: &Client, pool_address: &Pubkey, wallet: &Pubkey) -> Result<()> {
+    let address: Pubkey = pda::buyer(pool_address, wallet).0;
+    let buyer = decode::buyer(&client.account(&address)?.data)?;
+    let quote = decode::claim_quote(&client.view(ignition::quote_claimable(pool_address, wallet))?)?;
+    let view = json!({
+        "address": address.to_string(),
+        "wallet": wallet.to_string(),
+        "total_purchase": buyer.total_purchase,
+        "early_purchased": buyer.early_purchased,
+        "total_amount": buyer.total_amount,
+        "claimed_amount": buyer.cliamed_amount,
+        "last_purchase_time": buyer.last_purchase_time,
+        "cancellable_purchase": buyer.cancellable_purchase,
+        "claimable_amount": quote.claimable_amount,
+        "delegate": buyer.delegate.to_string(),
+        "claim_destination": buyer.claim_destination().to_string(),
+    });
+    println!("{}", serde_json::to_string_pretty(&view)?);
+    Ok(())
+}
+
+fn show_claims(client: &Client, pool_address: &Pubkey, wallet: &Pubkey) -> Result<()> {
+    let address: Pubkey = pda::claim_history(pool_address, wallet).0;
+    let history = decode::claim_history(&client.account(&address)?.data)?;
+    let claims: Vec<_> = history.claims
+        .iter()
+        .map(|claim| json!({ "claimed_at": claim.claimed_at, "amount": claim.amount }))
+        .collect();
+    let view = json!({
+        "address": address.to_string(),
+        "wallet": wallet.to_string(),
+        "claims": claims,
+    });
+    println!("{}", serde_json::to_string_pretty(&view)?);
+    Ok(())
+}
+
+fn show_milestones(client: &Client, pool: &Pubkey) -> Result<()> {
+    let address: Pubkey = pda::milestone_plan(pool).0;
+    let plan = decode::milestone_plan(&client.account(&address)?.data)?;
+    let milestones: Vec<_> = plan.milestones
+        .iter()
+        .map(|milestone| json!({ "release_time": milestone.release_time, "share": milestone.share }))
+        .collect();
+    let view = json!({
+        "address": address.to_string(),
+        "milestones": milestones,
+        "released_count": plan.released_count,
+        "released_amount": plan.released_amount,
+        "refund_passed": plan.refund_passed,
+        "refundable_amount": plan.refundable_amount,
+        "refunded_amount": plan.refunded_amount,
+    });
+    println!("{}", serde_json::to_string_pretty(&view)?);
+    Ok(())
+}
+
+fn show_proposal(client: &Client, address: &Pubkey) -> Result<()> {
+    let proposal = decode::proposal(&client.account(address)?.data)?;
+    let view = json!({
+        "address": address.to_string(),
+        "pool": proposal.pool.to_string(),
+        "index": proposal.index,
+        "kind": format!("{:?}", proposal.kind),
+        "proposer": proposal.proposer.to_string(),
+        "deadline": proposal.deadline,
+        "quorum": proposal.quorum,
+        "votes_for": proposal.votes_for,
+        "votes_against": proposal.votes_against,
+        "status": format!("{:?}", proposal.status),
+        "executed": proposal.executed,
+    });
+    println!("{}", serde_json::to_string_pretty(&view)?);
+    Ok(())
+}
+
+fn show_tiers(client: &Client, pool: &Pubkey) -> Result<()> {
+    let address: Pubkey = pda::tier_table(pool).0;
+    let table = decode::tier_table(&client.account(&address)?.data)?;
+    let early_pool_total: u64 = decode::ignition_pool(&client.account(pool)?.data)?.early_pool_total();
+    let tiers: Vec<_> = table.tiers
+        .iter()
+        .map(|tier| {
+            json!({
+                "threshold": tier.threshold,
+                "weight": tier.weight,
+                "allocation": table.allocation(early_pool_total, tier.weight),
+            })
+        })
+        .collect();
+    let view = json!({
+        "address": address.to_string(),
+        "stake_pool": table.stake_pool.to_string(),
+        "tiers": tiers,
+        "total_registered_weight": table.total_registered_weight,
+        "registered_count": table.registered_count,
+    });
+    println!("{}", serde_json::to_string_pretty(&view)?);
+    Ok(())
+}
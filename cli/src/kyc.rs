@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Subcommand;
+use paid_sdk::kyc;
+use solana_sdk::{ pubkey::Pubkey, signature::{ Keypair, Signer }, system_instruction };
+
+use crate::{ client::Client, read_keypair };
+
+#[derive(Subcommand)]
+pub enum KycCommand {
+    /// Allocate and initialize a kyc merkle tree
+    CreateTree {
+        #[arg(long, default_value_t = 14)]
+        max_depth: u32,
+        #[arg(long, default_value_t = 64)]
+        max_buffer_size: u32,
+        /// Keypair of the new tree account, generated if omitted
+        #[arg(long)]
+        tree_keypair: Option<PathBuf>,
+    },
+    /// Append a leaf to a kyc merkle tree
+    Append {
+        #[arg(long)]
+        tree: Pubkey,
+        #[arg(long)]
+        note: String,
+    },
+}
+
+pub fn run(client: &Client, command: KycCommand) -> Result<()> {
+    let payer: Pubkey = client.payer();
+    match command {
+        KycCommand::CreateTree { max_depth, max_buffer_size, tree_keypair } => {
+            let tree: Keypair = match tree_keypair {
+                Some(path) => read_keypair(&path)?,
+                None => Keypair::new(),
+            };
+            println!("tree: {}", tree.pubkey());
+            let space: usize = kyc::merkle_tree_account_size(max_depth, max_buffer_size);
+            let lamports: u64 = client.rpc.get_minimum_balance_for_rent_exemption(space)?;
+            let allocate = system_instruction::create_account(
+                &payer,
+                &tree.pubkey(),
+                lamports,
+                space as u64,
+                &spl_account_compression::ID
+            );
+            let create = kyc::create_note_tree(&payer, &tree.pubkey(), max_depth, max_buffer_size);
+            client.send(&[allocate, create], &[&tree])
+        }
+        KycCommand::Append { tree, note } =>
+            client.send(&[kyc::append_note(&payer, &tree, note)], &[]),
+    }
+}
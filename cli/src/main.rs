@@ -0,0 +1,72 @@
+// @dev json! view of pool state outgrows the default limit
+#![recursion_limit = "256"]
+
+use std::path::{ Path, PathBuf };
+
+use anyhow::{ anyhow, Result };
+use clap::{ Parser, Subcommand };
+use solana_sdk::signature::{ read_keypair_file, Keypair };
+
+mod client;
+mod ignition;
+mod kyc;
+mod project;
+mod spec;
+mod stake;
+
+// @dev operator cli for launchpad, staking and kyc trees
+#[derive(Parser)]
+#[command(name = "paid", version)]
+struct Cli {
+    /// Rpc url, local validator by default
+    #[arg(long, global = true, env = "PAID_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+    /// Fee payer and signer, solana cli keypair by default
+    #[arg(long, global = true, env = "PAID_KEYPAIR")]
+    keypair: Option<PathBuf>,
+    /// Simulate and print transactions instead of sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Launchpad pool administration
+    #[command(subcommand)]
+    Pool(ignition::PoolCommand),
+    /// Multi round project administration
+    #[command(subcommand)]
+    Project(project::ProjectCommand),
+    /// Stake pool administration
+    #[command(subcommand)]
+    Stake(stake::StakeCommand),
+    /// Kyc merkle trees
+    #[command(subcommand)]
+    Kyc(kyc::KycCommand),
+}
+
+pub fn read_keypair(path: &Path) -> Result<Keypair> {
+    read_keypair_file(path).map_err(|err| anyhow!("failed to read keypair {}: {}", path.display(), err))
+}
+
+fn default_keypair() -> Result<PathBuf> {
+    let home: String = std::env::var("HOME").map_err(|_| anyhow!("HOME is not set, pass --keypair"))?;
+    Ok(Path::new(&home).join(".config/solana/id.json"))
+}
+
+fn main() -> Result<()> {
+    let cli: Cli = Cli::parse();
+    let keypair_path: PathBuf = match cli.keypair {
+        Some(path) => path,
+        None => default_keypair()?,
+    };
+    let client: client::Client = client::Client::new(&cli.url, read_keypair(&keypair_path)?, cli.dry_run);
+    match cli.command {
+        Command::Pool(command) => ignition::run(&client, command),
+        Command::Project(command) => project::run(&client, command),
+        Command::Stake(command) => stake::run(&client, command),
+        Command::Kyc(command) => kyc::run(&client, command),
+    }
+}
@@ -0,0 +1,159 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Subcommand;
+use paid_sdk::{ decode, ignition, paid_ignition::Project, pda };
+use serde_json::json;
+use solana_sdk::{ pubkey::Pubkey, signature::{ Keypair, Signer } };
+
+use crate::{ client::Client, read_keypair, spec::RoundSpec };
+
+#[derive(Subcommand)]
+pub enum ProjectCommand {
+    /// Create a project whose rounds share one offer vault
+    Create {
+        #[arg(long)]
+        purchase_mint: Pubkey,
+        #[arg(long)]
+        offer_mint: Pubkey,
+        /// Keypair of the new project account, generated if omitted
+        #[arg(long)]
+        project_keypair: Option<PathBuf>,
+    },
+    /// Append a round from a toml or json spec, opening after the last round closes
+    AddRound {
+        #[arg(long)]
+        project: Pubkey,
+        #[arg(long)]
+        spec: PathBuf,
+    },
+    /// Fund the shared offer vault with ido token
+    Fund {
+        #[arg(long)]
+        project: Pubkey,
+        #[arg(long)]
+        amount: u64,
+        /// Ido token account of owner, associated account if omitted
+        #[arg(long)]
+        owner_token: Option<Pubkey>,
+    },
+    /// Claim vested ido token of payer from a round
+    Claim {
+        #[arg(long)]
+        project: Pubkey,
+        #[arg(long)]
+        round: u8,
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Withdraw the raise of a closed round to the owner
+    WithdrawRaise {
+        #[arg(long)]
+        project: Pubkey,
+        #[arg(long)]
+        round: u8,
+    },
+    /// Withdraw unsold ido token once the last round closed
+    WithdrawOffer {
+        #[arg(long)]
+        project: Pubkey,
+    },
+    /// Print project state and its rounds
+    Show {
+        #[arg(long)]
+        project: Pubkey,
+    },
+}
+
+pub fn run(client: &Client, command: ProjectCommand) -> Result<()> {
+    let payer: Pubkey = client.payer();
+    match command {
+        ProjectCommand::Create { purchase_mint, offer_mint, project_keypair } => {
+            let project: Keypair = match project_keypair {
+                Some(path) => read_keypair(&path)?,
+                None => Keypair::new(),
+            };
+            println!("project: {}", project.pubkey());
+            let instruction = ignition::create_project(
+                &payer,
+                &project.pubkey(),
+                &purchase_mint,
+                &offer_mint
+            );
+            client.send(&[instruction], &[&project])
+        }
+        ProjectCommand::AddRound { project, spec } => {
+            let params = RoundSpec::load(&spec)?.to_params()?;
+            client.send(&[ignition::add_round(&payer, &project, params)], &[])
+        }
+        ProjectCommand::Fund { project, amount, owner_token } => {
+            let offer_mint: Pubkey = fetch(client, &project)?.offer_mint;
+            let owner_token: Pubkey = owner_token.unwrap_or_else(||
+                anchor_spl::associated_token::get_associated_token_address(&payer, &offer_mint)
+            );
+            client.send(
+                &[ignition::fund_project(&payer, &project, &offer_mint, &owner_token, amount)],
+                &[]
+            )
+        }
+        ProjectCommand::Claim { project, round, amount } => {
+            let offer_mint: Pubkey = fetch(client, &project)?.offer_mint;
+            client.send(
+                &[ignition::claim_round_offer(&payer, &project, &offer_mint, round, amount)],
+                &[]
+            )
+        }
+        ProjectCommand::WithdrawRaise { project, round } => {
+            let purchase_mint: Pubkey = fetch(client, &project)?.purchase_mint;
+            client.send(
+                &[ignition::withdraw_round_raise(&payer, &project, &purchase_mint, round)],
+                &[]
+            )
+        }
+        ProjectCommand::WithdrawOffer { project } => {
+            let offer_mint: Pubkey = fetch(client, &project)?.offer_mint;
+            client.send(&[ignition::withdraw_project_offer(&payer, &project, &offer_mint)], &[])
+        }
+        ProjectCommand::Show { project } => show_project(client, &project),
+    }
+}
+
+fn fetch(client: &Client, project: &Pubkey) -> Result<Project> {
+    Ok(decode::project(&client.account(project)?.data)?)
+}
+
+fn show_project(client: &Client, address: &Pubkey) -> Result<()> {
+    let project: Project = fetch(client, address)?;
+    let rounds: Vec<_> = project.rounds
+        .iter()
+        .map(|round| json!({
+            "rate": round.rate,
+            "max_raise_amount": round.max_raise_amount,
+            "max_purchase_per_wallet": round.max_purchase_per_wallet,
+            "open_time": round.open_time,
+            "close_time": round.close_time,
+            "eligibility": format!("{:?}", round.eligibility),
+            "tge_date": round.vesting.tge_date,
+            "tge_percentage": round.vesting.tge_percentage,
+            "vesting_cliff": round.vesting.vesting_cliff,
+            "vesting_frequency": round.vesting.vesting_frequency,
+            "number_of_vesting": round.vesting.number_of_vesting,
+            "purchased_amount": round.purchased_amount,
+            "raise_withdrawn": round.raise_withdrawn,
+        }))
+        .collect();
+    let view = json!({
+        "address": address.to_string(),
+        "owner": project.owner.to_string(),
+        "purchase_mint": project.purchase_mint.to_string(),
+        "offer_mint": project.offer_mint.to_string(),
+        "total_funded_amount": project.total_funded_amount,
+        "sold_offer_amount": project.sold_offer_amount,
+        "excess_offer_amount": project.excess_offer_amount(),
+        "offer_vault": pda::project_offer_vault(address).0.to_string(),
+        "purchase_vault": pda::project_purchase_vault(address).0.to_string(),
+        "rounds": rounds,
+    });
+    println!("{}", serde_json::to_string_pretty(&view)?);
+    Ok(())
+}
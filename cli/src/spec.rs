@@ -0,0 +1,201 @@
+use std::{ fs, path::Path, str::FromStr };
+
+use anyhow::{ bail, Context, Result };
+use paid_sdk::{
+    allowlist,
+    ignition::PoolParams,
+    paid_ignition::{ Eligibility, MetadataArgs, RoundParams, UnsoldPolicy, Vesting },
+};
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+
+// @dev pool definition read from toml or json
+#[derive(Debug, Deserialize)]
+pub struct PoolSpec {
+    pub purchase_mint: String,
+    pub offer_mint: String,
+    #[serde(flatten)]
+    pub params: PoolParams,
+    // @dev return, burn or send, unsold ido token goes back to creator if omitted
+    #[serde(default)]
+    pub unsold_policy: UnsoldPolicySpec,
+    // @dev required if unsold ido token is sent
+    pub unsold_recipient: Option<String>,
+    // @dev display metadata stored on chain with the pool
+    pub metadata: MetadataSpec,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MetadataSpec {
+    pub name: String,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub uri: String,
+    #[serde(default)]
+    pub website: String,
+    // @dev hex sha256 of the json behind uri, zero if omitted
+    pub content_hash: Option<String>,
+}
+
+impl MetadataSpec {
+    pub fn to_args(&self) -> Result<MetadataArgs> {
+        Ok(MetadataArgs {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone(),
+            website: self.website.clone(),
+            content_hash: match &self.content_hash {
+                Some(hash) => parse_content_hash(hash)?,
+                None => [0; 32],
+            },
+        })
+    }
+}
+
+// @dev 64 hex characters
+pub fn parse_content_hash(value: &str) -> Result<[u8; 32]> {
+    if value.len() != 64 || !value.is_ascii() {
+        bail!("content hash must be 64 hex characters");
+    }
+    let mut hash: [u8; 32] = [0; 32];
+    for (index, byte) in hash.iter_mut().enumerate() {
+        *byte = u8
+            ::from_str_radix(&value[index * 2..index * 2 + 2], 16)
+            .context("content hash must be 64 hex characters")?;
+    }
+    Ok(hash)
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnsoldPolicySpec {
+    #[default]
+    Return,
+    Burn,
+    Send,
+}
+
+impl PoolSpec {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content: String = fs
+            ::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => toml::from_str(&content).context("invalid toml pool spec"),
+            Some("json") => serde_json::from_str(&content).context("invalid json pool spec"),
+            _ => bail!("pool spec must be a .toml or .json file"),
+        }
+    }
+
+    pub fn purchase_mint(&self) -> Result<Pubkey> {
+        Pubkey::from_str(&self.purchase_mint).context("invalid purchase_mint")
+    }
+
+    pub fn offer_mint(&self) -> Result<Pubkey> {
+        Pubkey::from_str(&self.offer_mint).context("invalid offer_mint")
+    }
+
+    pub fn unsold_policy(&self) -> UnsoldPolicy {
+        match self.unsold_policy {
+            UnsoldPolicySpec::Return => UnsoldPolicy::ReturnToCreator,
+            UnsoldPolicySpec::Burn => UnsoldPolicy::Burn,
+            UnsoldPolicySpec::Send => UnsoldPolicy::SendToRecipient,
+        }
+    }
+
+    pub fn unsold_recipient(&self) -> Result<Pubkey> {
+        match &self.unsold_recipient {
+            Some(recipient) => Pubkey::from_str(recipient).context("invalid unsold_recipient"),
+            None => Ok(Pubkey::default()),
+        }
+    }
+}
+
+// @dev round definition read from toml or json
+#[derive(Debug, Deserialize)]
+pub struct RoundSpec {
+    // @dev amount of ido token for 1 purchase token
+    pub rate: u64,
+    pub max_raise_amount: u64,
+    // @dev uncapped if omitted
+    #[serde(default)]
+    pub max_purchase_per_wallet: u64,
+    pub open_time: i64,
+    pub close_time: i64,
+    pub eligibility: EligibilitySpec,
+    pub tge_date: i64,
+    pub tge_percentage: u16,
+    pub vesting_cliff: i64,
+    pub vesting_frequency: i64,
+    pub number_of_vesting: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "mode", rename_all = "lowercase")]
+pub enum EligibilitySpec {
+    Public,
+    Stake {
+        stake_pool: String,
+        min_stake: u64,
+    },
+    Kyc {
+        merkle_tree: String,
+        authority: String,
+    },
+    // @dev root is computed from the listed wallets
+    Allowlist {
+        wallets: Vec<String>,
+    },
+}
+
+impl RoundSpec {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content: String = fs
+            ::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => toml::from_str(&content).context("invalid toml round spec"),
+            Some("json") => serde_json::from_str(&content).context("invalid json round spec"),
+            _ => bail!("round spec must be a .toml or .json file"),
+        }
+    }
+
+    pub fn to_params(&self) -> Result<RoundParams> {
+        let eligibility: Eligibility = match &self.eligibility {
+            EligibilitySpec::Public => Eligibility::Public,
+            EligibilitySpec::Stake { stake_pool, min_stake } =>
+                Eligibility::Stake {
+                    stake_pool: Pubkey::from_str(stake_pool).context("invalid stake_pool")?,
+                    min_stake: *min_stake,
+                },
+            EligibilitySpec::Kyc { merkle_tree, authority } =>
+                Eligibility::Kyc {
+                    merkle_tree: Pubkey::from_str(merkle_tree).context("invalid merkle_tree")?,
+                    authority: Pubkey::from_str(authority).context("invalid authority")?,
+                },
+            EligibilitySpec::Allowlist { wallets } => {
+                let wallets: Vec<Pubkey> = wallets
+                    .iter()
+                    .map(|wallet| Pubkey::from_str(wallet).context("invalid allowlist wallet"))
+                    .collect::<Result<_>>()?;
+                Eligibility::Allowlist { merkle_root: allowlist::root(&wallets) }
+            }
+        };
+        Ok(RoundParams {
+            rate: self.rate,
+            max_raise_amount: self.max_raise_amount,
+            max_purchase_per_wallet: self.max_purchase_per_wallet,
+            open_time: self.open_time,
+            close_time: self.close_time,
+            eligibility,
+            vesting: Vesting {
+                tge_date: self.tge_date,
+                tge_percentage: self.tge_percentage,
+                vesting_cliff: self.vesting_cliff,
+                vesting_frequency: self.vesting_frequency,
+                number_of_vesting: self.number_of_vesting,
+            },
+        })
+    }
+}
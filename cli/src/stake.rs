@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Subcommand;
+use paid_sdk::{ decode, stake };
+use serde_json::json;
+use solana_sdk::{ pubkey::Pubkey, signature::{ Keypair, Signer } };
+
+use crate::{ client::Client, read_keypair };
+
+#[derive(Subcommand)]
+pub enum StakeCommand {
+    /// Create a stake pool
+    InitPool {
+        #[arg(long)]
+        reward_mint: Pubkey,
+        #[arg(long)]
+        stake_mint: Pubkey,
+        #[arg(long)]
+        reward_decimals: u8,
+        #[arg(long)]
+        farm_decimals: u8,
+        #[arg(long)]
+        reward_per_block: u64,
+        /// Keypair of the new pool account, generated if omitted
+        #[arg(long)]
+        pool_keypair: Option<PathBuf>,
+    },
+    /// Fund the reward pot
+    Fund {
+        #[arg(long)]
+        pool: Pubkey,
+        #[arg(long)]
+        amount: u64,
+        /// Reward token account of funder, associated account if omitted
+        #[arg(long)]
+        owner_token: Option<Pubkey>,
+    },
+    /// Print reward claimable by a staker now
+    Pending {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Wallet of staker
+        #[arg(long)]
+        wallet: Pubkey,
+    },
+}
+
+pub fn run(client: &Client, command: StakeCommand) -> Result<()> {
+    let payer: Pubkey = client.payer();
+    match command {
+        StakeCommand::InitPool {
+            reward_mint,
+            stake_mint,
+            reward_decimals,
+            farm_decimals,
+            reward_per_block,
+            pool_keypair,
+        } => {
+            let pool: Keypair = match pool_keypair {
+                Some(path) => read_keypair(&path)?,
+                None => Keypair::new(),
+            };
+            println!("pool: {}", pool.pubkey());
+            let instruction = stake::init_pool(
+                &payer,
+                &pool.pubkey(),
+                &reward_mint,
+                &stake_mint,
+                reward_decimals,
+                farm_decimals,
+                reward_per_block
+            );
+            client.send(&[instruction], &[&pool])
+        }
+        StakeCommand::Fund { pool, amount, owner_token } => {
+            let reward_mint: Pubkey = decode::stake_pool(&client.account(&pool)?.data)?.reward_mint;
+            let owner_token: Pubkey = owner_token.unwrap_or_else(||
+                anchor_spl::associated_token::get_associated_token_address(&payer, &reward_mint)
+            );
+            client.send(&[stake::fund_reward(&payer, &pool, &reward_mint, &owner_token, amount)], &[])
+        }
+        StakeCommand::Pending { pool, wallet } => {
+            let reward: u64 = decode::pending_rewards(
+                &client.view(stake::pending_rewards(&pool, &wallet))?
+            )?;
+            println!("{}", serde_json::to_string_pretty(&json!({ "pending_rewards": reward }))?);
+            Ok(())
+        }
+    }
+}
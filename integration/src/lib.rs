@@ -0,0 +1,263 @@
+// @dev in-process test harness for paid-ignition, paidStake, paidAmm and kycMerkleTree
+use anchor_lang::{
+    solana_program::{ account_info::AccountInfo, entrypoint::ProgramResult, program_pack::Pack },
+    AccountDeserialize,
+    AnchorDeserialize,
+};
+use anchor_spl::token::spl_token;
+use solana_program_test::{ processor, BanksClientError, ProgramTest, ProgramTestContext };
+use solana_sdk::{
+    account::Account,
+    bpf_loader_upgradeable::{ self, UpgradeableLoaderState },
+    clock::Clock,
+    instruction::{ Instruction, InstructionError },
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{ Keypair, Signer },
+    system_instruction,
+    transaction::{ Transaction, TransactionError },
+};
+
+// @dev unix time the clock starts at
+pub const START_TIME: i64 = 1_700_000_000;
+
+// @dev anchor entrypoints tie accounts and infos to one lifetime
+fn paid_ignition_entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    paid_ignition::entry(program_id, Box::leak(accounts.to_vec().into_boxed_slice()), data)
+}
+
+fn paid_stake_entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    paid_stake::entry(program_id, Box::leak(accounts.to_vec().into_boxed_slice()), data)
+}
+
+fn paid_amm_entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    paid_amm::entry(program_id, Box::leak(accounts.to_vec().into_boxed_slice()), data)
+}
+
+fn kyc_merkle_tree_entry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8]
+) -> ProgramResult {
+    kyc_merkle_tree::entry(program_id, Box::leak(accounts.to_vec().into_boxed_slice()), data)
+}
+
+fn compression_entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    spl_account_compression::entry(program_id, Box::leak(accounts.to_vec().into_boxed_slice()), data)
+}
+
+pub struct Env {
+    pub ctx: ProgramTestContext,
+    // @dev unix time every following transaction sees
+    pub now: i64,
+    // @dev upgrade authority of paid-ignition and paidStake, the only key allowed to init config
+    upgrade_authority: Keypair,
+}
+
+impl Env {
+    // @dev all four programs plus compression and noop run natively, spl token programs are built in
+    pub async fn start() -> Self {
+        let mut program_test: ProgramTest = ProgramTest::default();
+        program_test.prefer_bpf(false);
+        program_test.add_program("paid_ignition", paid_ignition::ID, processor!(paid_ignition_entry));
+        program_test.add_program("paid_stake", paid_stake::ID, processor!(paid_stake_entry));
+        program_test.add_program("paid_amm", paid_amm::ID, processor!(paid_amm_entry));
+        program_test.add_program(
+            "kyc_merkle_tree",
+            kyc_merkle_tree::ID,
+            processor!(kyc_merkle_tree_entry)
+        );
+        program_test.add_program(
+            "spl_account_compression",
+            spl_account_compression::ID,
+            processor!(compression_entry)
+        );
+        program_test.add_program("spl_noop", spl_noop::ID, processor!(spl_noop::noop));
+        // native programs have no program data, stand one in so init_config can check authority
+        let upgrade_authority: Keypair = Keypair::new();
+        let program_data: Vec<u8> = bincode
+            ::serialize(
+                &(UpgradeableLoaderState::ProgramData {
+                    slot: 0,
+                    upgrade_authority_address: Some(upgrade_authority.pubkey()),
+                })
+            )
+            .unwrap();
+        for program_id in [paid_ignition::ID, paid_stake::ID] {
+            program_test.add_account(
+                paid_sdk::pda::program_data(&program_id).0,
+                Account {
+                    lamports: LAMPORTS_PER_SOL,
+                    data: program_data.clone(),
+                    owner: bpf_loader_upgradeable::ID,
+                    executable: false,
+                    rent_epoch: 0,
+                }
+            );
+        }
+        let mut env: Env = Self {
+            ctx: program_test.start_with_context().await,
+            now: START_TIME,
+            upgrade_authority,
+        };
+        env.advance().await;
+        env
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.ctx.payer.pubkey()
+    }
+
+    // @dev move clock to unix_timestamp
+    pub async fn warp(&mut self, unix_timestamp: i64) {
+        self.now = unix_timestamp;
+        self.advance().await;
+    }
+
+    // @dev next slot for a fresh blockhash, clock pinned to now
+    pub async fn advance(&mut self) {
+        let clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        self.ctx.warp_to_slot(clock.slot + 1).unwrap();
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = self.now;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    // @dev fee payer signs every transaction, signers are the extra ones
+    pub async fn send(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair]
+    ) -> Result<(), BanksClientError> {
+        self.advance().await;
+        let mut keypairs: Vec<&Keypair> = vec![&self.ctx.payer];
+        keypairs.extend_from_slice(signers);
+        let transaction: Transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.ctx.payer.pubkey()),
+            &keypairs[..],
+            self.ctx.last_blockhash
+        );
+        self.ctx.banks_client.process_transaction(transaction).await
+    }
+
+    // @dev simulate a view instruction and decode its return data
+    pub async fn view<T: AnchorDeserialize>(&mut self, instruction: Instruction) -> T {
+        self.advance().await;
+        let transaction: Transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.ctx.payer.pubkey()),
+            &[&self.ctx.payer],
+            self.ctx.last_blockhash
+        );
+        let simulation = self.ctx.banks_client.simulate_transaction(transaction).await.unwrap();
+        simulation.result.unwrap().unwrap();
+        let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+        paid_sdk::decode::return_data(&return_data.data).unwrap()
+    }
+
+    // @dev upgrade authority of programs holding some sol for rent
+    pub async fn admin(&mut self) -> Keypair {
+        let admin: Keypair = Keypair::from_bytes(&self.upgrade_authority.to_bytes()).unwrap();
+        let instruction = system_instruction::transfer(
+            &self.payer(),
+            &admin.pubkey(),
+            10 * LAMPORTS_PER_SOL
+        );
+        self.send(&[instruction], &[]).await.unwrap();
+        admin
+    }
+
+    // @dev new keypair holding some sol for rent
+    pub async fn wallet(&mut self) -> Keypair {
+        let wallet: Keypair = Keypair::new();
+        let instruction = system_instruction::transfer(
+            &self.payer(),
+            &wallet.pubkey(),
+            10 * LAMPORTS_PER_SOL
+        );
+        self.send(&[instruction], &[]).await.unwrap();
+        wallet
+    }
+
+    // @dev mint authority is the fee payer
+    pub async fn create_mint(&mut self, decimals: u8) -> Pubkey {
+        let mint: Keypair = Keypair::new();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let instructions = [
+            system_instruction::create_account(
+                &self.payer(),
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID
+            ),
+            spl_token::instruction
+                ::initialize_mint2(&spl_token::ID, &mint.pubkey(), &self.payer(), None, decimals)
+                .unwrap(),
+        ];
+        self.send(&instructions, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    // @dev associated token account of wallet, minted amount if any
+    pub async fn token_account(&mut self, wallet: &Pubkey, mint: &Pubkey, amount: u64) -> Pubkey {
+        let address: Pubkey = anchor_spl::associated_token::get_associated_token_address(
+            wallet,
+            mint
+        );
+        let mut instructions: Vec<Instruction> = vec![
+            spl_associated_token_account::instruction::create_associated_token_account(
+                &self.payer(),
+                wallet,
+                mint,
+                &spl_token::ID
+            )
+        ];
+        if amount > 0 {
+            instructions.push(
+                spl_token::instruction
+                    ::mint_to(&spl_token::ID, mint, &address, &self.payer(), &[], amount)
+                    .unwrap()
+            );
+        }
+        self.send(&instructions, &[]).await.unwrap();
+        address
+    }
+
+    // @dev mint more to an existing token account
+    pub async fn mint_to(&mut self, mint: &Pubkey, address: &Pubkey, amount: u64) {
+        let instruction = spl_token::instruction
+            ::mint_to(&spl_token::ID, mint, address, &self.payer(), &[], amount)
+            .unwrap();
+        self.send(&[instruction], &[]).await.unwrap();
+    }
+
+    pub async fn token_balance(&mut self, address: &Pubkey) -> u64 {
+        let account = self.ctx.banks_client.get_account(*address).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.ctx.banks_client.get_balance(*address).await.unwrap()
+    }
+
+    pub async fn exists(&mut self, address: &Pubkey) -> bool {
+        self.ctx.banks_client.get_account(*address).await.unwrap().is_some()
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let account = self.ctx.banks_client.get_account(*address).await.unwrap().unwrap();
+        paid_sdk::decode::decode(&account.data).unwrap()
+    }
+}
+
+// @dev program error code, anchor offsets are already applied by the caller
+pub fn assert_error(result: Result<(), BanksClientError>, code: u32) {
+    match result.expect_err("transaction should fail").unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(actual)) => {
+            assert_eq!(actual, code, "expected error {}, got {}", code, actual);
+        }
+        err => panic!("expected error {}, got {:?}", code, err),
+    }
+}
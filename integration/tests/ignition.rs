@@ -0,0 +1,2534 @@
+// @dev not reachable from any instruction, so not covered here:
+// MaxPurchaseForKYCUserNotValid, NotFunded, NotClaimable, InvalidGalaxyPoolProportion,
+// InvalidEarlyAccessProportion, InvalidTGEPercentage, TimeOutBuyIDOToken
+use paid_ignition::{
+    error::ErrCode,
+    Buyer,
+    ClaimHistory,
+    ClaimQuote,
+    Eligibility,
+    LpLock,
+    MetadataArgs,
+    Milestone,
+    MilestonePlan,
+    Pool,
+    PoolMetadata,
+    PoolStatus,
+    Project,
+    Proposal,
+    ProposalKind,
+    ProposalStatus,
+    PurchaseQuote,
+    Role,
+    RoundBuyer,
+    RoundParams,
+    Tier,
+    TierTable,
+    UnsoldPolicy,
+    Vesting,
+    LOCKUP_DURATION,
+    MAX_SYMBOL_LENGTH,
+    MIN_PROPOSAL_QUORUM,
+    MIN_REFUND_QUORUM,
+    MIN_VOTING_PERIOD,
+};
+use paid_integration::{ assert_error, Env, START_TIME };
+use paid_stake::states::StakeCheckpoints;
+use paid_sdk::{
+    allowlist,
+    amm,
+    ignition::{ self, PoolParams, Proposer, RoundEligibility },
+    kyc,
+    math,
+    paid_amm,
+    pda,
+    stake,
+};
+use solana_program_test::BanksClientError;
+use solana_sdk::{ pubkey::Pubkey, signature::{ Keypair, Signer }, system_instruction };
+use spl_concurrent_merkle_tree::concurrent_merkle_tree::ConcurrentMerkleTree;
+
+const EARLY_OPEN: i64 = START_TIME + 1000;
+const EARLY_CLOSE: i64 = START_TIME + 2000;
+// @dev long enough to outlast the cooling-off period
+const OPEN_CLOSE: i64 = EARLY_CLOSE + 300_000;
+const TGE: i64 = OPEN_CLOSE + 1000;
+
+const RAISE: u64 = 1_000_000;
+const RATE: u64 = 10;
+// @dev raise times rate plus 1% token fee
+const REQUIRED_OFFER: u64 = 10_100_000;
+
+fn params() -> PoolParams {
+    PoolParams {
+        max_purchase_amount_for_kyc_user: RAISE,
+        max_purchase_amount_for_not_kyc_user: RAISE,
+        token_fee_percentage: 100,
+        early_pool_participation_fee_percentage: 100,
+        open_pool_participation_fee_percentage: 100,
+        early_pool_proportion: 10000,
+        open_pool_proportion: 5000,
+        total_raise_amount: RAISE,
+        early_pool_open_time: EARLY_OPEN,
+        early_pool_close_time: EARLY_CLOSE,
+        open_pool_close_time: OPEN_CLOSE,
+        rate: RATE,
+        offer_decimals: 6,
+        tge_date: TGE,
+        tge_percentage: 2000,
+        vesting_cliff: 1000,
+        vesting_frequency: 1000,
+        number_of_vesting: 4,
+    }
+}
+
+fn metadata() -> MetadataArgs {
+    MetadataArgs {
+        name: "Paid Project".to_string(),
+        symbol: "PAID".to_string(),
+        uri: "https://example.com/pool.json".to_string(),
+        website: "https://example.com".to_string(),
+        content_hash: [7; 32],
+    }
+}
+
+fn code(err: ErrCode) -> u32 {
+    u32::from(err)
+}
+
+// @dev round with uncapped wallets, rate and raise of a small sale
+fn round(open_time: i64, close_time: i64, eligibility: Eligibility, vesting: Vesting) -> RoundParams {
+    RoundParams {
+        rate: RATE,
+        max_raise_amount: 100_000,
+        max_purchase_per_wallet: 0,
+        open_time,
+        close_time,
+        eligibility,
+        vesting,
+    }
+}
+
+// @dev everything unlocked at tge
+fn unlocked_at(tge_date: i64) -> Vesting {
+    Vesting {
+        tge_date,
+        tge_percentage: 10000,
+        vesting_cliff: 0,
+        vesting_frequency: 1,
+        number_of_vesting: 1,
+    }
+}
+
+struct Sale {
+    env: Env,
+    admin: Keypair,
+    operator: Keypair,
+    pool: Pubkey,
+    purchase_mint: Pubkey,
+    offer_mint: Pubkey,
+    operator_offer: Pubkey,
+}
+
+impl Sale {
+    // @dev config, roles and a created pool, nothing funded yet
+    async fn start() -> Self {
+        Self::start_with(UnsoldPolicy::ReturnToCreator, Pubkey::default()).await
+    }
+
+    async fn start_with(unsold_policy: UnsoldPolicy, unsold_recipient: Pubkey) -> Self {
+        let mut env: Env = Env::start().await;
+        let admin: Keypair = env.admin().await;
+        let operator: Keypair = env.wallet().await;
+        env.send(&[ignition::init_config(&admin.pubkey())], &[&admin]).await.unwrap();
+        for role in [Role::PoolOperator, Role::Funder, Role::Pauser, Role::FeeManager] {
+            env.send(
+                &[ignition::grant_role(&admin.pubkey(), role, &operator.pubkey())],
+                &[&admin]
+            ).await.unwrap();
+        }
+        let purchase_mint: Pubkey = env.create_mint(6).await;
+        let offer_mint: Pubkey = env.create_mint(6).await;
+        let operator_offer: Pubkey = env.token_account(
+            &operator.pubkey(),
+            &offer_mint,
+            REQUIRED_OFFER
+        ).await;
+        let pool: Keypair = Keypair::new();
+        env.send(
+            &[
+                ignition::create_pool(
+                    &operator.pubkey(),
+                    &pool.pubkey(),
+                    &purchase_mint,
+                    &offer_mint,
+                    params().to_uints(),
+                    unsold_policy,
+                    &unsold_recipient,
+                    metadata()
+                ),
+            ],
+            &[&operator, &pool]
+        ).await.unwrap();
+        Self {
+            env,
+            admin,
+            operator,
+            pool: pool.pubkey(),
+            purchase_mint,
+            offer_mint,
+            operator_offer,
+        }
+    }
+
+    async fn fund(&mut self, amount: u64) {
+        let instruction = ignition::fund_offer(
+            &self.operator.pubkey(),
+            &self.pool,
+            &self.offer_mint,
+            &self.operator_offer,
+            amount
+        );
+        self.env.send(&[instruction], &[&self.operator]).await.unwrap();
+    }
+
+    // @dev wallet holding purchase token
+    async fn buyer(&mut self, amount: u64) -> (Keypair, Pubkey) {
+        let wallet: Keypair = self.env.wallet().await;
+        let token: Pubkey = self.env.token_account(
+            &wallet.pubkey(),
+            &self.purchase_mint,
+            amount
+        ).await;
+        (wallet, token)
+    }
+
+    async fn buy_early(
+        &mut self,
+        wallet: &Keypair,
+        token: &Pubkey,
+        amount: u64
+    ) -> Result<(), BanksClientError> {
+        let tiered: bool = self.pool().await.tiered;
+        let instruction = ignition::buy_in_early_pool(
+            &wallet.pubkey(),
+            &self.pool,
+            &self.purchase_mint,
+            token,
+            amount,
+            tiered
+        );
+        self.env.send(&[instruction], &[wallet]).await
+    }
+
+    async fn buy_open(
+        &mut self,
+        wallet: &Keypair,
+        token: &Pubkey,
+        amount: u64
+    ) -> Result<(), BanksClientError> {
+        let instruction = ignition::buy_in_open_pool(
+            &wallet.pubkey(),
+            &self.pool,
+            &self.purchase_mint,
+            token,
+            amount
+        );
+        self.env.send(&[instruction], &[wallet]).await
+    }
+
+    async fn pool(&mut self) -> Pool {
+        let pool: Pubkey = self.pool;
+        self.env.account(&pool).await
+    }
+
+    async fn buyer_account(&mut self, wallet: &Keypair) -> Buyer {
+        let buyer: Pubkey = pda::buyer(&self.pool, &wallet.pubkey()).0;
+        self.env.account(&buyer).await
+    }
+
+    // @dev paidStake pool operated by pool operator, returns pool and stake mint
+    async fn stake_pool(&mut self) -> (Pubkey, Pubkey) {
+        let (admin, operator): (Pubkey, Pubkey) = (self.admin.pubkey(), self.operator.pubkey());
+        self.env.send(&[stake::init_config(&admin)], &[&self.admin]).await.unwrap();
+        self.env.send(
+            &[stake::grant_role(&admin, paid_stake::states::Role::PoolOperator, &operator)],
+            &[&self.admin]
+        ).await.unwrap();
+        let stake_mint: Pubkey = self.env.create_mint(6).await;
+        let reward_mint: Pubkey = self.env.create_mint(6).await;
+        let stake_pool: Keypair = Keypair::new();
+        self.env.send(
+            &[
+                stake::init_pool(
+                    &operator,
+                    &stake_pool.pubkey(),
+                    &reward_mint,
+                    &stake_mint,
+                    6,
+                    6,
+                    10
+                ),
+            ],
+            &[&self.operator, &stake_pool]
+        ).await.unwrap();
+        (stake_pool.pubkey(), stake_mint)
+    }
+
+    // @dev buyer wallet that staked amount in stake pool
+    async fn staked_buyer(
+        &mut self,
+        stake_pool: &Pubkey,
+        stake_mint: &Pubkey,
+        staked_amount: u64,
+        purchase_amount: u64
+    ) -> (Keypair, Pubkey) {
+        let (wallet, token) = self.buyer(purchase_amount).await;
+        let stake_token: Pubkey = self.env.token_account(
+            &wallet.pubkey(),
+            stake_mint,
+            staked_amount
+        ).await;
+        self.env.send(
+            &[stake::stake(&wallet.pubkey(), stake_pool, stake_mint, &stake_token, staked_amount)],
+            &[&wallet]
+        ).await.unwrap();
+        (wallet, token)
+    }
+
+    // @dev empty project over pool mints, owned by pool operator
+    async fn create_project(&mut self) -> Pubkey {
+        let project: Keypair = Keypair::new();
+        self.env.send(
+            &[
+                ignition::create_project(
+                    &self.operator.pubkey(),
+                    &project.pubkey(),
+                    &self.purchase_mint,
+                    &self.offer_mint
+                ),
+            ],
+            &[&self.operator, &project]
+        ).await.unwrap();
+        project.pubkey()
+    }
+
+    async fn add_round(
+        &mut self,
+        project: &Pubkey,
+        params: RoundParams
+    ) -> Result<(), BanksClientError> {
+        let instruction = ignition::add_round(&self.operator.pubkey(), project, params);
+        self.env.send(&[instruction], &[&self.operator]).await
+    }
+
+    async fn fund_project(&mut self, project: &Pubkey, amount: u64) -> Result<(), BanksClientError> {
+        let instruction = ignition::fund_project(
+            &self.operator.pubkey(),
+            project,
+            &self.offer_mint,
+            &self.operator_offer,
+            amount
+        );
+        self.env.send(&[instruction], &[&self.operator]).await
+    }
+
+    async fn buy_round(
+        &mut self,
+        wallet: &Keypair,
+        project: &Pubkey,
+        round: u8,
+        amount: u64,
+        eligibility: RoundEligibility
+    ) -> Result<(), BanksClientError> {
+        let instruction = ignition::buy_in_round(
+            &wallet.pubkey(),
+            project,
+            &self.purchase_mint,
+            round,
+            amount,
+            eligibility
+        );
+        self.env.send(&[instruction], &[wallet]).await
+    }
+
+    async fn claim_round(
+        &mut self,
+        wallet: &Keypair,
+        project: &Pubkey,
+        round: u8,
+        amount: u64
+    ) -> Result<(), BanksClientError> {
+        let instruction = ignition::claim_round_offer(
+            &wallet.pubkey(),
+            project,
+            &self.offer_mint,
+            round,
+            amount
+        );
+        self.env.send(&[instruction], &[wallet]).await
+    }
+
+    async fn round_buyer(&mut self, project: &Pubkey, round: u8, wallet: &Keypair) -> RoundBuyer {
+        let round_buyer: Pubkey = pda::round_buyer(project, round, &wallet.pubkey()).0;
+        self.env.account(&round_buyer).await
+    }
+
+    async fn refresh(&mut self) -> PoolStatus {
+        self.env.send(&[ignition::refresh_pool_status(&self.pool)], &[]).await.unwrap();
+        self.pool().await.status
+    }
+}
+
+#[tokio::test]
+async fn sale_fills_both_pools_and_vests() {
+    let mut sale: Sale = Sale::start().await;
+    sale.fund(REQUIRED_OFFER / 2).await;
+    assert_eq!(sale.pool().await.status, PoolStatus::Created);
+    sale.fund(REQUIRED_OFFER - REQUIRED_OFFER / 2).await;
+    assert_eq!(sale.pool().await.status, PoolStatus::Funded);
+
+    let (early, early_token) = sale.buyer(1_000_000).await;
+    let (open, open_token) = sale.buyer(1_000_000).await;
+
+    // early pool not open yet
+    assert_error(
+        sale.buy_early(&early, &early_token, 100_000).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+
+    sale.env.warp(EARLY_OPEN).await;
+    assert_error(sale.buy_early(&early, &early_token, 0).await, code(ErrCode::InvalidAmount));
+    // early cap is half the raise, purchase crossing it is partially filled
+    sale.buy_early(&early, &early_token, 600_000).await.unwrap();
+    assert_error(
+        sale.buy_early(&early, &early_token, 1).await,
+        code(ErrCode::ExceedMaxPurchaseAmountForEarlyAccess)
+    );
+    let pool: Pool = sale.pool().await;
+    assert_eq!(pool.status, PoolStatus::EarlyOpen);
+    assert_eq!(pool.purchased_amount_in_early_access, 500_000);
+    let buyer: Buyer = sale.buyer_account(&early).await;
+    assert_eq!(buyer.total_purchase, 500_000);
+    assert_eq!(buyer.total_amount, 500_000 * RATE);
+    // early pool transfers net amount only
+    assert_eq!(sale.env.token_balance(&early_token).await, 500_000);
+
+    sale.env.warp(EARLY_CLOSE).await;
+    assert_error(
+        sale.buy_early(&early, &early_token, 1_000).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+    sale.buy_open(&open, &open_token, 600_000).await.unwrap();
+    assert_error(sale.buy_open(&open, &open_token, 1).await, code(ErrCode::HardCapReached));
+    let pool: Pool = sale.pool().await;
+    assert_eq!(pool.status, PoolStatus::OpenOpen);
+    assert_eq!(pool.purchased_amount_in_open_pool, 500_000);
+    assert_eq!(pool.purchased_amount, RAISE);
+    // open pool follows the same fee model, vault holds exactly the recorded raise
+    assert_eq!(sale.env.token_balance(&open_token).await, 500_000);
+    let purchase_vault: Pubkey = pda::purchase_vault(&sale.pool).0;
+    assert_eq!(sale.env.token_balance(&purchase_vault).await, pool.purchased_amount);
+
+    // cooling-off period is over by the time the sale closes
+    sale.env.warp(OPEN_CLOSE).await;
+    assert_error(
+        sale.env.send(
+            &[ignition::cancel_purchase(&open.pubkey(), &sale.pool, &open_token)],
+            &[&open]
+        ).await,
+        code(ErrCode::CoolingOffPeriodEnded)
+    );
+
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    assert_eq!(sale.refresh().await, PoolStatus::Succeeded);
+    sale.env.send(
+        &[ignition::enable_claims(&sale.operator.pubkey(), &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    assert_eq!(sale.pool().await.status, PoolStatus::Claiming);
+
+    // 20% at tge, the rest in 4 releases starting at the cliff
+    let pool: Pool = sale.pool().await;
+    let buyer: Buyer = sale.buyer_account(&early).await;
+    assert_eq!(math::claimable_amount(&pool, &buyer, TGE), 1_000_000);
+    assert_eq!(math::claimable_amount(&pool, &buyer, TGE + 1000), 2_000_000);
+    assert_eq!(math::claimable_amount(&pool, &buyer, TGE + 2500), 3_000_000);
+    assert_eq!(math::claimable_amount(&pool, &buyer, TGE + 4000), 5_000_000);
+
+    // raise is still in the vault, buyer is not settled
+    assert_error(
+        sale.env.send(&[ignition::close_buyer(&early.pubkey(), &sale.pool)], &[&early]).await,
+        code(ErrCode::NotSettled)
+    );
+    assert_error(
+        sale.env.send(
+            &[ignition::close_purchase_vault(&early.pubkey(), &sale.pool)],
+            &[]
+        ).await,
+        code(ErrCode::NotSettled)
+    );
+
+    sale.env.warp(TGE + 3999).await;
+    assert_error(
+        sale.env.send(
+            &[ignition::finalize_pool(&sale.operator.pubkey(), &sale.pool, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidTime)
+    );
+    sale.env.warp(TGE + 4000).await;
+    sale.env.send(
+        &[ignition::finalize_pool(&sale.operator.pubkey(), &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    assert_eq!(sale.pool().await.status, PoolStatus::Finalized);
+}
+
+#[tokio::test]
+async fn quotes_match_executed_purchases() {
+    let mut sale: Sale = Sale::start().await;
+    sale.fund(REQUIRED_OFFER).await;
+    let (buyer, buyer_token) = sale.buyer(1_000_000).await;
+
+    // nothing is sold before the early pool opens
+    let quote: PurchaseQuote = sale.env.view(ignition::quote_purchase(&sale.pool, 100_000, None, false, false)).await;
+    assert_eq!(quote.status, PoolStatus::Funded);
+    assert_eq!(quote.purchase_amount, 0);
+    assert_eq!(quote.remaining_amount, 0);
+
+    sale.env.warp(EARLY_OPEN).await;
+    let quote: PurchaseQuote = sale.env.view(ignition::quote_purchase(&sale.pool, 600_000, None, false, false)).await;
+    assert_eq!(quote.status, PoolStatus::EarlyOpen);
+    assert_eq!(quote.remaining_amount, 500_000);
+    assert_eq!(quote.purchase_amount, 505_050);
+    assert_eq!(quote.participant_fee, 5_050);
+    sale.buy_early(&buyer, &buyer_token, 600_000).await.unwrap();
+    assert_eq!(sale.buyer_account(&buyer).await.total_amount, quote.offered_amount);
+
+    let claim: ClaimQuote = sale.env.view(ignition::quote_claimable(&sale.pool, &buyer.pubkey())).await;
+    assert_eq!(claim.total_amount, quote.offered_amount);
+    assert_eq!(claim.claimable_amount, 0);
+
+    sale.env.warp(EARLY_CLOSE).await;
+    let quote: PurchaseQuote = sale.env.view(ignition::quote_purchase(&sale.pool, 100_000, None, false, false)).await;
+    assert_eq!(quote.status, PoolStatus::OpenOpen);
+    assert_eq!(quote.remaining_amount, 500_000);
+    assert_eq!(quote.offered_amount, 99_000 * RATE);
+
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    sale.env.send(
+        &[ignition::enable_claims(&sale.operator.pubkey(), &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.env.warp(TGE + 1000).await;
+    let claim: ClaimQuote = sale.env.view(ignition::quote_claimable(&sale.pool, &buyer.pubkey())).await;
+    let pool: Pool = sale.pool().await;
+    let account: Buyer = sale.buyer_account(&buyer).await;
+    assert_eq!(claim.status, PoolStatus::Claiming);
+    assert_eq!(claim.claimable_amount, math::claimable_amount(&pool, &account, TGE + 1000));
+}
+
+#[tokio::test]
+async fn buyer_claims_vested_offer() {
+    let mut sale: Sale = Sale::start().await;
+    sale.fund(REQUIRED_OFFER).await;
+    let (early, early_token) = sale.buyer(100_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&early, &early_token, 100_000).await.unwrap();
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    sale.env.send(
+        &[ignition::enable_claims(&sale.operator.pubkey(), &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+
+    // delegate claims part of the tge release into the default destination
+    let delegate: Keypair = sale.env.wallet().await;
+    let destination: Pubkey = Pubkey::new_unique();
+    sale.env.send(
+        &[ignition::set_claim_settings(&early.pubkey(), &sale.pool, &delegate.pubkey(), &destination)],
+        &[&early]
+    ).await.unwrap();
+    sale.env.warp(TGE).await;
+    let buyer: Buyer = sale.buyer_account(&early).await;
+    let tge_amount: u64 = (buyer.total_amount * 2000) / 10000;
+    let (pool, offer_mint): (Pubkey, Pubkey) = (sale.pool, sale.offer_mint);
+    let claim = |amount: u64| {
+        ignition::claim_offer(
+            &delegate.pubkey(),
+            &early.pubkey(),
+            &destination,
+            &pool,
+            &offer_mint,
+            amount
+        )
+    };
+    sale.env.send(&[claim(tge_amount / 2)], &[&delegate]).await.unwrap();
+    let buyer: Buyer = sale.buyer_account(&early).await;
+    assert_eq!(buyer.cliamed_amount, tge_amount / 2);
+    let destination_offer: Pubkey = anchor_spl::associated_token::get_associated_token_address(
+        &destination,
+        &sale.offer_mint
+    );
+    assert_eq!(sale.env.token_balance(&destination_offer).await, buyer.cliamed_amount);
+    assert_error(
+        sale.env.send(&[claim(tge_amount - tge_amount / 2 + 1)], &[&delegate]).await,
+        code(ErrCode::ExceedsClaimableAmount)
+    );
+
+    sale.env.warp(TGE + 4000).await;
+    sale.env.send(&[claim(buyer.total_amount - buyer.cliamed_amount)], &[&delegate]).await.unwrap();
+    let buyer: Buyer = sale.buyer_account(&early).await;
+    assert_eq!(buyer.cliamed_amount, buyer.total_amount);
+    assert_eq!(sale.env.token_balance(&destination_offer).await, buyer.total_amount);
+    assert_error(
+        sale.env.send(&[claim(1)], &[&delegate]).await,
+        code(ErrCode::AlreadyClaimedTotoalAmount)
+    );
+
+    // every claim is recorded against the buyer wallet
+    let history: ClaimHistory = sale.env.account(&pda::claim_history(&pool, &early.pubkey()).0).await;
+    assert_eq!((history.wallet, history.rent_payer), (early.pubkey(), delegate.pubkey()));
+    let records: Vec<(i64, u64)> = history.claims
+        .iter()
+        .map(|claim| (claim.claimed_at, claim.amount))
+        .collect();
+    assert_eq!(
+        records,
+        vec![(TGE, tge_amount / 2), (TGE + 4000, buyer.total_amount - tge_amount / 2)]
+    );
+    // history outlives the buyer account, closable once the buyer is, rent goes back to delegate
+    let close_history = ignition::close_claim_history(&early.pubkey(), &delegate.pubkey(), &sale.pool);
+    assert_error(
+        sale.env.send(&[close_history.clone()], &[&early]).await,
+        code(ErrCode::NotSettled)
+    );
+    sale.env.send(&[ignition::close_buyer(&early.pubkey(), &sale.pool)], &[&early]).await.unwrap();
+    let before: u64 = sale.env.lamports(&delegate.pubkey()).await;
+    sale.env.send(&[close_history], &[&early]).await.unwrap();
+    assert!(!sale.env.exists(&pda::claim_history(&pool, &early.pubkey()).0).await);
+    assert!(sale.env.lamports(&delegate.pubkey()).await > before);
+}
+
+#[tokio::test]
+async fn claim_authority_and_destination_are_checked() {
+    let mut sale: Sale = Sale::start().await;
+    sale.fund(REQUIRED_OFFER).await;
+    let (early, early_token) = sale.buyer(100_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&early, &early_token, 100_000).await.unwrap();
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    sale.env.send(
+        &[ignition::enable_claims(&sale.operator.pubkey(), &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.env.warp(TGE).await;
+
+    let wallet: Pubkey = early.pubkey();
+    let stranger: Keypair = sale.env.wallet().await;
+    let (pool, offer_mint): (Pubkey, Pubkey) = (sale.pool, sale.offer_mint);
+    let claim = |signer: &Pubkey, destination: &Pubkey, amount: u64| {
+        ignition::claim_offer(signer, &wallet, destination, &pool, &offer_mint, amount)
+    };
+    assert_error(
+        sale.env.send(&[claim(&stranger.pubkey(), &wallet, 1)], &[&stranger]).await,
+        code(ErrCode::NotClaimAuthority)
+    );
+    // destination defaults to the buyer wallet
+    assert_error(
+        sale.env.send(&[claim(&wallet, &stranger.pubkey(), 1)], &[&early]).await,
+        code(ErrCode::InvalidClaimAccounts)
+    );
+    assert_error(
+        sale.env.send(&[claim(&wallet, &wallet, 0)], &[&early]).await,
+        code(ErrCode::InvalidAmount)
+    );
+    assert_error(
+        sale.env.send(&[claim(&wallet, &wallet, u64::MAX)], &[&early]).await,
+        code(ErrCode::ExceedsClaimableAmount)
+    );
+
+    // delegate can be set and cleared by the buyer only
+    assert_error(
+        sale.env.send(
+            &[ignition::set_claim_settings(&stranger.pubkey(), &sale.pool, &stranger.pubkey(), &wallet)],
+            &[&stranger]
+        ).await,
+        u32::from(anchor_lang::error::ErrorCode::AccountNotInitialized)
+    );
+    sale.env.send(
+        &[ignition::set_claim_settings(&wallet, &sale.pool, &stranger.pubkey(), &Pubkey::default())],
+        &[&early]
+    ).await.unwrap();
+    let buyer: Buyer = sale.buyer_account(&early).await;
+    assert_eq!(buyer.delegate, stranger.pubkey());
+    assert_eq!(buyer.claim_destination(), wallet);
+    sale.env.send(
+        &[ignition::set_claim_settings(&wallet, &sale.pool, &Pubkey::default(), &Pubkey::default())],
+        &[&early]
+    ).await.unwrap();
+    assert_error(
+        sale.env.send(&[claim(&stranger.pubkey(), &wallet, 1)], &[&stranger]).await,
+        code(ErrCode::NotClaimAuthority)
+    );
+}
+
+#[tokio::test]
+async fn crank_pushes_vested_offer_and_pays_tips() {
+    const TIP: u64 = 5_000;
+    let mut sale: Sale = Sale::start().await;
+    sale.fund(REQUIRED_OFFER).await;
+    let (first, first_token) = sale.buyer(100_000).await;
+    let (second, second_token) = sale.buyer(200_000).await;
+    let (third, third_token) = sale.buyer(50_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&first, &first_token, 100_000).await.unwrap();
+    sale.buy_early(&second, &second_token, 200_000).await.unwrap();
+    sale.buy_early(&third, &third_token, 50_000).await.unwrap();
+    // third buyer has no offer token account yet
+    for wallet in [&first, &second] {
+        sale.env.token_account(&wallet.pubkey(), &sale.offer_mint, 0).await;
+    }
+    let owner: Pubkey = sale.operator.pubkey();
+    sale.env.send(
+        &[ignition::fund_tip_vault(&owner, &sale.pool, TIP, 3 * TIP)],
+        &[&sale.operator]
+    ).await.unwrap();
+
+    let wallets: [Pubkey; 3] = [first.pubkey(), second.pubkey(), third.pubkey()];
+    let cranker: Keypair = sale.env.wallet().await;
+    let crank = ignition::crank_claims(
+        &cranker.pubkey(),
+        &sale.pool,
+        &sale.offer_mint,
+        &wallets,
+        true
+    );
+    // claims not enabled yet
+    assert_error(
+        sale.env.send(&[crank.clone()], &[&cranker]).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    sale.env.send(
+        &[ignition::enable_claims(&owner, &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+
+    sale.env.warp(TGE).await;
+    let before: u64 = sale.env.lamports(&cranker.pubkey()).await;
+    sale.env.send(&[crank.clone()], &[&cranker]).await.unwrap();
+    for wallet in [&first, &second] {
+        let buyer: Buyer = sale.buyer_account(wallet).await;
+        assert_eq!(buyer.cliamed_amount, (buyer.total_amount * 2000) / 10000);
+        let offer: Pubkey = anchor_spl::associated_token::get_associated_token_address(
+            &wallet.pubkey(),
+            &sale.offer_mint
+        );
+        assert_eq!(sale.env.token_balance(&offer).await, buyer.cliamed_amount);
+        // cranked claims are not recorded in claim history
+        assert!(!sale.env.exists(&pda::claim_history(&sale.pool, &wallet.pubkey()).0).await);
+    }
+    // missing token account is skipped instead of failing the batch, and earns no tip
+    assert_eq!(sale.buyer_account(&third).await.cliamed_amount, 0);
+    // cranker also signs, so only the fee payer is charged
+    assert_eq!(sale.env.lamports(&cranker.pubkey()).await, before + 2 * TIP);
+
+    // nothing newly vested, no tips
+    sale.env.send(&[crank.clone()], &[&cranker]).await.unwrap();
+    assert_eq!(sale.env.lamports(&cranker.pubkey()).await, before + 2 * TIP);
+
+    // tips are capped by what is left in the vault
+    sale.env.warp(TGE + 1000).await;
+    sale.env.send(&[crank], &[&cranker]).await.unwrap();
+    assert_eq!(sale.env.lamports(&cranker.pubkey()).await, before + 3 * TIP);
+
+    // buyer of one wallet paired with token account of another
+    let mut mismatched = ignition::crank_claims(
+        &cranker.pubkey(),
+        &sale.pool,
+        &sale.offer_mint,
+        &wallets,
+        false
+    );
+    let last: usize = mismatched.accounts.len() - 1;
+    mismatched.accounts.swap(last - 2, last);
+    assert_error(
+        sale.env.send(&[mismatched], &[&cranker]).await,
+        code(ErrCode::InvalidClaimAccounts)
+    );
+
+    sale.env.send(
+        &[ignition::close_tip_vault(&owner, &sale.pool)],
+        &[&sale.operator]
+    ).await.unwrap();
+    assert!(!sale.env.exists(&pda::tip_vault(&sale.pool).0).await);
+}
+
+// @dev sells 100_000 of the raise in the early pool, returns unsold ido token
+async fn partially_sold(sale: &mut Sale) -> u64 {
+    sale.fund(REQUIRED_OFFER).await;
+    let (buyer, buyer_token) = sale.buyer(100_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&buyer, &buyer_token, 100_000).await.unwrap();
+    // 1% participation fee is not converted
+    let sold: u64 = 99_000 * RATE;
+    assert_eq!(sale.pool().await.sold_offer_amount(), sold);
+    // token fee share of funding is not unsold
+    RAISE * RATE - sold
+}
+
+#[tokio::test]
+async fn unsold_offer_returns_to_creator() {
+    let mut sale: Sale = Sale::start().await;
+    let unsold: u64 = partially_sold(&mut sale).await;
+    let finalize = ignition::finalize_unsold(&sale.pool, &sale.offer_mint, Some(&sale.operator_offer));
+    assert_error(sale.env.send(&[finalize.clone()], &[]).await, code(ErrCode::InvalidPoolStatus));
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    let before: u64 = sale.env.token_balance(&sale.operator_offer).await;
+
+    // only the owner token account is accepted
+    let stranger: Keypair = sale.env.wallet().await;
+    let stranger_offer: Pubkey = sale.env.token_account(&stranger.pubkey(), &sale.offer_mint, 0).await;
+    assert_error(
+        sale.env.send(
+            &[ignition::finalize_unsold(&sale.pool, &sale.offer_mint, Some(&stranger_offer))],
+            &[]
+        ).await,
+        code(ErrCode::InvalidUnsoldDestination)
+    );
+    assert_error(
+        sale.env.send(&[ignition::finalize_unsold(&sale.pool, &sale.offer_mint, None)], &[]).await,
+        code(ErrCode::InvalidUnsoldDestination)
+    );
+
+    sale.env.send(&[finalize.clone()], &[]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&sale.operator_offer).await, before + unsold);
+    let vault: Pubkey = pda::offer_vault(&sale.pool).0;
+    assert_eq!(sale.env.token_balance(&vault).await, REQUIRED_OFFER - unsold);
+    assert_eq!(sale.pool().await.total_funded_amount, REQUIRED_OFFER - unsold);
+    assert_eq!(sale.pool().await.unsold_finalized_amount, unsold);
+    // nothing returned since last finalize
+    assert_error(sale.env.send(&[finalize], &[]).await, code(ErrCode::NoUnsoldAmount));
+}
+
+#[tokio::test]
+async fn unsold_offer_is_burned() {
+    let mut sale: Sale = Sale::start_with(UnsoldPolicy::Burn, Pubkey::default()).await;
+    let unsold: u64 = partially_sold(&mut sale).await;
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    assert_error(
+        sale.env.send(
+            &[ignition::finalize_unsold(&sale.pool, &sale.offer_mint, Some(&sale.operator_offer))],
+            &[]
+        ).await,
+        code(ErrCode::InvalidUnsoldDestination)
+    );
+    sale.env.send(&[ignition::finalize_unsold(&sale.pool, &sale.offer_mint, None)], &[]).await.unwrap();
+    let vault: Pubkey = pda::offer_vault(&sale.pool).0;
+    assert_eq!(sale.env.token_balance(&vault).await, REQUIRED_OFFER - unsold);
+}
+
+#[tokio::test]
+async fn unsold_offer_goes_to_recipient() {
+    let recipient: Pubkey = Pubkey::new_unique();
+    let mut sale: Sale = Sale::start_with(UnsoldPolicy::SendToRecipient, recipient).await;
+    let unsold: u64 = partially_sold(&mut sale).await;
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    let recipient_offer: Pubkey = sale.env.token_account(&recipient, &sale.offer_mint, 0).await;
+    sale.env.send(
+        &[ignition::finalize_unsold(&sale.pool, &sale.offer_mint, Some(&recipient_offer))],
+        &[]
+    ).await.unwrap();
+    assert_eq!(sale.env.token_balance(&recipient_offer).await, unsold);
+}
+
+#[tokio::test]
+async fn unsold_recipient_is_validated_at_creation() {
+    let mut sale: Sale = Sale::start().await;
+    let operator: Pubkey = sale.operator.pubkey();
+    for (policy, recipient) in [
+        (UnsoldPolicy::SendToRecipient, Pubkey::default()),
+        (UnsoldPolicy::Burn, Pubkey::new_unique()),
+    ] {
+        let pool: Keypair = Keypair::new();
+        let instruction = ignition::create_pool(
+            &operator,
+            &pool.pubkey(),
+            &sale.purchase_mint,
+            &sale.offer_mint,
+            params().to_uints(),
+            policy,
+            &recipient,
+            metadata()
+        );
+        assert_error(
+            sale.env.send(&[instruction], &[&sale.operator, &pool]).await,
+            code(ErrCode::InvalidParam)
+        );
+    }
+}
+
+#[tokio::test]
+async fn pool_metadata_is_editable_until_early_pool_opens() {
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    let address: Pubkey = pda::pool_metadata(&sale.pool).0;
+    let stored: PoolMetadata = sale.env.account(&address).await;
+    assert_eq!(
+        (stored.pool, stored.name.as_str(), stored.content_hash),
+        (sale.pool, "Paid Project", [7; 32])
+    );
+
+    let pool: Keypair = Keypair::new();
+    let unnamed = ignition::create_pool(
+        &owner,
+        &pool.pubkey(),
+        &sale.purchase_mint,
+        &sale.offer_mint,
+        params().to_uints(),
+        UnsoldPolicy::ReturnToCreator,
+        &Pubkey::default(),
+        MetadataArgs { name: String::new(), ..metadata() }
+    );
+    assert_error(
+        sale.env.send(&[unnamed], &[&sale.operator, &pool]).await,
+        code(ErrCode::InvalidMetadata)
+    );
+    let long_symbol = MetadataArgs { symbol: "S".repeat(MAX_SYMBOL_LENGTH + 1), ..metadata() };
+    assert_error(
+        sale.env.send(
+            &[ignition::update_pool_metadata(&owner, &sale.pool, long_symbol)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidMetadata)
+    );
+    let (stranger, _) = sale.buyer(0).await;
+    assert!(
+        sale.env.send(
+            &[ignition::update_pool_metadata(&stranger.pubkey(), &sale.pool, metadata())],
+            &[&stranger]
+        ).await.is_err()
+    );
+
+    let renamed = MetadataArgs { name: "Renamed".to_string(), website: String::new(), ..metadata() };
+    sale.env.send(
+        &[ignition::update_pool_metadata(&owner, &sale.pool, renamed.clone())],
+        &[&sale.operator]
+    ).await.unwrap();
+    let stored: PoolMetadata = sale.env.account(&address).await;
+    assert_eq!((stored.name.as_str(), stored.website.as_str()), ("Renamed", ""));
+
+    sale.fund(REQUIRED_OFFER).await;
+    sale.env.warp(EARLY_OPEN).await;
+    assert_error(
+        sale.env.send(
+            &[ignition::update_pool_metadata(&owner, &sale.pool, renamed)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+}
+
+#[tokio::test]
+async fn liquidity_is_seeded_and_locked() {
+    const LIQUIDITY_PERCENTAGE: u16 = 2000;
+    const LOCK: i64 = 1000;
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    // amm program must be allowed by super admin first
+    let configure = ignition::set_liquidity_config(
+        &owner,
+        &sale.pool,
+        LIQUIDITY_PERCENTAGE,
+        &paid_amm::ID,
+        LOCK
+    );
+    assert_error(
+        sale.env.send(&[configure.clone()], &[&sale.operator]).await,
+        code(ErrCode::AmmProgramNotAllowed)
+    );
+    let allow = ignition::set_amm_programs(&sale.admin.pubkey(), vec![paid_amm::ID]);
+    sale.env.send(&[allow], &[&sale.admin]).await.unwrap();
+    assert_error(
+        sale.env.send(
+            &[ignition::set_liquidity_config(&owner, &sale.pool, 10001, &paid_amm::ID, LOCK)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidParam)
+    );
+    sale.env.send(&[configure], &[&sale.operator]).await.unwrap();
+
+    // funding must also cover liquidity share of the whole raise
+    let reserve: u64 = (RAISE * (LIQUIDITY_PERCENTAGE as u64)) / 10000 * RATE;
+    sale.fund(REQUIRED_OFFER).await;
+    assert_eq!(sale.pool().await.status, PoolStatus::Created);
+    let (offer_mint, operator_offer) = (sale.offer_mint, sale.operator_offer);
+    sale.env.mint_to(&offer_mint, &operator_offer, reserve).await;
+    sale.fund(reserve).await;
+    assert_eq!(sale.pool().await.status, PoolStatus::Funded);
+
+    let (buyer, buyer_token) = sale.buyer(100_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&buyer, &buyer_token, 100_000).await.unwrap();
+    let seed = ignition::seed_liquidity(&owner, &sale.pool, &sale.purchase_mint, &sale.offer_mint, 0);
+    sale.env.send(
+        &[amm::create_pool(&owner, &sale.purchase_mint, &sale.offer_mint)],
+        &[&sale.operator]
+    ).await.unwrap();
+    assert_error(
+        sale.env.send(&[seed.clone()], &[&sale.operator]).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    // amm accounts must belong to the pool mints
+    let mut foreign = seed.clone();
+    let amm_pool: Pubkey = pda::amm_pool(&sale.purchase_mint, &sale.offer_mint).0;
+    for meta in foreign.accounts.iter_mut().filter(|meta| meta.pubkey == amm_pool) {
+        meta.pubkey = pda::amm_pool(&sale.offer_mint, &sale.purchase_mint).0;
+    }
+    assert_error(
+        sale.env.send(&[foreign], &[&sale.operator]).await,
+        u32::from(anchor_lang::error::ErrorCode::ConstraintSeeds)
+    );
+    sale.env.send(&[seed], &[&sale.operator]).await.unwrap();
+    // escrows are closed, vaults never signed for the amm
+    for mint in [&sale.purchase_mint, &sale.offer_mint] {
+        assert!(!sale.env.exists(&pda::liquidity_escrow(&sale.pool, mint).0).await);
+    }
+    // 20% of net raise and matching ido token at sale price
+    let purchase_amount: u64 = 99_000 / 5;
+    let offer_amount: u64 = purchase_amount * RATE;
+    assert_eq!(
+        sale.env.token_balance(&pda::amm_vault(&amm_pool, &sale.purchase_mint).0).await,
+        purchase_amount
+    );
+    assert_eq!(
+        sale.env.token_balance(&pda::amm_vault(&amm_pool, &sale.offer_mint).0).await,
+        offer_amount
+    );
+    let lp_lock: LpLock = sale.env.account(&pda::lp_lock(&sale.pool).0).await;
+    assert_eq!(lp_lock.amount, 62_613);
+    assert_eq!(lp_lock.unlock_time, OPEN_CLOSE + 1 + LOCK);
+    let pool: Pool = sale.pool().await;
+    assert!(pool.liquidity_seeded);
+    assert_eq!(pool.total_funded_amount, REQUIRED_OFFER + reserve - offer_amount);
+
+    // seeded raise can no longer be refunded
+    assert_error(
+        sale.env.send(&[ignition::cancel_pool(&owner, &sale.pool, false)], &[&sale.operator]).await,
+        code(ErrCode::RaiseAlreadyReleased)
+    );
+    // unused liquidity reserve is unsold, token fee share stays in vault
+    sale.env.send(
+        &[ignition::finalize_unsold(&sale.pool, &sale.offer_mint, Some(&sale.operator_offer))],
+        &[]
+    ).await.unwrap();
+    assert_eq!(
+        sale.env.token_balance(&sale.operator_offer).await,
+        RAISE * RATE + reserve - offer_amount - 99_000 * RATE
+    );
+    assert_eq!(
+        sale.env.token_balance(&pda::offer_vault(&sale.pool).0).await,
+        REQUIRED_OFFER - RAISE * RATE + 99_000 * RATE
+    );
+
+    let withdraw = ignition::withdraw_locked_lp(&owner, &sale.pool, &lp_lock.lp_mint);
+    assert_error(
+        sale.env.send(&[withdraw.clone()], &[&sale.operator]).await,
+        code(ErrCode::LpLocked)
+    );
+    sale.env.warp(lp_lock.unlock_time).await;
+    sale.env.send(&[withdraw], &[&sale.operator]).await.unwrap();
+    let owner_lp: Pubkey = anchor_spl::associated_token::get_associated_token_address(
+        &owner,
+        &lp_lock.lp_mint
+    );
+    assert_eq!(sale.env.token_balance(&owner_lp).await, lp_lock.amount);
+    assert!(!sale.env.exists(&pda::lp_lock(&sale.pool).0).await);
+    assert!(!sale.env.exists(&pda::lp_vault(&sale.pool).0).await);
+}
+
+#[tokio::test]
+async fn milestones_release_raise_until_refund_approved() {
+    const VOTE_END: i64 = OPEN_CLOSE + 200 + MIN_VOTING_PERIOD;
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    let milestone = |release_time: i64, share: u16| Milestone { release_time, share };
+    // shares must add up to the whole raise, first release after close
+    for milestones in [
+        vec![milestone(OPEN_CLOSE + 100, 3000), milestone(OPEN_CLOSE + 10_000, 6000)],
+        vec![milestone(OPEN_CLOSE - 1, 3000), milestone(OPEN_CLOSE + 10_000, 7000)],
+        vec![milestone(OPEN_CLOSE + 100, 3000), milestone(OPEN_CLOSE + 100, 7000)],
+    ] {
+        assert_error(
+            sale.env.send(
+                &[ignition::declare_milestones(&owner, &sale.pool, milestones)],
+                &[&sale.operator]
+            ).await,
+            code(ErrCode::InvalidMilestones)
+        );
+    }
+    let milestones: Vec<Milestone> = vec![
+        milestone(OPEN_CLOSE + 100, 3000),
+        milestone(VOTE_END + 1000, 7000)
+    ];
+    sale.env.send(
+        &[ignition::declare_milestones(&owner, &sale.pool, milestones.clone())],
+        &[&sale.operator]
+    ).await.unwrap();
+
+    sale.fund(REQUIRED_OFFER).await;
+    let (first, first_token) = sale.buyer(60_000).await;
+    let (second, second_token) = sale.buyer(40_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&first, &first_token, 60_000).await.unwrap();
+    sale.buy_early(&second, &second_token, 40_000).await.unwrap();
+    // schedule is fixed once sale opens
+    assert_error(
+        sale.env.send(
+            &[ignition::declare_milestones(&owner, &sale.pool, milestones)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    let purchase_mint: Pubkey = sale.purchase_mint;
+    let owner_purchase: Pubkey = sale.env.token_account(&owner, &purchase_mint, 0).await;
+    let release = ignition::release_milestone(&owner, &sale.pool, &purchase_mint);
+    assert_error(
+        sale.env.send(&[release.clone()], &[&sale.operator]).await,
+        code(ErrCode::MilestoneNotReached)
+    );
+    sale.env.warp(OPEN_CLOSE + 100).await;
+    sale.env.send(&[release.clone()], &[&sale.operator]).await.unwrap();
+    // 30% of net raise
+    assert_eq!(sale.env.token_balance(&owner_purchase).await, 29_700);
+    // released raise can no longer be refunded by cancelling
+    assert_eq!(sale.pool().await.released_purchase_amount, 29_700);
+    assert_error(
+        sale.env.send(&[ignition::cancel_pool(&owner, &sale.pool, false)], &[&sale.operator]).await,
+        code(ErrCode::RaiseAlreadyReleased)
+    );
+    sale.env.advance().await;
+    assert_error(
+        sale.env.send(&[release.clone()], &[&sale.operator]).await,
+        code(ErrCode::MilestoneNotReached)
+    );
+
+    // buyer opens a refund proposal, which needs a majority quorum
+    let propose = |quorum: u16| {
+        ignition::create_proposal(
+            &second.pubkey(),
+            &sale.pool,
+            0,
+            Proposer::Buyer,
+            ProposalKind::RefundMilestones,
+            VOTE_END,
+            quorum
+        )
+    };
+    let (short_quorum, refund_proposal) = (propose(MIN_REFUND_QUORUM - 1), propose(MIN_REFUND_QUORUM));
+    assert_error(sale.env.send(&[short_quorum], &[&second]).await, code(ErrCode::InvalidParam));
+    sale.env.send(&[refund_proposal], &[&second]).await.unwrap();
+    let proposal: Pubkey = pda::proposal(&sale.pool, 0).0;
+    let approve = ignition::approve_refund(&first.pubkey(), &sale.pool, &proposal);
+    sale.env.send(
+        &[ignition::cast_vote(&second.pubkey(), &sale.pool, &proposal, true)],
+        &[&second]
+    ).await.unwrap();
+    assert_error(
+        sale.env.send(&[approve.clone()], &[&first]).await,
+        code(ErrCode::ProposalNotApproved)
+    );
+    let refund_first = ignition::claim_milestone_refund(&first.pubkey(), &sale.pool, &purchase_mint);
+    assert_error(
+        sale.env.send(&[refund_first.clone()], &[&first]).await,
+        code(ErrCode::RefundVoteNotPassed)
+    );
+    sale.env.send(
+        &[ignition::cast_vote(&first.pubkey(), &sale.pool, &proposal, true)],
+        &[&first]
+    ).await.unwrap();
+    sale.env.warp(VOTE_END).await;
+    sale.env.send(&[ignition::finalize_proposal(&sale.pool, &proposal)], &[]).await.unwrap();
+    sale.env.send(&[approve.clone()], &[&first]).await.unwrap();
+    let plan: MilestonePlan = sale.env.account(&pda::milestone_plan(&sale.pool).0).await;
+    assert!(plan.refund_passed);
+    assert_eq!(plan.refundable_amount, 69_300);
+    assert!(sale.env.account::<Proposal>(&proposal).await.executed);
+    sale.env.advance().await;
+    assert_error(sale.env.send(&[approve], &[&first]).await, code(ErrCode::RefundVotePassed));
+
+    // unreleased tranches stay with buyers
+    sale.env.warp(VOTE_END + 1000).await;
+    assert_error(
+        sale.env.send(&[release], &[&sale.operator]).await,
+        code(ErrCode::RefundVotePassed)
+    );
+    // first claims more than the 30% of 594_000 its released share backs
+    sale.env.send(
+        &[ignition::enable_claims(&owner, &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    let claim = |wallet: &Keypair, amount: u64| {
+        let wallet: Pubkey = wallet.pubkey();
+        ignition::claim_offer(&wallet, &wallet, &wallet, &sale.pool, &sale.offer_mint, amount)
+    };
+    let (claim_first, claim_second, claim_more) = (
+        claim(&first, 200_000),
+        claim(&second, 118_800),
+        claim(&second, 1),
+    );
+    sale.env.send(&[claim_first], &[&first]).await.unwrap();
+    // 41_580 refund less 21_800 overclaimed ido token at sale price
+    sale.env.send(&[refund_first.clone()], &[&first]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&first_token).await, 39_400);
+    assert_eq!(sale.buyer_account(&first).await.total_amount, 200_000);
+    sale.env.advance().await;
+    assert_error(
+        sale.env.send(&[refund_first], &[&first]).await,
+        code(ErrCode::AlreadyRefunded)
+    );
+    sale.env.send(
+        &[ignition::claim_milestone_refund(&second.pubkey(), &sale.pool, &purchase_mint)],
+        &[&second]
+    ).await.unwrap();
+    assert_eq!(sale.env.token_balance(&second_token).await, 27_720);
+    let plan: MilestonePlan = sale.env.account(&pda::milestone_plan(&sale.pool).0).await;
+    assert_eq!(plan.refunded_amount, 39_400 + 27_720);
+
+    // refunded buyer only claims allocation backed by released raise
+    assert_eq!(sale.buyer_account(&second).await.total_amount, 118_800);
+    sale.env.send(&[claim_second], &[&second]).await.unwrap();
+    assert_error(
+        sale.env.send(&[claim_more], &[&second]).await,
+        code(ErrCode::AlreadyClaimedTotoalAmount)
+    );
+
+    // forfeited allocation is carried out by unsold policy
+    let pool: Pool = sale.pool().await;
+    assert_eq!(pool.forfeited_offer_amount, 394_000 + 277_200);
+    assert_eq!(pool.sold_offer_amount(), 318_800);
+    let before: u64 = sale.env.token_balance(&sale.operator_offer).await;
+    sale.env.send(
+        &[ignition::finalize_unsold(&sale.pool, &sale.offer_mint, Some(&sale.operator_offer))],
+        &[]
+    ).await.unwrap();
+    assert_eq!(sale.env.token_balance(&sale.operator_offer).await, before + RAISE * RATE - 318_800);
+}
+
+#[tokio::test]
+async fn undeclared_milestones_release_raise_at_tge() {
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    sale.fund(REQUIRED_OFFER).await;
+    let (wallet, token) = sale.buyer(60_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&wallet, &token, 60_000).await.unwrap();
+
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    let purchase_mint: Pubkey = sale.purchase_mint;
+    let owner_purchase: Pubkey = sale.env.token_account(&owner, &purchase_mint, 0).await;
+    let release = ignition::release_milestone(&owner, &sale.pool, &purchase_mint);
+    assert_error(
+        sale.env.send(&[release.clone()], &[&sale.operator]).await,
+        code(ErrCode::MilestoneNotReached)
+    );
+    sale.env.warp(TGE).await;
+    sale.env.send(&[release.clone()], &[&sale.operator]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&owner_purchase).await, 59_400);
+    let plan: MilestonePlan = sale.env.account(&pda::milestone_plan(&sale.pool).0).await;
+    assert_eq!(plan.milestones.len(), 1);
+    assert_eq!(plan.milestones[0].release_time, TGE);
+    sale.env.advance().await;
+    assert_error(
+        sale.env.send(&[release], &[&sale.operator]).await,
+        code(ErrCode::AllMilestonesReleased)
+    );
+}
+
+#[tokio::test]
+async fn protection_reserve_stays_with_cancelled_pool() {
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    sale.env.send(
+        &[ignition::set_protection_config(&owner, &sale.pool, 500, 5000)],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.fund(REQUIRED_OFFER).await;
+    let (buyer, buyer_token) = sale.buyer(60_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&buyer, &buyer_token, 60_000).await.unwrap();
+    sale.env.send(
+        &[ignition::cancel_pool(&owner, &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+
+    // buyers of a cancelled pool are owed the whole raise
+    sale.env.warp(TGE + 500).await;
+    let purchase_mint: Pubkey = sale.purchase_mint;
+    sale.env.token_account(&owner, &purchase_mint, 0).await;
+    assert_error(
+        sale.env.send(
+            &[ignition::reclaim_protection_reserve(&owner, &sale.pool, &purchase_mint)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+}
+
+#[tokio::test]
+async fn price_protection_refunds_unclaimed_buyers() {
+    const WINDOW: i64 = 500;
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    for (window, percentage) in [(WINDOW, 10001), (0, 1000), (WINDOW, 0)] {
+        assert_error(
+            sale.env.send(
+                &[ignition::set_protection_config(&owner, &sale.pool, window, percentage)],
+                &[&sale.operator]
+            ).await,
+            code(ErrCode::InvalidParam)
+        );
+    }
+    sale.env.send(
+        &[ignition::set_protection_config(&owner, &sale.pool, WINDOW, 5000)],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.fund(REQUIRED_OFFER).await;
+    let (first, first_token) = sale.buyer(60_000).await;
+    let (second, second_token) = sale.buyer(40_000).await;
+    let (third, third_token) = sale.buyer(10_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&first, &first_token, 60_000).await.unwrap();
+    sale.buy_early(&second, &second_token, 40_000).await.unwrap();
+    sale.buy_early(&third, &third_token, 10_000).await.unwrap();
+    // half of 108_900 net raise is reserved
+    assert_eq!(sale.pool().await.protection_reserve(), 54_450);
+
+    let purchase_mint: Pubkey = sale.purchase_mint;
+    let protect = |wallet: &Keypair| {
+        ignition::claim_price_protection(&wallet.pubkey(), &sale.pool, &purchase_mint)
+    };
+    let (protect_first, protect_second, protect_third) = (
+        protect(&first),
+        protect(&second),
+        protect(&third),
+    );
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    assert_error(
+        sale.env.send(&[protect_second.clone()], &[&second]).await,
+        code(ErrCode::ProtectionWindowClosed)
+    );
+    sale.env.send(
+        &[ignition::enable_claims(&owner, &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    let finalize = ignition::finalize_unsold(&sale.pool, &sale.offer_mint, Some(&sale.operator_offer));
+    sale.env.send(&[finalize.clone()], &[]).await.unwrap();
+    let finalized: u64 = sale.env.token_balance(&sale.operator_offer).await;
+
+    // any claim gives up protection
+    sale.env.warp(TGE).await;
+    let claim = ignition::claim_offer(
+        &third.pubkey(),
+        &third.pubkey(),
+        &third.pubkey(),
+        &sale.pool,
+        &sale.offer_mint,
+        1
+    );
+    sale.env.send(&[claim], &[&third]).await.unwrap();
+    assert_error(
+        sale.env.send(&[protect_third], &[&third]).await,
+        code(ErrCode::AlreadyClaimed)
+    );
+
+    sale.env.send(&[protect_second], &[&second]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&second_token).await, 39_600);
+    let buyer: Buyer = sale.buyer_account(&second).await;
+    assert_eq!((buyer.total_purchase, buyer.total_amount), (0, 0));
+    let pool: Pool = sale.pool().await;
+    assert_eq!(pool.protected_amount, 39_600);
+    assert_eq!(pool.sold_offer_amount(), (108_900 - 39_600) * RATE);
+    // returned allocation is carried out by another finalize
+    sale.env.send(&[finalize], &[]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&sale.operator_offer).await, finalized + 39_600 * RATE);
+    // 39_600 + 59_400 exceeds reserve
+    assert_error(
+        sale.env.send(&[protect_first.clone()], &[&first]).await,
+        code(ErrCode::ProtectionCapReached)
+    );
+
+    let owner_purchase: Pubkey = sale.env.token_account(&owner, &purchase_mint, 0).await;
+    let reclaim = ignition::reclaim_protection_reserve(&owner, &sale.pool, &purchase_mint);
+    assert_error(
+        sale.env.send(&[reclaim.clone()], &[&sale.operator]).await,
+        code(ErrCode::ProtectionWindowOpen)
+    );
+    sale.env.warp(TGE + WINDOW).await;
+    assert_error(
+        sale.env.send(&[protect_first], &[&first]).await,
+        code(ErrCode::ProtectionWindowClosed)
+    );
+    sale.env.send(&[reclaim.clone()], &[&sale.operator]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&owner_purchase).await, 54_450 - 39_600);
+    sale.env.advance().await;
+    assert_error(
+        sale.env.send(&[reclaim], &[&sale.operator]).await,
+        code(ErrCode::ProtectionReserveReclaimed)
+    );
+}
+
+#[tokio::test]
+async fn early_pool_follows_stake_tiers() {
+    const SNAPSHOT: i64 = START_TIME + 500;
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    let (stake_pool, stake_mint) = sale.stake_pool().await;
+    let tier = |threshold: u64, weight: u16| Tier { threshold, weight };
+    let unordered: Vec<Tier> = vec![tier(1000, 1), tier(100, 3)];
+    assert_error(
+        sale.env.send(
+            &[
+                ignition::set_tier_table(&owner, &sale.pool, &stake_pool, unordered, SNAPSHOT, false),
+            ],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidTiers)
+    );
+    let tiers: Vec<Tier> = vec![tier(100, 1), tier(1000, 3)];
+    // stake is never read live, a snapshot is required
+    assert_error(
+        sale.env.send(
+            &[ignition::set_tier_table(&owner, &sale.pool, &stake_pool, tiers.clone(), 0, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidTime)
+    );
+    let set_tiers = ignition::set_tier_table(&owner, &sale.pool, &stake_pool, tiers, SNAPSHOT, false);
+    sale.env.send(&[set_tiers.clone()], &[&sale.operator]).await.unwrap();
+    sale.fund(REQUIRED_OFFER).await;
+
+    let (bronze, bronze_token) = sale.staked_buyer(&stake_pool, &stake_mint, 500, 200_000).await;
+    let (gold, gold_token) = sale.staked_buyer(&stake_pool, &stake_mint, 2000, 100_000).await;
+    let (small, _) = sale.staked_buyer(&stake_pool, &stake_mint, 50, 0).await;
+    let (unregistered, unregistered_token) = sale.buyer(1_000).await;
+    let register = |wallet: &Keypair| {
+        ignition::register_tier(&wallet.pubkey(), &sale.pool, &stake_pool, true)
+    };
+    let register_small = register(&small);
+    let (register_bronze, register_gold) = (register(&bronze), register(&gold));
+    sale.env.warp(SNAPSHOT).await;
+    assert_error(
+        sale.env.send(&[register_small.clone()], &[&small]).await,
+        code(ErrCode::NotEnoughStaker)
+    );
+    sale.env.send(&[register_bronze], &[&bronze]).await.unwrap();
+    sale.env.send(&[register_gold], &[&gold]).await.unwrap();
+    let table: TierTable = sale.env.account(&pda::tier_table(&sale.pool).0).await;
+    assert_eq!((table.registered_count, table.total_registered_weight), (2, 4));
+    // table is fixed once wallets registered
+    assert_error(
+        sale.env.send(&[set_tiers], &[&sale.operator]).await,
+        code(ErrCode::TierTableLocked)
+    );
+
+    sale.env.warp(EARLY_OPEN).await;
+    assert_error(
+        sale.env.send(&[register_small], &[&small]).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+    assert_error(
+        sale.buy_early(&unregistered, &unregistered_token, 1_000).await,
+        code(ErrCode::NotRegistered)
+    );
+    // 500_000 early pool split 1:3, purchase crossing allocation is partially filled
+    let quote_bronze = |bought: bool| {
+        ignition::quote_purchase(&sale.pool, 200_000, Some(&bronze.pubkey()), bought, true)
+    };
+    let (before_purchase, after_purchase) = (quote_bronze(false), quote_bronze(true));
+    let quote: PurchaseQuote = sale.env.view(before_purchase).await;
+    assert_eq!((quote.remaining_amount, quote.wallet_remaining_amount), (500_000, 125_000));
+    assert_eq!(quote.purchase_amount, 126_262);
+    sale.buy_early(&bronze, &bronze_token, 200_000).await.unwrap();
+    let quote: PurchaseQuote = sale.env.view(after_purchase).await;
+    assert_eq!((quote.remaining_amount, quote.wallet_remaining_amount), (375_000, 0));
+    assert_eq!(quote.purchase_amount, 0);
+    assert_eq!(sale.buyer_account(&bronze).await.early_purchased, 125_000);
+    assert_eq!(sale.env.token_balance(&bronze_token).await, 200_000 - 126_262);
+    sale.env.advance().await;
+    assert_error(
+        sale.buy_early(&bronze, &bronze_token, 1_000).await,
+        code(ErrCode::ExceedsTierAllocation)
+    );
+    sale.buy_early(&gold, &gold_token, 100_000).await.unwrap();
+    assert_eq!(sale.buyer_account(&gold).await.early_purchased, 99_000);
+
+    // table and registrations back early pool allocations until early pool closes
+    let close_table = ignition::close_tier_table(&owner, &sale.pool);
+    assert_error(sale.env.send(&[close_table.clone()], &[]).await, code(ErrCode::InvalidTime));
+    sale.env.warp(EARLY_CLOSE).await;
+    sale.env.send(&[close_table], &[]).await.unwrap();
+    sale.env.send(
+        &[ignition::close_tier_registration(&bronze.pubkey(), &sale.pool)],
+        &[&bronze]
+    ).await.unwrap();
+    assert!(!sale.env.exists(&pda::tier_table(&sale.pool).0).await);
+    assert!(!sale.env.exists(&pda::tier_registration(&sale.pool, &bronze.pubkey()).0).await);
+}
+
+#[tokio::test]
+async fn stake_tiers_use_snapshot_balance() {
+    const SNAPSHOT: i64 = START_TIME + 500;
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    let (stake_pool, stake_mint) = sale.stake_pool().await;
+    let tiers: Vec<Tier> = vec![Tier { threshold: 100, weight: 1 }];
+    assert_error(
+        sale.env.send(
+            &[
+                ignition::set_tier_table(&owner, &sale.pool, &stake_pool, tiers.clone(), EARLY_OPEN, true),
+            ],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidTime)
+    );
+    sale.env.send(
+        &[ignition::set_tier_table(&owner, &sale.pool, &stake_pool, tiers, SNAPSHOT, true)],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.fund(REQUIRED_OFFER).await;
+
+    let (holder, _) = sale.staked_buyer(&stake_pool, &stake_mint, 500, 0).await;
+    let (withdrawer, _) = sale.staked_buyer(&stake_pool, &stake_mint, 500, 0).await;
+    let register = |wallet: &Keypair| {
+        ignition::register_tier(&wallet.pubkey(), &sale.pool, &stake_pool, true)
+    };
+    let (register_holder, register_withdrawer) = (register(&holder), register(&withdrawer));
+    assert_error(
+        sale.env.send(&[register_holder.clone()], &[&holder]).await,
+        code(ErrCode::InvalidTime)
+    );
+
+    sale.env.warp(SNAPSHOT + 1).await;
+    // stake after snapshot does not count
+    let (flash, _) = sale.staked_buyer(&stake_pool, &stake_mint, 5_000, 0).await;
+    assert_error(
+        sale.env.send(&[register(&flash)], &[&flash]).await,
+        code(ErrCode::NotEnoughStaker)
+    );
+    // stake must be held from snapshot on
+    sale.env.send(
+        &[stake::withdraw(&withdrawer.pubkey(), &stake_pool, &stake_mint, 450)],
+        &[&withdrawer]
+    ).await.unwrap();
+    assert_error(
+        sale.env.send(&[register_withdrawer], &[&withdrawer]).await,
+        code(ErrCode::NotEnoughStaker)
+    );
+    sale.env.send(&[register_holder], &[&holder]).await.unwrap();
+    let checkpoints: StakeCheckpoints = sale.env.account(
+        &pda::stake_checkpoints(&stake_pool, &withdrawer.pubkey()).0
+    ).await;
+    assert_eq!(checkpoints.balance_at(SNAPSHOT), 500);
+    assert_eq!(checkpoints.min_balance_since(SNAPSHOT), 50);
+}
+
+#[tokio::test]
+async fn unfunded_pool_fails_and_closes() {
+    let mut sale: Sale = Sale::start().await;
+    sale.fund(1_000_000).await;
+    let (buyer, buyer_token) = sale.buyer(1_000).await;
+
+    sale.env.warp(EARLY_OPEN).await;
+    assert_error(
+        sale.buy_early(&buyer, &buyer_token, 1_000).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+    assert_eq!(sale.refresh().await, PoolStatus::Failed);
+
+    // offer must be returned before the pool settles
+    assert_error(
+        sale.env.send(
+            &[ignition::finalize_pool(&sale.operator.pubkey(), &sale.pool, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::NotSettled)
+    );
+    assert_error(
+        sale.env.send(
+            &[
+                ignition::withdraw_offer(
+                    &sale.operator.pubkey(),
+                    &sale.pool,
+                    &sale.offer_mint,
+                    &sale.operator_offer,
+                    1_000_001,
+                    false
+                ),
+            ],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidAmount)
+    );
+    sale.env.send(
+        &[
+            ignition::withdraw_offer(
+                &sale.operator.pubkey(),
+                &sale.pool,
+                &sale.offer_mint,
+                &sale.operator_offer,
+                1_000_000,
+                false
+            ),
+        ],
+        &[&sale.operator]
+    ).await.unwrap();
+    let operator_offer: Pubkey = sale.operator_offer;
+    assert_eq!(sale.env.token_balance(&operator_offer).await, REQUIRED_OFFER);
+
+    // not finalized yet
+    assert_error(
+        sale.env.send(
+            &[ignition::close_pool(&sale.operator.pubkey(), &sale.operator.pubkey(), &sale.pool)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+    sale.env.send(
+        &[ignition::finalize_pool(&sale.operator.pubkey(), &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    assert_error(
+        sale.env.send(
+            &[ignition::close_pool(&sale.operator.pubkey(), &sale.operator.pubkey(), &sale.pool)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::VaultNotClosed)
+    );
+    sale.env.send(
+        &[ignition::close_offer_vault(&sale.operator.pubkey(), &sale.pool)],
+        &[]
+    ).await.unwrap();
+    sale.env.send(
+        &[ignition::close_pool(&sale.operator.pubkey(), &sale.operator.pubkey(), &sale.pool)],
+        &[&sale.operator]
+    ).await.unwrap();
+    let pool: Pubkey = sale.pool;
+    assert!(!sale.env.exists(&pool).await);
+    assert!(!sale.env.exists(&pda::pool_metadata(&pool).0).await);
+}
+
+#[tokio::test]
+async fn cancel_refunds_current_cooling_off_period_only() {
+    let mut sale: Sale = Sale::start().await;
+    sale.fund(REQUIRED_OFFER).await;
+    let (wallet, token) = sale.buyer(300_000).await;
+
+    sale.env.warp(EARLY_CLOSE).await;
+    sale.buy_open(&wallet, &token, 101_000).await.unwrap();
+    let first: Buyer = sale.buyer_account(&wallet).await;
+
+    // a later purchase does not reopen cooling-off for the earlier one
+    sale.env.warp(EARLY_CLOSE + LOCKUP_DURATION + 1).await;
+    sale.buy_open(&wallet, &token, 101_000).await.unwrap();
+    let balance: u64 = sale.env.token_balance(&token).await;
+    let buyer: Buyer = sale.buyer_account(&wallet).await;
+    assert_eq!(buyer.cancellable_purchase, buyer.total_purchase - first.total_purchase);
+    sale.env.send(&[ignition::cancel_purchase(&wallet.pubkey(), &sale.pool, &token)], &[&wallet]).await.unwrap();
+    let buyer: Buyer = sale.buyer_account(&wallet).await;
+    assert_eq!(buyer.total_purchase, first.total_purchase);
+    assert_eq!(buyer.total_amount, first.total_amount);
+    // both purchases net the same amount
+    assert_eq!(sale.env.token_balance(&token).await, balance + first.total_purchase);
+    assert_eq!(sale.pool().await.purchased_amount, first.total_purchase);
+    assert_error(
+        sale.env.send(&[ignition::cancel_purchase(&wallet.pubkey(), &sale.pool, &token)], &[&wallet]).await,
+        code(ErrCode::InvalidAmount)
+    );
+}
+
+#[tokio::test]
+async fn cancelled_pool_refunds_buyers() {
+    let mut sale: Sale = Sale::start().await;
+    sale.fund(REQUIRED_OFFER).await;
+    let (first, first_token) = sale.buyer(100_000).await;
+    let (second, second_token) = sale.buyer(100_000).await;
+
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&first, &first_token, 100_000).await.unwrap();
+    sale.buy_early(&second, &second_token, 100_000).await.unwrap();
+
+    // first buyer backs out within the cooling-off period
+    sale.env.warp(EARLY_OPEN + LOCKUP_DURATION).await;
+    sale.env.send(
+        &[ignition::cancel_purchase(&first.pubkey(), &sale.pool, &first_token)],
+        &[&first]
+    ).await.unwrap();
+    assert_eq!(sale.env.token_balance(&first_token).await, 100_000);
+    assert_eq!(sale.buyer_account(&first).await.total_purchase, 0);
+    assert_error(
+        sale.env.send(
+            &[ignition::cancel_purchase(&first.pubkey(), &sale.pool, &first_token)],
+            &[&first]
+        ).await,
+        code(ErrCode::InvalidAmount)
+    );
+    sale.env.send(&[ignition::close_buyer(&first.pubkey(), &sale.pool)], &[&first]).await.unwrap();
+
+    // refunds only once the pool is cancelled
+    assert_error(
+        sale.env.send(
+            &[ignition::user_withdraw_purchase(&second.pubkey(), &sale.pool, &second_token, 99_000)],
+            &[&second]
+        ).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+    sale.env.send(
+        &[ignition::cancel_pool(&sale.operator.pubkey(), &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    assert_eq!(sale.pool().await.status, PoolStatus::Cancelled);
+    assert_error(
+        sale.env.send(
+            &[ignition::cancel_pool(&sale.operator.pubkey(), &sale.pool, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidStatusTransition)
+    );
+    assert_error(
+        sale.env.send(
+            &[ignition::enable_claims(&sale.operator.pubkey(), &sale.pool, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidStatusTransition)
+    );
+
+    assert_error(
+        sale.env.send(
+            &[ignition::user_withdraw_purchase(&second.pubkey(), &sale.pool, &second_token, 99_001)],
+            &[&second]
+        ).await,
+        code(ErrCode::InvalidAmount)
+    );
+    sale.env.send(
+        &[ignition::user_withdraw_purchase(&second.pubkey(), &sale.pool, &second_token, 99_000)],
+        &[&second]
+    ).await.unwrap();
+    assert_eq!(sale.env.token_balance(&second_token).await, 100_000);
+    sale.env.send(&[ignition::close_buyer(&second.pubkey(), &sale.pool)], &[&second]).await.unwrap();
+
+    sale.env.send(
+        &[
+            ignition::withdraw_offer(
+                &sale.operator.pubkey(),
+                &sale.pool,
+                &sale.offer_mint,
+                &sale.operator_offer,
+                REQUIRED_OFFER,
+                false
+            ),
+        ],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.env.send(
+        &[ignition::finalize_pool(&sale.operator.pubkey(), &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    // purchase vault rent goes back to the first buyer who created it
+    sale.env.send(&[ignition::close_purchase_vault(&first.pubkey(), &sale.pool)], &[]).await.unwrap();
+    sale.env.send(
+        &[ignition::close_offer_vault(&sale.operator.pubkey(), &sale.pool)],
+        &[]
+    ).await.unwrap();
+    sale.env.send(
+        &[ignition::close_pool(&sale.operator.pubkey(), &sale.operator.pubkey(), &sale.pool)],
+        &[&sale.operator]
+    ).await.unwrap();
+}
+
+#[tokio::test]
+async fn finalized_pool_sweeps_vaults_and_closes() {
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    sale.fund(REQUIRED_OFFER).await;
+    let (wallet, token) = sale.buyer(100_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&wallet, &token, 100_000).await.unwrap();
+
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    sale.env.send(
+        &[ignition::enable_claims(&owner, &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.env.send(
+        &[ignition::finalize_unsold(&sale.pool, &sale.offer_mint, Some(&sale.operator_offer))],
+        &[]
+    ).await.unwrap();
+    let purchase_mint: Pubkey = sale.purchase_mint;
+    let owner_purchase: Pubkey = sale.env.token_account(&owner, &purchase_mint, 0).await;
+    let sweep = |milestone_plan: bool| {
+        ignition::sweep_vaults(&owner, &sale.pool, &purchase_mint, &sale.offer_mint, milestone_plan)
+    };
+    let (sweep_early, sweep_fee, sweep_dust) = (sweep(false), sweep(false), sweep(true));
+    assert_error(
+        sale.env.send(&[sweep_early], &[&sale.operator]).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+    sale.env.warp(TGE + 5000).await;
+    sale.env.send(
+        &[ignition::finalize_pool(&owner, &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+
+    // token fee share leaves, unclaimed allocation and unreleased raise stay
+    let before: u64 = sale.env.token_balance(&sale.operator_offer).await;
+    sale.env.send(&[sweep_fee], &[&sale.operator]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&sale.operator_offer).await, before + RAISE * RATE / 100);
+    assert_eq!(sale.env.token_balance(&pda::offer_vault(&sale.pool).0).await, 990_000);
+    assert_eq!(sale.env.token_balance(&owner_purchase).await, 0);
+    assert_error(
+        sale.env.send(&[ignition::close_offer_vault(&owner, &sale.pool)], &[]).await,
+        code(ErrCode::NotSettled)
+    );
+
+    // once buyer claimed and raise is released nothing is left to sweep
+    let release = ignition::release_milestone(&owner, &sale.pool, &purchase_mint);
+    sale.env.send(&[release], &[&sale.operator]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&owner_purchase).await, 99_000);
+    let wallet_key: Pubkey = wallet.pubkey();
+    let claim = ignition::claim_offer(
+        &wallet_key,
+        &wallet_key,
+        &wallet_key,
+        &sale.pool,
+        &sale.offer_mint,
+        990_000
+    );
+    sale.env.send(&[claim], &[&wallet]).await.unwrap();
+    assert_eq!(sale.pool().await.fund_claimed_amount, 990_000);
+    assert_error(sale.env.send(&[sweep_dust], &[&sale.operator]).await, code(ErrCode::InvalidAmount));
+    sale.env.send(&[ignition::close_offer_vault(&owner, &sale.pool)], &[]).await.unwrap();
+    // milestone plan outlives the vault it pays out of
+    assert_error(
+        sale.env.send(&[ignition::close_milestone_plan(&owner, &sale.pool)], &[]).await,
+        code(ErrCode::VaultNotClosed)
+    );
+    sale.env.send(&[ignition::close_purchase_vault(&wallet_key, &sale.pool)], &[]).await.unwrap();
+    sale.env.advance().await;
+    sale.env.send(&[ignition::close_milestone_plan(&owner, &sale.pool)], &[]).await.unwrap();
+    assert!(!sale.env.exists(&pda::milestone_plan(&sale.pool).0).await);
+    sale.env.send(
+        &[ignition::close_pool(&owner, &owner, &sale.pool)],
+        &[&sale.operator]
+    ).await.unwrap();
+}
+
+#[tokio::test]
+async fn pause_blocks_value_moving_instructions() {
+    let mut sale: Sale = Sale::start().await;
+    let operator: Pubkey = sale.operator.pubkey();
+    sale.env.send(&[ignition::set_paused(&operator, true)], &[&sale.operator]).await.unwrap();
+    assert_error(
+        sale.env.send(
+            &[
+                ignition::fund_offer(
+                    &operator,
+                    &sale.pool,
+                    &sale.offer_mint,
+                    &sale.operator_offer,
+                    1
+                ),
+            ],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::Paused)
+    );
+    sale.env.send(&[ignition::set_paused(&operator, false)], &[&sale.operator]).await.unwrap();
+
+    sale.env.send(
+        &[ignition::set_pool_paused(&operator, &sale.pool, true)],
+        &[&sale.operator]
+    ).await.unwrap();
+    assert_error(
+        sale.env.send(
+            &[
+                ignition::fund_offer(
+                    &operator,
+                    &sale.pool,
+                    &sale.offer_mint,
+                    &sale.operator_offer,
+                    1
+                ),
+            ],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::Paused)
+    );
+    sale.env.send(
+        &[ignition::set_pool_paused(&operator, &sale.pool, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.fund(REQUIRED_OFFER).await;
+
+    // funding in the wrong token
+    let other_mint: Pubkey = sale.env.create_mint(6).await;
+    let other_token: Pubkey = sale.env.token_account(&operator, &other_mint, 1).await;
+    assert_error(
+        sale.env.send(
+            &[ignition::fund_offer(&operator, &sale.pool, &other_mint, &other_token, 1)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidMint)
+    );
+}
+
+#[tokio::test]
+async fn schedule_and_fee_updates_are_validated() {
+    let mut sale: Sale = Sale::start().await;
+    let operator: Pubkey = sale.operator.pubkey();
+
+    assert_error(
+        sale.env.send(
+            &[ignition::update_fees(&operator, &sale.pool, 10001, 100, 100, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidTokenFeePercentage)
+    );
+    assert_error(
+        sale.env.send(
+            &[ignition::update_fees(&operator, &sale.pool, 100, 5001, 100, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidParam)
+    );
+    sale.env.send(
+        &[ignition::update_fees(&operator, &sale.pool, 0, 200, 300, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    let pool: Pool = sale.pool().await;
+    assert_eq!(pool.early_pool_participation_fee_percentage, 200);
+    assert_eq!(pool.open_pool_participation_fee_percentage, 300);
+
+    assert_error(
+        sale.env.send(
+            &[ignition::update_time(&operator, &sale.pool, EARLY_OPEN - 1, OPEN_CLOSE, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidTime)
+    );
+    assert_error(
+        sale.env.send(
+            &[ignition::update_time(&operator, &sale.pool, EARLY_CLOSE, TGE + 1, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidTime)
+    );
+    sale.env.send(
+        &[ignition::update_time(&operator, &sale.pool, EARLY_CLOSE + 10, OPEN_CLOSE, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    assert_eq!(sale.pool().await.open_pool_open_time, EARLY_CLOSE + 10);
+
+    // tge may move twice, bounded by the original date
+    assert_error(
+        sale.env.send(
+            &[ignition::update_tge_date(&operator, &sale.pool, OPEN_CLOSE - 1, None, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidTime)
+    );
+    assert_error(
+        sale.env.send(
+            &[ignition::update_tge_date(&operator, &sale.pool, TGE + 86400 * 730 + 1, None, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::NotAllowedToAdjustTGEDateTooFar)
+    );
+    for tge in [TGE + 100, TGE + 200] {
+        sale.env.send(
+            &[ignition::update_tge_date(&operator, &sale.pool, tge, None, false)],
+            &[&sale.operator]
+        ).await.unwrap();
+    }
+    assert_error(
+        sale.env.send(
+            &[ignition::update_tge_date(&operator, &sale.pool, TGE + 300, None, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::NotAllowedToAdjustTGEDateExceedsAttempts)
+    );
+    let tge_history: Pubkey = pda::tge_history(&sale.pool).0;
+    let history: paid_ignition::TgeHistory = sale.env.account(&tge_history).await;
+    assert_eq!(history.changes.len(), 2);
+
+    // pool failed unfunded, fees are frozen
+    sale.env.warp(EARLY_OPEN).await;
+    assert_error(
+        sale.env.send(
+            &[ignition::update_fees(&operator, &sale.pool, 0, 0, 0, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::InvalidPoolStatus)
+    );
+}
+
+#[tokio::test]
+async fn tge_date_is_frozen_after_tge() {
+    let mut sale: Sale = Sale::start().await;
+    sale.fund(REQUIRED_OFFER).await;
+    let (buyer, buyer_token) = sale.buyer(1_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&buyer, &buyer_token, 1_000).await.unwrap();
+    sale.env.warp(TGE).await;
+    let owner: Pubkey = sale.operator.pubkey();
+    assert_error(
+        sale.env.send(
+            &[ignition::update_tge_date(&owner, &sale.pool, TGE + 100, None, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::TGEAlreadyPassed)
+    );
+}
+
+#[tokio::test]
+async fn tge_update_follows_buyer_proposal() {
+    const VOTE_END: i64 = OPEN_CLOSE + 100 + MIN_VOTING_PERIOD;
+    const SECOND_VOTE_END: i64 = VOTE_END + 100 + MIN_VOTING_PERIOD;
+    // tge moved past both votes before governance is turned on
+    const NEW_TGE: i64 = SECOND_VOTE_END + 1000;
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    sale.env.send(
+        &[ignition::update_tge_date(&owner, &sale.pool, NEW_TGE, None, false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.env.send(
+        &[ignition::set_tge_governance(&owner, &sale.pool, true)],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.fund(REQUIRED_OFFER).await;
+    let (first, first_token) = sale.buyer(60_000).await;
+    let (second, second_token) = sale.buyer(40_000).await;
+    sale.env.warp(EARLY_OPEN).await;
+    sale.buy_early(&first, &first_token, 60_000).await.unwrap();
+    sale.buy_early(&second, &second_token, 40_000).await.unwrap();
+    sale.env.warp(OPEN_CLOSE + 1).await;
+    assert_error(
+        sale.env.send(
+            &[ignition::update_tge_date(&owner, &sale.pool, NEW_TGE + 500, None, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::ProposalNotApproved)
+    );
+
+    // only pool owner or super admin opens proposals, buyers only refund ones
+    let kind = ProposalKind::ExtendTge { tge_date: NEW_TGE + 500 };
+    assert_error(
+        sale.env.send(
+            &[
+                ignition::create_proposal(
+                    &first.pubkey(),
+                    &sale.pool,
+                    0,
+                    Proposer::Buyer,
+                    kind,
+                    VOTE_END,
+                    5000
+                ),
+            ],
+            &[&first]
+        ).await,
+        code(ErrCode::NotProposer)
+    );
+    // protocol minimums on voting period and quorum
+    for (deadline, quorum, error) in [
+        (OPEN_CLOSE + 100, 5000, ErrCode::InvalidTime),
+        (VOTE_END, MIN_PROPOSAL_QUORUM - 1, ErrCode::InvalidParam),
+    ] {
+        assert_error(
+            sale.env.send(
+                &[ignition::create_proposal(&owner, &sale.pool, 0, Proposer::Owner, kind, deadline, quorum)],
+                &[&sale.operator]
+            ).await,
+            code(error)
+        );
+    }
+    sale.env.send(
+        &[ignition::create_proposal(&owner, &sale.pool, 0, Proposer::Owner, kind, VOTE_END, 5000)],
+        &[&sale.operator]
+    ).await.unwrap();
+    let proposal: Pubkey = pda::proposal(&sale.pool, 0).0;
+    let vote_for = ignition::cast_vote(&first.pubkey(), &sale.pool, &proposal, true);
+    let vote_against = ignition::cast_vote(&second.pubkey(), &sale.pool, &proposal, false);
+    sale.env.send(&[vote_against], &[&second]).await.unwrap();
+    sale.env.send(&[vote_for.clone()], &[&first]).await.unwrap();
+    sale.env.advance().await;
+    assert!(sale.env.send(&[vote_for], &[&first]).await.is_err());
+    let finalize = ignition::finalize_proposal(&sale.pool, &proposal);
+    assert_error(sale.env.send(&[finalize.clone()], &[]).await, code(ErrCode::VotingNotEnded));
+    // vote record keeps wallet from voting twice while proposal is active
+    let close_vote = ignition::close_proposal_vote(&second.pubkey(), &proposal);
+    assert_error(
+        sale.env.send(&[close_vote.clone()], &[&second]).await,
+        code(ErrCode::ProposalNotSettled)
+    );
+    sale.env.warp(VOTE_END).await;
+    sale.env.send(&[finalize], &[]).await.unwrap();
+    let account: Proposal = sale.env.account(&proposal).await;
+    assert_eq!(account.status, ProposalStatus::Approved);
+    assert_eq!((account.votes_for, account.votes_against), (59_400, 39_600));
+    sale.env.send(&[close_vote], &[&second]).await.unwrap();
+    // approved proposal stays until acted on
+    let close_proposal = ignition::close_proposal(&owner, &sale.pool, &proposal);
+    assert_error(
+        sale.env.send(&[close_proposal.clone()], &[]).await,
+        code(ErrCode::ProposalNotSettled)
+    );
+
+    // approval covers the proposed date once
+    assert_error(
+        sale.env.send(
+            &[ignition::update_tge_date(&owner, &sale.pool, NEW_TGE + 400, Some(&proposal), false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::ProposalNotApproved)
+    );
+    sale.env.send(
+        &[ignition::update_tge_date(&owner, &sale.pool, NEW_TGE + 500, Some(&proposal), false)],
+        &[&sale.operator]
+    ).await.unwrap();
+    assert_eq!(sale.pool().await.tge_date, NEW_TGE + 500);
+    assert!(sale.env.account::<Proposal>(&proposal).await.executed);
+    // both adjustment attempts are used up by now
+    sale.env.advance().await;
+    sale.env.send(&[close_proposal], &[]).await.unwrap();
+    assert!(!sale.env.exists(&proposal).await);
+    assert_error(
+        sale.env.send(
+            &[ignition::update_tge_date(&owner, &sale.pool, NEW_TGE + 500, Some(&proposal), false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::NotAllowedToAdjustTGEDateExceedsAttempts)
+    );
+
+    // super admin proposal short of quorum is rejected
+    let admin: Pubkey = sale.admin.pubkey();
+    sale.env.send(
+        &[
+            ignition::create_proposal(
+                &admin,
+                &sale.pool,
+                1,
+                Proposer::SuperAdmin,
+                ProposalKind::ExtendTge { tge_date: NEW_TGE + 600 },
+                SECOND_VOTE_END,
+                10000
+            ),
+        ],
+        &[&sale.admin]
+    ).await.unwrap();
+    let proposal: Pubkey = pda::proposal(&sale.pool, 1).0;
+    let vote_for = ignition::cast_vote(&first.pubkey(), &sale.pool, &proposal, true);
+    sale.env.send(&[vote_for], &[&first]).await.unwrap();
+    sale.env.warp(SECOND_VOTE_END).await;
+    let late_vote = ignition::cast_vote(&second.pubkey(), &sale.pool, &proposal, true);
+    assert_error(sale.env.send(&[late_vote], &[&second]).await, code(ErrCode::VotingEnded));
+    sale.env.send(&[ignition::finalize_proposal(&sale.pool, &proposal)], &[]).await.unwrap();
+    let account: Proposal = sale.env.account(&proposal).await;
+    assert_eq!(account.status, ProposalStatus::Rejected);
+    assert_eq!(sale.pool().await.proposal_count, 2);
+    // rent of rejected proposal goes back to super admin who opened it
+    sale.env.send(&[ignition::close_proposal(&admin, &sale.pool, &proposal)], &[]).await.unwrap();
+    sale.env.send(
+        &[ignition::close_proposal_vote(&first.pubkey(), &proposal)],
+        &[&first]
+    ).await.unwrap();
+    assert!(!sale.env.exists(&pda::proposal_vote(&proposal, &first.pubkey()).0).await);
+}
+
+#[tokio::test]
+async fn config_is_initialized_by_upgrade_authority_only() {
+    let mut env: Env = Env::start().await;
+    let stranger: Keypair = env.wallet().await;
+    assert_error(
+        env.send(&[ignition::init_config(&stranger.pubkey())], &[&stranger]).await,
+        code(ErrCode::NotUpgradeAuthority)
+    );
+    let admin: Keypair = env.admin().await;
+    env.send(&[ignition::init_config(&admin.pubkey())], &[&admin]).await.unwrap();
+    assert!(env.exists(&pda::ignition_role(Role::SuperAdmin, &admin.pubkey()).0).await);
+}
+
+#[tokio::test]
+async fn ownership_and_roles_are_guarded() {
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    let next_owner: Keypair = sale.env.wallet().await;
+
+    assert_error(
+        sale.env.send(&[ignition::cancel_owner_transfer(&owner, &sale.pool)], &[&sale.operator]).await,
+        code(ErrCode::NoPendingOwner)
+    );
+    assert_error(
+        sale.env.send(
+            &[ignition::accept_owner(&next_owner.pubkey(), &sale.pool)],
+            &[&next_owner]
+        ).await,
+        code(ErrCode::NoPendingOwner)
+    );
+    assert_error(
+        sale.env.send(&[ignition::propose_owner(&owner, &sale.pool, &owner)], &[&sale.operator]).await,
+        code(ErrCode::InvalidParam)
+    );
+    sale.env.send(
+        &[ignition::propose_owner(&owner, &sale.pool, &next_owner.pubkey())],
+        &[&sale.operator]
+    ).await.unwrap();
+    let stranger: Keypair = sale.env.wallet().await;
+    assert_error(
+        sale.env.send(&[ignition::accept_owner(&stranger.pubkey(), &sale.pool)], &[&stranger]).await,
+        code(ErrCode::NotPendingOwner)
+    );
+    sale.env.send(
+        &[ignition::accept_owner(&next_owner.pubkey(), &sale.pool)],
+        &[&next_owner]
+    ).await.unwrap();
+    let pool: Pool = sale.pool().await;
+    assert_eq!(pool.owner, next_owner.pubkey());
+    assert_eq!(pool.pending_owner, Pubkey::default());
+    // previous owner no longer controls the pool as owner, new owner needs no role
+    assert_error(
+        sale.env.send(&[ignition::cancel_pool(&owner, &sale.pool, false)], &[&sale.operator]).await,
+        code(ErrCode::NotPoolAuthority)
+    );
+    assert_error(
+        sale.env.send(
+            &[ignition::update_fees(&owner, &sale.pool, 0, 200, 300, false)],
+            &[&sale.operator]
+        ).await,
+        code(ErrCode::NotPoolAuthority)
+    );
+    sale.env.send(
+        &[ignition::update_time(&next_owner.pubkey(), &sale.pool, EARLY_CLOSE, OPEN_CLOSE, false)],
+        &[&next_owner]
+    ).await.unwrap();
+    // pool operator and fee manager roles act on the pool without owning it
+    assert_error(
+        sale.env.send(
+            &[ignition::update_time(&stranger.pubkey(), &sale.pool, EARLY_CLOSE, OPEN_CLOSE, true)],
+            &[&stranger]
+        ).await,
+        u32::from(anchor_lang::error::ErrorCode::AccountNotInitialized)
+    );
+    sale.env.send(
+        &[ignition::update_time(&owner, &sale.pool, EARLY_CLOSE, OPEN_CLOSE, true)],
+        &[&sale.operator]
+    ).await.unwrap();
+    let update_fees = ignition::update_fees(&owner, &sale.pool, 0, 200, 300, true);
+    sale.env.send(&[update_fees.clone()], &[&sale.operator]).await.unwrap();
+
+    let admin: Pubkey = sale.admin.pubkey();
+    assert_error(
+        sale.env.send(&[ignition::revoke_role(&admin, Role::SuperAdmin, &admin)], &[&sale.admin]).await,
+        code(ErrCode::CannotRevokeOwnSuperAdmin)
+    );
+    sale.env.send(
+        &[ignition::revoke_role(&admin, Role::FeeManager, &owner)],
+        &[&sale.admin]
+    ).await.unwrap();
+    assert!(!sale.env.exists(&pda::ignition_role(Role::FeeManager, &owner).0).await);
+    assert_error(
+        sale.env.send(&[update_fees], &[&sale.operator]).await,
+        u32::from(anchor_lang::error::ErrorCode::AccountNotInitialized)
+    );
+}
+
+#[tokio::test]
+async fn project_rounds_share_offer_vault_and_vest_independently() {
+    const SEED_OPEN: i64 = START_TIME + 100;
+    const PUBLIC_OPEN: i64 = START_TIME + 200;
+    const PUBLIC_CLOSE: i64 = START_TIME + 400;
+    const FUNDED: u64 = 200_000;
+    let mut sale: Sale = Sale::start().await;
+    let owner: Pubkey = sale.operator.pubkey();
+    let project: Pubkey = sale.create_project().await;
+
+    let (seed, seed_token) = sale.buyer(20_000).await;
+    let (outsider, _) = sale.buyer(20_000).await;
+    let (late, _) = sale.buyer(20_000).await;
+    let wallets: Vec<Pubkey> = vec![seed.pubkey(), late.pubkey(), Pubkey::new_unique()];
+    // seed round sells at double rate to allowlist, half vested at tge and half one period later
+    let seed_round: RoundParams = RoundParams {
+        rate: RATE * 2,
+        max_purchase_per_wallet: 6_000,
+        ..round(
+            SEED_OPEN,
+            PUBLIC_OPEN,
+            Eligibility::Allowlist { merkle_root: allowlist::root(&wallets) },
+            Vesting {
+                tge_date: START_TIME + 300,
+                tge_percentage: 0,
+                vesting_cliff: 0,
+                vesting_frequency: 100,
+                number_of_vesting: 2,
+            }
+        )
+    };
+    sale.add_round(&project, seed_round).await.unwrap();
+    // rounds may not overlap
+    assert_error(
+        sale.add_round(
+            &project,
+            round(START_TIME + 150, PUBLIC_CLOSE, Eligibility::Public, unlocked_at(PUBLIC_CLOSE))
+        ).await,
+        code(ErrCode::InvalidRound)
+    );
+    sale.add_round(
+        &project,
+        round(PUBLIC_OPEN, PUBLIC_CLOSE, Eligibility::Public, unlocked_at(START_TIME + 500))
+    ).await.unwrap();
+    sale.fund_project(&project, FUNDED).await.unwrap();
+    let vault: Pubkey = pda::project_offer_vault(&project).0;
+    assert_eq!(sale.env.token_balance(&vault).await, FUNDED);
+
+    let seed_proof = RoundEligibility::Allowlist {
+        proof: allowlist::proof(&wallets, &seed.pubkey()).unwrap(),
+    };
+    assert_error(
+        sale.buy_round(&seed, &project, 0, 1_000, seed_proof.clone()).await,
+        code(ErrCode::RoundNotOpen)
+    );
+
+    sale.env.warp(SEED_OPEN).await;
+    // proof of another wallet does not admit outsider
+    assert_error(
+        sale.buy_round(&outsider, &project, 0, 1_000, seed_proof.clone()).await,
+        code(ErrCode::NotEligible)
+    );
+    // purchase crossing wallet cap is partially filled
+    sale.buy_round(&seed, &project, 0, 10_000, seed_proof.clone()).await.unwrap();
+    assert_error(
+        sale.buy_round(&seed, &project, 0, 1, seed_proof.clone()).await,
+        code(ErrCode::WalletCapReached)
+    );
+    assert_eq!(sale.env.token_balance(&seed_token).await, 14_000);
+    let seed_buyer: RoundBuyer = sale.round_buyer(&project, 0, &seed).await;
+    assert_eq!(seed_buyer.purchase_amount, 6_000);
+    assert_eq!(seed_buyer.offer_amount, 6_000 * RATE * 2);
+
+    sale.env.warp(PUBLIC_OPEN).await;
+    assert_error(
+        sale.buy_round(&seed, &project, 0, 1_000, seed_proof).await,
+        code(ErrCode::RoundNotOpen)
+    );
+    assert_error(
+        sale.buy_round(&seed, &project, 2, 1_000, RoundEligibility::Public).await,
+        code(ErrCode::InvalidRound)
+    );
+    sale.buy_round(&seed, &project, 1, 5_000, RoundEligibility::Public).await.unwrap();
+    // sold offer may not exceed funded offer
+    assert_error(
+        sale.buy_round(&late, &project, 1, 10_000, RoundEligibility::Public).await,
+        code(ErrCode::NotFunded)
+    );
+    let project_account: Project = sale.env.account(&project).await;
+    assert_eq!(project_account.sold_offer_amount, 120_000 + 50_000);
+    assert_eq!(project_account.rounds[0].purchased_amount, 6_000);
+    assert_eq!(project_account.rounds[1].purchased_amount, 5_000);
+    assert_eq!(sale.round_buyer(&project, 1, &seed).await.offer_amount, 50_000);
+
+    // seed round vests while public round is still open
+    sale.env.warp(START_TIME + 300).await;
+    assert_error(
+        sale.claim_round(&seed, &project, 0, 60_001).await,
+        code(ErrCode::ExceedsClaimableAmount)
+    );
+    sale.claim_round(&seed, &project, 0, 60_000).await.unwrap();
+    assert_error(
+        sale.claim_round(&seed, &project, 1, 1).await,
+        code(ErrCode::NotclaimableAmount)
+    );
+    let close_seed_buyer = ignition::close_round_buyer(&seed.pubkey(), &project, 0);
+    assert_error(
+        sale.env.send(&[close_seed_buyer.clone()], &[&seed]).await,
+        code(ErrCode::NotSettled)
+    );
+    let owner_purchase: Pubkey = sale.env.token_account(&owner, &sale.purchase_mint, 0).await;
+    let withdraw_raise = |round: u8| {
+        ignition::withdraw_round_raise(&owner, &project, &sale.purchase_mint, round)
+    };
+    let (withdraw_seed, withdraw_public) = (withdraw_raise(0), withdraw_raise(1));
+    assert_error(
+        sale.env.send(&[withdraw_public.clone()], &[&sale.operator]).await,
+        code(ErrCode::RoundNotClosed)
+    );
+    // withdrawals stop while protocol is paused
+    sale.env.send(&[ignition::set_paused(&owner, true)], &[&sale.operator]).await.unwrap();
+    assert_error(
+        sale.env.send(&[withdraw_seed.clone()], &[&sale.operator]).await,
+        code(ErrCode::Paused)
+    );
+    sale.env.send(&[ignition::set_paused(&owner, false)], &[&sale.operator]).await.unwrap();
+    sale.env.send(&[withdraw_seed.clone()], &[&sale.operator]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&owner_purchase).await, 6_000);
+    assert_error(
+        sale.env.send(&[withdraw_seed], &[&sale.operator]).await,
+        code(ErrCode::RaiseWithdrawn)
+    );
+    let withdraw_offer = ignition::withdraw_project_offer(&owner, &project, &sale.offer_mint);
+    assert_error(
+        sale.env.send(&[withdraw_offer.clone()], &[&sale.operator]).await,
+        code(ErrCode::RoundNotClosed)
+    );
+
+    sale.env.warp(START_TIME + 500).await;
+    sale.claim_round(&seed, &project, 0, 60_000).await.unwrap();
+    sale.claim_round(&seed, &project, 1, 50_000).await.unwrap();
+    let seed_offer: Pubkey = sale.env.token_account(&seed.pubkey(), &sale.offer_mint, 0).await;
+    assert_eq!(sale.env.token_balance(&seed_offer).await, 170_000);
+    sale.env.send(&[withdraw_public], &[&sale.operator]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&owner_purchase).await, 11_000);
+    // only offer nobody bought is returned, once
+    let owner_offer: u64 = sale.env.token_balance(&sale.operator_offer).await;
+    sale.env.send(&[withdraw_offer.clone()], &[&sale.operator]).await.unwrap();
+    assert_eq!(sale.env.token_balance(&sale.operator_offer).await, owner_offer + 30_000);
+    assert_eq!(sale.env.token_balance(&vault).await, 0);
+    assert_error(
+        sale.env.send(&[withdraw_offer], &[&sale.operator]).await,
+        code(ErrCode::NoUnsoldAmount)
+    );
+    assert_error(sale.fund_project(&project, 1).await, code(ErrCode::InvalidTime));
+
+    // settled allocations, vaults and project give their rent back
+    sale.env.send(&[close_seed_buyer], &[&seed]).await.unwrap();
+    sale.env.send(
+        &[ignition::close_round_buyer(&seed.pubkey(), &project, 1)],
+        &[&seed]
+    ).await.unwrap();
+    assert!(!sale.env.exists(&pda::round_buyer(&project, 0, &seed.pubkey()).0).await);
+    let next_owner: Keypair = sale.env.wallet().await;
+    sale.env.send(
+        &[ignition::propose_project_owner(&owner, &project, &next_owner.pubkey())],
+        &[&sale.operator]
+    ).await.unwrap();
+    sale.env.send(
+        &[ignition::accept_project_owner(&next_owner.pubkey(), &project)],
+        &[&next_owner]
+    ).await.unwrap();
+    assert_eq!(sale.env.account::<Project>(&project).await.owner, next_owner.pubkey());
+    let close_project = ignition::close_project(&next_owner.pubkey(), &owner, &project);
+    assert_error(
+        sale.env.send(&[close_project.clone()], &[&next_owner]).await,
+        code(ErrCode::VaultNotClosed)
+    );
+    sale.env.send(
+        &[ignition::close_project_purchase_vault(&seed.pubkey(), &project)],
+        &[]
+    ).await.unwrap();
+    sale.env.send(&[ignition::close_project_offer_vault(&owner, &project)], &[]).await.unwrap();
+    sale.env.send(&[close_project], &[&next_owner]).await.unwrap();
+    assert!(!sale.env.exists(&project).await);
+}
+
+#[tokio::test]
+async fn project_rounds_gate_on_stake_and_kyc() {
+    const KYC_OPEN: i64 = START_TIME + 200;
+    const MAX_DEPTH: u32 = 3;
+    const MAX_BUFFER_SIZE: u32 = 8;
+    const CANOPY_SIZE: usize = ((1 << (MAX_DEPTH + 1)) - 2) * 32;
+    let mut sale: Sale = Sale::start().await;
+    let project: Pubkey = sale.create_project().await;
+    let (stake_pool, stake_mint) = sale.stake_pool().await;
+
+    // kyc tree of pool operator with full canopy, purchases pass no proof nodes
+    let authority: Pubkey = sale.operator.pubkey();
+    let tree: Keypair = Keypair::new();
+    let size: usize = kyc::merkle_tree_account_size(MAX_DEPTH, MAX_BUFFER_SIZE) + CANOPY_SIZE;
+    let rent = sale.env.ctx.banks_client.get_rent().await.unwrap();
+    sale.env.send(
+        &[
+            system_instruction::create_account(
+                &sale.env.payer(),
+                &tree.pubkey(),
+                rent.minimum_balance(size),
+                size as u64,
+                &spl_account_compression::ID
+            ),
+            kyc::create_note_tree(&authority, &tree.pubkey(), MAX_DEPTH, MAX_BUFFER_SIZE),
+        ],
+        &[&tree, &sale.operator]
+    ).await.unwrap();
+
+    sale.add_round(
+        &project,
+        round(
+            START_TIME + 100,
+            KYC_OPEN,
+            Eligibility::Stake { stake_pool, min_stake: 100 },
+            unlocked_at(KYC_OPEN)
+        )
+    ).await.unwrap();
+    sale.add_round(
+        &project,
+        round(
+            KYC_OPEN,
+            START_TIME + 300,
+            Eligibility::Kyc { merkle_tree: tree.pubkey(), authority },
+            unlocked_at(START_TIME + 300)
+        )
+    ).await.unwrap();
+    sale.fund_project(&project, 2_000_000).await.unwrap();
+
+    let (holder, _) = sale.staked_buyer(&stake_pool, &stake_mint, 500, 10_000).await;
+    let (withdrawer, _) = sale.staked_buyer(&stake_pool, &stake_mint, 500, 10_000).await;
+    let staked = |checkpoints: bool| RoundEligibility::Stake { stake_pool, checkpoints };
+
+    sale.env.warp(START_TIME + 101).await;
+    // stake after round opened does not count
+    let (flash, _) = sale.staked_buyer(&stake_pool, &stake_mint, 5_000, 10_000).await;
+    assert_error(
+        sale.buy_round(&flash, &project, 0, 1_000, staked(true)).await,
+        code(ErrCode::NotEnoughStaker)
+    );
+    // stake must be held from round open on
+    sale.env.send(
+        &[stake::withdraw(&withdrawer.pubkey(), &stake_pool, &stake_mint, 450)],
+        &[&withdrawer]
+    ).await.unwrap();
+    assert_error(
+        sale.buy_round(&withdrawer, &project, 0, 1_000, staked(true)).await,
+        code(ErrCode::NotEnoughStaker)
+    );
+    assert_error(
+        sale.buy_round(&holder, &project, 0, 1_000, RoundEligibility::Public).await,
+        code(ErrCode::NotEnoughStaker)
+    );
+    sale.buy_round(&holder, &project, 0, 1_000, staked(true)).await.unwrap();
+
+    sale.env.warp(KYC_OPEN).await;
+    let (verified, _) = sale.buyer(10_000).await;
+    let (unverified, _) = sale.buyer(10_000).await;
+    sale.env.send(
+        &[kyc::append_note(&authority, &tree.pubkey(), verified.pubkey().to_string())],
+        &[&sale.operator]
+    ).await.unwrap();
+    let mut kyc_tree = ConcurrentMerkleTree::<3, 8>::new();
+    kyc_tree.initialize().unwrap();
+    kyc_tree.append(Eligibility::kyc_leaf(&verified.pubkey(), &authority)).unwrap();
+    let proof = RoundEligibility::Kyc {
+        merkle_tree: tree.pubkey(),
+        root: kyc_tree.get_root(),
+        index: 0,
+        proof: Vec::new(),
+    };
+    // leaf of another wallet does not verify
+    assert!(sale.buy_round(&unverified, &project, 1, 1_000, proof.clone()).await.is_err());
+    sale.buy_round(&verified, &project, 1, 1_000, proof).await.unwrap();
+    assert_eq!(sale.round_buyer(&project, 1, &verified).await.offer_amount, 1_000 * RATE);
+    assert_eq!(sale.round_buyer(&project, 0, &holder).await.offer_amount, 1_000 * RATE);
+}
@@ -0,0 +1,140 @@
+use paid_integration::Env;
+use paid_sdk::kyc;
+use solana_sdk::{
+    keccak,
+    pubkey::Pubkey,
+    signature::{ Keypair, Signer },
+    system_instruction,
+};
+use spl_concurrent_merkle_tree::concurrent_merkle_tree::ConcurrentMerkleTree;
+
+const MAX_DEPTH: u32 = 3;
+const MAX_BUFFER_SIZE: u32 = 8;
+// @dev full canopy, update_note passes no proof accounts
+const CANOPY_SIZE: usize = ((1 << (MAX_DEPTH + 1)) - 2) * 32;
+
+fn leaf(note: &str, owner: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[note.as_bytes(), owner.as_ref()]).to_bytes()
+}
+
+// @dev root of a tree holding leaves from index 0
+fn root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut tree = ConcurrentMerkleTree::<3, 8>::new();
+    tree.initialize().unwrap();
+    for leaf in leaves {
+        tree.append(*leaf).unwrap();
+    }
+    tree.get_root()
+}
+
+async fn create_tree(env: &mut Env, owner: &Keypair) -> Pubkey {
+    let tree: Keypair = Keypair::new();
+    let size: usize = kyc::merkle_tree_account_size(MAX_DEPTH, MAX_BUFFER_SIZE) + CANOPY_SIZE;
+    let rent = env.ctx.banks_client.get_rent().await.unwrap();
+    let instructions = [
+        system_instruction::create_account(
+            &env.payer(),
+            &tree.pubkey(),
+            rent.minimum_balance(size),
+            size as u64,
+            &spl_account_compression::ID
+        ),
+        kyc::create_note_tree(&owner.pubkey(), &tree.pubkey(), MAX_DEPTH, MAX_BUFFER_SIZE),
+    ];
+    env.send(&instructions, &[&tree, owner]).await.unwrap();
+    tree.pubkey()
+}
+
+#[tokio::test]
+async fn note_is_appended_and_updated_against_root() {
+    let mut env: Env = Env::start().await;
+    let owner: Keypair = env.wallet().await;
+    let tree: Pubkey = create_tree(&mut env, &owner).await;
+
+    env.send(&[kyc::append_note(&owner.pubkey(), &tree, "kyc:v1".to_string())], &[&owner]).await.unwrap();
+    let old_leaf: [u8; 32] = leaf("kyc:v1", &owner.pubkey());
+
+    // identical notes are a no-op
+    env.send(
+        &[
+            kyc::update_note(
+                &owner.pubkey(),
+                &tree,
+                0,
+                [7; 32],
+                "kyc:v1".to_string(),
+                "kyc:v1".to_string()
+            ),
+        ],
+        &[&owner]
+    ).await.unwrap();
+
+    env.send(
+        &[
+            kyc::update_note(
+                &owner.pubkey(),
+                &tree,
+                0,
+                root(&[old_leaf]),
+                "kyc:v1".to_string(),
+                "kyc:v2".to_string()
+            ),
+        ],
+        &[&owner]
+    ).await.unwrap();
+
+    // replaced note is gone, new one verifies against the new root
+    let new_leaf: [u8; 32] = leaf("kyc:v2", &owner.pubkey());
+    let result = env.send(
+        &[
+            kyc::update_note(
+                &owner.pubkey(),
+                &tree,
+                0,
+                root(&[new_leaf]),
+                "kyc:v1".to_string(),
+                "kyc:v3".to_string()
+            ),
+        ],
+        &[&owner]
+    ).await;
+    assert!(result.is_err());
+    env.send(
+        &[
+            kyc::update_note(
+                &owner.pubkey(),
+                &tree,
+                0,
+                root(&[new_leaf]),
+                "kyc:v2".to_string(),
+                "kyc:v3".to_string()
+            ),
+        ],
+        &[&owner]
+    ).await.unwrap();
+}
+
+#[tokio::test]
+async fn note_of_other_owner_does_not_verify() {
+    let mut env: Env = Env::start().await;
+    let owner: Keypair = env.wallet().await;
+    let other: Keypair = env.wallet().await;
+    let tree: Pubkey = create_tree(&mut env, &owner).await;
+    env.send(&[kyc::append_note(&owner.pubkey(), &tree, "kyc:v1".to_string())], &[&owner]).await.unwrap();
+
+    // leaf is bound to the owner who appended it
+    let result = env.send(
+        &[
+            kyc::update_note(
+                &other.pubkey(),
+                &tree,
+                0,
+                root(&[leaf("kyc:v1", &owner.pubkey())]),
+                "kyc:v1".to_string(),
+                "kyc:v2".to_string()
+            ),
+        ],
+        &[&other]
+    ).await;
+    assert!(result.is_err());
+}
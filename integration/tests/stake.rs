@@ -0,0 +1,278 @@
+use anchor_spl::associated_token::get_associated_token_address;
+use paid_integration::{ assert_error, Env };
+use paid_sdk::{ math, pda, stake };
+use paid_stake::{ error::ErrCode, states::{ Pool, Role, Staker } };
+use solana_sdk::{ pubkey::Pubkey, signature::{ Keypair, Signer } };
+
+const STAKE_AMOUNT: u64 = 1_000_000;
+// @dev 10 reward per second for one whole stake token
+const REWARD_PER_BLOCK: u64 = 10;
+const REWARD_AMOUNT: u64 = 1_000;
+
+fn code(err: ErrCode) -> u32 {
+    u32::from(err)
+}
+
+struct Farm {
+    env: Env,
+    admin: Keypair,
+    operator: Keypair,
+    pool: Pubkey,
+    stake_mint: Pubkey,
+    reward_mint: Pubkey,
+}
+
+impl Farm {
+    // @dev config, roles and a pool with funded reward pot
+    async fn start() -> Self {
+        let mut env: Env = Env::start().await;
+        let admin: Keypair = env.admin().await;
+        let operator: Keypair = env.wallet().await;
+        env.send(&[stake::init_config(&admin.pubkey())], &[&admin]).await.unwrap();
+        for role in [Role::PoolOperator, Role::Funder, Role::Pauser] {
+            env.send(
+                &[stake::grant_role(&admin.pubkey(), role, &operator.pubkey())],
+                &[&admin]
+            ).await.unwrap();
+        }
+        let stake_mint: Pubkey = env.create_mint(6).await;
+        let reward_mint: Pubkey = env.create_mint(6).await;
+        let pool: Keypair = Keypair::new();
+        env.send(
+            &[
+                stake::init_pool(
+                    &operator.pubkey(),
+                    &pool.pubkey(),
+                    &reward_mint,
+                    &stake_mint,
+                    6,
+                    6,
+                    REWARD_PER_BLOCK
+                ),
+            ],
+            &[&operator, &pool]
+        ).await.unwrap();
+        let operator_reward: Pubkey = env.token_account(
+            &operator.pubkey(),
+            &reward_mint,
+            REWARD_AMOUNT
+        ).await;
+        env.send(
+            &[
+                stake::fund_reward(
+                    &operator.pubkey(),
+                    &pool.pubkey(),
+                    &reward_mint,
+                    &operator_reward,
+                    REWARD_AMOUNT
+                ),
+            ],
+            &[&operator]
+        ).await.unwrap();
+        Self { env, admin, operator, pool: pool.pubkey(), stake_mint, reward_mint }
+    }
+
+    // @dev wallet holding stake token in its associated token account
+    async fn staker(&mut self) -> (Keypair, Pubkey) {
+        let wallet: Keypair = self.env.wallet().await;
+        let token: Pubkey = self.env.token_account(
+            &wallet.pubkey(),
+            &self.stake_mint,
+            STAKE_AMOUNT
+        ).await;
+        (wallet, token)
+    }
+
+    async fn pool(&mut self) -> Pool {
+        let pool: Pubkey = self.pool;
+        self.env.account(&pool).await
+    }
+
+    async fn staker_account(&mut self, wallet: &Keypair) -> Staker {
+        let staker: Pubkey = pda::staker(&self.pool, &wallet.pubkey()).0;
+        self.env.account(&staker).await
+    }
+}
+
+#[tokio::test]
+async fn stake_claim_withdraw_and_close() {
+    let mut farm: Farm = Farm::start().await;
+    let (user, user_token) = farm.staker().await;
+    farm.env.send(
+        &[stake::stake(&user.pubkey(), &farm.pool, &farm.stake_mint, &user_token, STAKE_AMOUNT)],
+        &[&user]
+    ).await.unwrap();
+    let pool: Pool = farm.pool().await;
+    assert_eq!(pool.total_staked, STAKE_AMOUNT);
+    assert_eq!(pool.staker_count, 1);
+
+    // rewards accrue per second while staked
+    let staked_at: i64 = farm.env.now;
+    farm.env.warp(staked_at + 100).await;
+    let pool: Pool = farm.pool().await;
+    let staker: Staker = farm.staker_account(&user).await;
+    let pending: u64 = math::pending_reward(&pool, &staker, farm.env.now);
+    assert_eq!(pending, REWARD_AMOUNT);
+    let quoted: u64 = farm.env.view(stake::pending_rewards(&farm.pool, &user.pubkey())).await;
+    assert_eq!(quoted, pending);
+    farm.env.send(&[stake::claim(&user.pubkey(), &farm.pool, &farm.reward_mint)], &[&user]).await.unwrap();
+    let user_reward: Pubkey = get_associated_token_address(&user.pubkey(), &farm.reward_mint);
+    assert_eq!(farm.env.token_balance(&user_reward).await, pending);
+    let staker: Staker = farm.staker_account(&user).await;
+    assert_eq!(staker.withdraw, pending);
+    assert_eq!(staker.last_update, staked_at + 100);
+
+    // still staked, nothing can close yet
+    assert_error(
+        farm.env.send(&[stake::close_staker(&user.pubkey(), &farm.pool)], &[&user]).await,
+        code(ErrCode::NotSettled)
+    );
+    assert_error(
+        farm.env.send(&[stake::close_stake_vault(&user.pubkey(), &farm.pool)], &[]).await,
+        code(ErrCode::NotSettled)
+    );
+    let operator: Pubkey = farm.operator.pubkey();
+    assert_error(
+        farm.env.send(
+            &[stake::close_pool(&operator, &operator, &farm.pool)],
+            &[&farm.operator]
+        ).await,
+        code(ErrCode::NotSettled)
+    );
+
+    assert_error(
+        farm.env.send(
+            &[stake::withdraw(&user.pubkey(), &farm.pool, &farm.stake_mint, STAKE_AMOUNT + 1)],
+            &[&user]
+        ).await,
+        code(ErrCode::InvalidAmount)
+    );
+    farm.env.send(
+        &[stake::withdraw(&user.pubkey(), &farm.pool, &farm.stake_mint, STAKE_AMOUNT)],
+        &[&user]
+    ).await.unwrap();
+    assert_eq!(farm.env.token_balance(&user_token).await, STAKE_AMOUNT);
+    assert_eq!(farm.pool().await.total_staked, 0);
+
+    let close_checkpoints = stake::close_checkpoints(&user.pubkey(), &farm.pool);
+    assert_error(
+        farm.env.send(&[close_checkpoints.clone()], &[&user]).await,
+        code(ErrCode::NotSettled)
+    );
+    farm.env.send(&[stake::close_staker(&user.pubkey(), &farm.pool)], &[&user]).await.unwrap();
+    farm.env.send(&[close_checkpoints], &[&user]).await.unwrap();
+    assert_error(
+        farm.env.send(
+            &[stake::close_pool(&operator, &operator, &farm.pool)],
+            &[&farm.operator]
+        ).await,
+        code(ErrCode::VaultNotClosed)
+    );
+    // stake vault rent goes back to the first staker, reward pot rent to the funder
+    farm.env.send(&[stake::close_stake_vault(&user.pubkey(), &farm.pool)], &[]).await.unwrap();
+    farm.env.send(&[stake::close_reward_pot(&operator, &farm.pool)], &[]).await.unwrap();
+    farm.env.send(
+        &[stake::close_pool(&operator, &operator, &farm.pool)],
+        &[&farm.operator]
+    ).await.unwrap();
+    let pool: Pubkey = farm.pool;
+    assert!(!farm.env.exists(&pool).await);
+}
+
+#[tokio::test]
+async fn funded_reward_pot_cannot_close() {
+    let mut farm: Farm = Farm::start().await;
+    let operator: Pubkey = farm.operator.pubkey();
+    assert_error(
+        farm.env.send(&[stake::close_reward_pot(&operator, &farm.pool)], &[]).await,
+        code(ErrCode::NotSettled)
+    );
+}
+
+#[tokio::test]
+async fn pause_blocks_staking() {
+    let mut farm: Farm = Farm::start().await;
+    let (user, user_token) = farm.staker().await;
+    let operator: Pubkey = farm.operator.pubkey();
+
+    farm.env.send(&[stake::set_paused(&operator, true)], &[&farm.operator]).await.unwrap();
+    assert_error(
+        farm.env.send(
+            &[stake::stake(&user.pubkey(), &farm.pool, &farm.stake_mint, &user_token, 1)],
+            &[&user]
+        ).await,
+        code(ErrCode::Paused)
+    );
+    farm.env.send(&[stake::set_paused(&operator, false)], &[&farm.operator]).await.unwrap();
+
+    farm.env.send(
+        &[stake::set_pool_paused(&operator, &farm.pool, true)],
+        &[&farm.operator]
+    ).await.unwrap();
+    assert_error(
+        farm.env.send(
+            &[stake::stake(&user.pubkey(), &farm.pool, &farm.stake_mint, &user_token, 1)],
+            &[&user]
+        ).await,
+        code(ErrCode::Paused)
+    );
+    farm.env.send(
+        &[stake::set_pool_paused(&operator, &farm.pool, false)],
+        &[&farm.operator]
+    ).await.unwrap();
+    farm.env.send(
+        &[stake::stake(&user.pubkey(), &farm.pool, &farm.stake_mint, &user_token, 1)],
+        &[&user]
+    ).await.unwrap();
+}
+
+#[tokio::test]
+async fn ownership_and_roles_are_guarded() {
+    let mut farm: Farm = Farm::start().await;
+    let owner: Pubkey = farm.operator.pubkey();
+    let next_owner: Keypair = farm.env.wallet().await;
+
+    assert_error(
+        farm.env.send(&[stake::cancel_owner_transfer(&owner, &farm.pool)], &[&farm.operator]).await,
+        code(ErrCode::NoPendingOwner)
+    );
+    assert_error(
+        farm.env.send(&[stake::propose_owner(&owner, &farm.pool, &owner)], &[&farm.operator]).await,
+        code(ErrCode::InvalidParam)
+    );
+    farm.env.send(
+        &[stake::propose_owner(&owner, &farm.pool, &next_owner.pubkey())],
+        &[&farm.operator]
+    ).await.unwrap();
+    let stranger: Keypair = farm.env.wallet().await;
+    assert_error(
+        farm.env.send(&[stake::accept_owner(&stranger.pubkey(), &farm.pool)], &[&stranger]).await,
+        code(ErrCode::NotPendingOwner)
+    );
+    farm.env.send(
+        &[stake::accept_owner(&next_owner.pubkey(), &farm.pool)],
+        &[&next_owner]
+    ).await.unwrap();
+    assert_eq!(farm.pool().await.owner, next_owner.pubkey());
+
+    let admin: Pubkey = farm.admin.pubkey();
+    assert_error(
+        farm.env.send(&[stake::revoke_role(&admin, Role::SuperAdmin, &admin)], &[&farm.admin]).await,
+        code(ErrCode::CannotRevokeOwnSuperAdmin)
+    );
+    farm.env.send(&[stake::revoke_role(&admin, Role::Pauser, &owner)], &[&farm.admin]).await.unwrap();
+    assert!(!farm.env.exists(&pda::stake_role(Role::Pauser, &owner).0).await);
+}
+
+#[tokio::test]
+async fn config_is_initialized_by_upgrade_authority_only() {
+    let mut env: Env = Env::start().await;
+    let stranger: Keypair = env.wallet().await;
+    assert_error(
+        env.send(&[stake::init_config(&stranger.pubkey())], &[&stranger]).await,
+        code(ErrCode::NotUpgradeAuthority)
+    );
+    let admin: Keypair = env.admin().await;
+    env.send(&[stake::init_config(&admin.pubkey())], &[&admin]).await.unwrap();
+    assert!(env.exists(&pda::stake_role(Role::SuperAdmin, &admin.pubkey()).0).await);
+}
@@ -0,0 +1,70 @@
+// @dev constant-product amm interface seed_liquidity calls into. any program exposing an anchor
+// instruction deposit_liquidity(amount_a, amount_b, min_lp) with this account order can be plugged in
+// once allowed in config. amm pool, reserves and lp mint are pdas of the amm program seeded by
+// ["amm-pool", mint_a, mint_b], ["vault", amm_pool, mint] and ["lp-mint", amm_pool]
+use anchor_lang::{
+    prelude::*,
+    solana_program::{ hash::hash, instruction::{ AccountMeta, Instruction }, program::invoke_signed },
+};
+
+pub struct DepositLiquidity<'info> {
+    // @dev owner of source_a
+    pub authority_a: AccountInfo<'info>,
+    // @dev owner of source_b
+    pub authority_b: AccountInfo<'info>,
+    pub source_a: AccountInfo<'info>,
+    pub source_b: AccountInfo<'info>,
+    // @dev receives minted lp token
+    pub lp_destination: AccountInfo<'info>,
+    pub amm_pool: AccountInfo<'info>,
+    pub vault_a: AccountInfo<'info>,
+    pub vault_b: AccountInfo<'info>,
+    pub lp_mint: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+pub fn deposit_liquidity<'info>(
+    amm_program: &AccountInfo<'info>,
+    accounts: DepositLiquidity<'info>,
+    amount_a: u64,
+    amount_b: u64,
+    min_lp: u64,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<()> {
+    let mut data: Vec<u8> = hash(b"global:deposit_liquidity").to_bytes()[..8].to_vec();
+    (amount_a, amount_b, min_lp).serialize(&mut data)?;
+    let instruction: Instruction = Instruction {
+        program_id: amm_program.key(),
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.authority_a.key(), true),
+            AccountMeta::new_readonly(accounts.authority_b.key(), true),
+            AccountMeta::new(accounts.source_a.key(), false),
+            AccountMeta::new(accounts.source_b.key(), false),
+            AccountMeta::new(accounts.lp_destination.key(), false),
+            AccountMeta::new_readonly(accounts.amm_pool.key(), false),
+            AccountMeta::new(accounts.vault_a.key(), false),
+            AccountMeta::new(accounts.vault_b.key(), false),
+            AccountMeta::new(accounts.lp_mint.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false)
+        ],
+        data,
+    };
+    invoke_signed(
+        &instruction,
+        &[
+            accounts.authority_a,
+            accounts.authority_b,
+            accounts.source_a,
+            accounts.source_b,
+            accounts.lp_destination,
+            accounts.amm_pool,
+            accounts.vault_a,
+            accounts.vault_b,
+            accounts.lp_mint,
+            accounts.token_program,
+            amm_program.clone(),
+        ],
+        signer_seeds
+    )?;
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// @dev spl account compression interface used to check kyc leaves of kycMerkleTree trees
+use anchor_lang::{
+    prelude::*,
+    solana_program::{ hash::hash, instruction::{ AccountMeta, Instruction }, program::invoke },
+};
+
+declare_id!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+// @dev fails unless leaf is at index of merkle tree with root, proof nodes ordered from leaf up
+pub fn verify_leaf<'info>(
+    compression_program: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    proof: &[AccountInfo<'info>],
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32
+) -> Result<()> {
+    let mut data: Vec<u8> = hash(b"global:verify_leaf").to_bytes()[..8].to_vec();
+    (root, leaf, index).serialize(&mut data)?;
+    let mut accounts: Vec<AccountMeta> = vec![AccountMeta::new_readonly(merkle_tree.key(), false)];
+    accounts.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+    let instruction: Instruction = Instruction {
+        program_id: compression_program.key(),
+        accounts,
+        data,
+    };
+    let mut infos: Vec<AccountInfo<'info>> = vec![merkle_tree.clone()];
+    infos.extend(proof.iter().cloned());
+    infos.push(compression_program.clone());
+    invoke(&instruction, &infos)?;
+    Ok(())
+}
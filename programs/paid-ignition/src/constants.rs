@@ -11,6 +11,20 @@ pub const MAX_CROWD_FUNDING_PARTICIPATION_FEE_PERCENTAGE: u16 = 5000;
 pub const MAX_TGE_DATE_ADJUSTMENT: i64 = 86400 * 730; // 730 days
 pub const MAX_TGE_DATE_ADJUSTMENT_ATTEMPTS: u8 = 2;
 pub const EAELRY_POOL_PARTICIPANT_STAKE_AMOUNT: u64 = 100000000;
+pub const MAX_MILESTONES: usize = 8;
+pub const MAX_TIERS: usize = 8;
+pub const MAX_ROUNDS: usize = 8;
+pub const MAX_NAME_LENGTH: usize = 32;
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+pub const MAX_URI_LENGTH: usize = 200;
+pub const MAX_WEBSITE_LENGTH: usize = 100;
+pub const MAX_CLAIM_RECORDS: usize = 32;
+pub const MAX_AMM_PROGRAMS: usize = 4;
+// @dev lowest quorum a proposal may ask for, based on PERCENTAGE_DENOMINATOR
+pub const MIN_PROPOSAL_QUORUM: u16 = 2000;
+// @dev lowest quorum a refund proposal may ask for, based on PERCENTAGE_DENOMINATOR
+pub const MIN_REFUND_QUORUM: u16 = 5000;
+pub const MIN_VOTING_PERIOD: i64 = 86400 * 3; // 3 days
 
 pub fn calculate_participiant_fee(purchase_amount: u64, participation_fee_percentage: u16) -> u64 {
   if participation_fee_percentage == 0 {
@@ -19,17 +33,60 @@ pub fn calculate_participiant_fee(purchase_amount: u64, participation_fee_percen
   return (purchase_amount * (participation_fee_percentage as u64)) / PERCENTAGE_DENOMINATOR;
 }
 
+// @dev largest purchase amount up to requested one whose net amount fits in remaining
+pub fn fill_purchase_amount(
+  purchase_amount: u64,
+  participation_fee_percentage: u16,
+  remaining_amount: u64
+) -> u64 {
+  let net_amount: u64 =
+      purchase_amount - calculate_participiant_fee(purchase_amount, participation_fee_percentage);
+  if net_amount <= remaining_amount {
+      return purchase_amount;
+  }
+  // gross up remaining amount by fee, then correct rounding
+  let mut fill_amount: u64 =
+      (remaining_amount * PERCENTAGE_DENOMINATOR) /
+      (PERCENTAGE_DENOMINATOR - (participation_fee_percentage as u64));
+  while
+      fill_amount - calculate_participiant_fee(fill_amount, participation_fee_percentage) >
+      remaining_amount
+  {
+      fill_amount -= 1;
+  }
+  fill_amount
+}
+
+pub fn required_offer_amount(
+  total_raise_amount: u64,
+  rate: u64,
+  token_fee_percentage: u16
+) -> u64 {
+  let offered_amount: u64 = total_raise_amount * rate;
+  offered_amount + (offered_amount * (token_fee_percentage as u64)) / PERCENTAGE_DENOMINATOR
+}
+
 pub fn max_purchase_amount_for_early_access(
   total_raise_amount: u64,
   open_pool_proportion: u64,
   early_pool_proportion: u64
 ) -> u64 {
-  return 
-      (total_raise_amount *
-          (PERCENTAGE_DENOMINATOR - open_pool_proportion) *
-          early_pool_proportion) /
-      PERCENTAGE_DENOMINATOR /
-      PERCENTAGE_DENOMINATOR;
+  // @dev widened so raise * 10000 * 10000 cannot overflow
+  return (((total_raise_amount as u128) *
+      ((PERCENTAGE_DENOMINATOR - open_pool_proportion) as u128) *
+      (early_pool_proportion as u128)) /
+      (PERCENTAGE_DENOMINATOR as u128) /
+      (PERCENTAGE_DENOMINATOR as u128)) as u64;
+}
+
+pub fn vesting_end_time(
+  tge_date: i64,
+  vesting_cliff: i64,
+  vesting_frequency: i64,
+  number_of_vesting: i64
+) -> i64 {
+  // last release happens number_of_vesting - 1 periods after cliff
+  tge_date + vesting_cliff + vesting_frequency * (number_of_vesting - 1).max(0)
 }
 
 pub fn calculate_claimable_amount(
@@ -42,23 +99,92 @@ pub fn calculate_claimable_amount(
   number_of_vesting_release: u64,
   time_stamp: i64
 ) -> u64 {
+  // @dev claimed amount may exceed vested amount once allocation shrank after a milestone refund
   let tge_amount: u64 = (total_amount * (tge_percentage as u64)) / 10000;
   // in cliff time
   if time_stamp < tge_date + vesting_cliff {
-      return tge_amount - claimed_amount;
+      return tge_amount.saturating_sub(claimed_amount);
   }
 
-  // after vesting duration
+  // after vesting duration, zero frequency releases everything at cliff
+  if vesting_frequency == 0 {
+      return total_amount.saturating_sub(claimed_amount);
+  }
   let release_index: u64 =
       ((time_stamp - tge_date - vesting_cliff) as u64) / vesting_frequency + 1;
-  if release_index >= number_of_vesting_release || vesting_frequency == 0 {
-      return total_amount - claimed_amount;
+  if release_index >= number_of_vesting_release {
+      return total_amount.saturating_sub(claimed_amount);
   }
 
   //  in vesting duration
   let total_claimalble_except_tge_amount = total_amount - tge_amount;
-  return 
+  let vested_amount: u64 =
       (release_index * total_claimalble_except_tge_amount) / number_of_vesting_release +
-      tge_amount -
-      claimed_amount;
-}
\ No newline at end of file
+      tge_amount;
+  vested_amount.saturating_sub(claimed_amount)
+}
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn early_access_cap_splits_raise() {
+      assert_eq!(max_purchase_amount_for_early_access(1_000_000, 2000, 5000), 400_000);
+      assert_eq!(max_purchase_amount_for_early_access(1_000_000, 10000, 5000), 0);
+  }
+
+  #[test]
+  fn participant_fee_and_required_offer() {
+      assert_eq!(calculate_participiant_fee(1_000, 0), 0);
+      assert_eq!(calculate_participiant_fee(1_000, 250), 25);
+      assert_eq!(required_offer_amount(1_000, 10, 500), 10_500);
+  }
+
+  #[test]
+  fn fill_is_largest_purchase_fitting_remaining() {
+      assert_eq!(fill_purchase_amount(100, 1000, 450), 100);
+      assert_eq!(fill_purchase_amount(1_000, 1000, 450), 500);
+      assert_eq!(fill_purchase_amount(1_000, 300, 100), 103);
+      for fee in [0, 1, 250, 3333, 5000] {
+          for remaining in 0..500 {
+              let fill: u64 = fill_purchase_amount(10_000, fee, remaining);
+              let net = |amount: u64| amount - calculate_participiant_fee(amount, fee);
+              assert!(net(fill) <= remaining);
+              assert!(net(fill + 1) > remaining);
+          }
+      }
+  }
+
+  #[test]
+  fn claimable_follows_cliff_and_periods() {
+      let claimable = |claimed: u64, now: i64| {
+          calculate_claimable_amount(1_000, claimed, 2000, 100, 50, 10, 4, now)
+      };
+      // tge share only until cliff ends
+      assert_eq!(claimable(0, 100), 200);
+      assert_eq!(claimable(0, 149), 200);
+      assert_eq!(claimable(0, 150), 400);
+      assert_eq!(claimable(0, 169), 600);
+      assert_eq!(claimable(0, 179), 800);
+      assert_eq!(claimable(0, 180), 1_000);
+      assert_eq!(claimable(300, 160), 300);
+      assert_eq!(claimable(1_000, 10_000), 0);
+      // zero frequency releases everything at cliff
+      assert_eq!(calculate_claimable_amount(1_000, 0, 2000, 100, 50, 0, 4, 150), 1_000);
+  }
+
+  #[test]
+  fn vesting_ends_at_last_release() {
+      assert_eq!(vesting_end_time(100, 50, 10, 4), 180);
+      assert_eq!(vesting_end_time(100, 50, 10, 1), 150);
+      assert_eq!(vesting_end_time(100, 50, 10, 0), 150);
+      assert_eq!(calculate_claimable_amount(1_000, 0, 0, 100, 50, 10, 4, 180), 1_000);
+  }
+
+  #[test]
+  fn early_access_cap_handles_large_raise() {
+      let raise: u64 = u64::MAX / 2;
+      assert_eq!(max_purchase_amount_for_early_access(raise, 0, 10000), raise);
+      assert_eq!(max_purchase_amount_for_early_access(raise, 5000, 10000), raise / 2);
+  }
+}
@@ -36,4 +36,112 @@ pub enum ErrCode {
     AlreadyClaimedTotoalAmount,
     #[msg("You did not stake enough token")]
     NotEnoughStaker,
+    #[msg("Paused")]
+    Paused,
+    #[msg("Not allowed to revoke own super admin role")]
+    CannotRevokeOwnSuperAdmin,
+    #[msg("No pending owner")]
+    NoPendingOwner,
+    #[msg("Signer is not the pending owner")]
+    NotPendingOwner,
+    #[msg("TGE date already passed")]
+    TGEAlreadyPassed,
+    #[msg("Cooling-off period ended")]
+    CoolingOffPeriodEnded,
+    #[msg("Account is not fully settled")]
+    NotSettled,
+    #[msg("Vault is not closed")]
+    VaultNotClosed,
+    #[msg("Invalid mint")]
+    InvalidMint,
+    #[msg("Invalid pool status")]
+    InvalidPoolStatus,
+    #[msg("Invalid pool status transition")]
+    InvalidStatusTransition,
+    #[msg("Hard cap reached")]
+    HardCapReached,
+    #[msg("Invalid claim accounts")]
+    InvalidClaimAccounts,
+    #[msg("Signer is not allowed to claim for buyer")]
+    NotClaimAuthority,
+    #[msg("Amount exceeds claimable amount")]
+    ExceedsClaimableAmount,
+    #[msg("No unsold amount")]
+    NoUnsoldAmount,
+    #[msg("Invalid unsold destination")]
+    InvalidUnsoldDestination,
+    #[msg("Liquidity is not configured")]
+    LiquidityNotConfigured,
+    #[msg("Liquidity already seeded")]
+    LiquidityAlreadySeeded,
+    #[msg("Lp token is still locked")]
+    LpLocked,
+    #[msg("Invalid milestones")]
+    InvalidMilestones,
+    #[msg("Milestone release time not reached")]
+    MilestoneNotReached,
+    #[msg("All milestones released")]
+    AllMilestonesReleased,
+    #[msg("Refund vote passed")]
+    RefundVotePassed,
+    #[msg("Refund vote not passed")]
+    RefundVoteNotPassed,
+    #[msg("Already refunded")]
+    AlreadyRefunded,
+    #[msg("Signer is not allowed to open proposals")]
+    NotProposer,
+    #[msg("Proposal is not active")]
+    ProposalNotActive,
+    #[msg("Voting has ended")]
+    VotingEnded,
+    #[msg("Voting has not ended")]
+    VotingNotEnded,
+    #[msg("Proposal is not approved")]
+    ProposalNotApproved,
+    #[msg("Price protection is not configured")]
+    ProtectionNotConfigured,
+    #[msg("Price protection window is closed")]
+    ProtectionWindowClosed,
+    #[msg("Price protection window is still open")]
+    ProtectionWindowOpen,
+    #[msg("Price protection cap reached")]
+    ProtectionCapReached,
+    #[msg("Protection reserve already reclaimed")]
+    ProtectionReserveReclaimed,
+    #[msg("Buyer already claimed")]
+    AlreadyClaimed,
+    #[msg("Invalid tiers")]
+    InvalidTiers,
+    #[msg("Tier table is fixed once wallets registered")]
+    TierTableLocked,
+    #[msg("Wallet is not registered for a tier")]
+    NotRegistered,
+    #[msg("Purchase exceeds tier allocation")]
+    ExceedsTierAllocation,
+    #[msg("Invalid pool metadata")]
+    InvalidMetadata,
+    #[msg("Invalid round")]
+    InvalidRound,
+    #[msg("Round is not open")]
+    RoundNotOpen,
+    #[msg("Round is not closed")]
+    RoundNotClosed,
+    #[msg("Wallet is not eligible for round")]
+    NotEligible,
+    #[msg("Purchase exceeds wallet cap of round")]
+    WalletCapReached,
+    #[msg("Raise of round already withdrawn")]
+    RaiseWithdrawn,
+    #[msg("Signer is not the upgrade authority of program")]
+    NotUpgradeAuthority,
+    #[msg("Raise already left purchase vault")]
+    RaiseAlreadyReleased,
+    #[msg("Amm program is not allowed")]
+    AmmProgramNotAllowed,
+    #[msg("Amm did not take exact liquidity amounts")]
+    LiquidityAmountMismatch,
+    #[msg("Proposal is active or approved and not executed yet")]
+    ProposalNotSettled,
+    #[msg("Signer is neither pool owner nor holder of required role")]
+    NotPoolAuthority,
 }
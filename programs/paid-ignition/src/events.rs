@@ -0,0 +1,245 @@
+use anchor_lang::prelude::*;
+
+use crate::{ Milestone, ProposalKind, ProposalStatus, Round, Tier, UnsoldPolicy };
+
+#[event]
+pub struct OwnerProposed {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct OwnerAccepted {
+    pub pool: Pubkey,
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct OwnerTransferCancelled {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct TgeDateUpdated {
+    pub pool: Pubkey,
+    pub previous_tge_date: i64,
+    pub new_tge_date: i64,
+    pub attempts: u8,
+}
+
+#[event]
+pub struct ClaimsCranked {
+    pub pool: Pubkey,
+    pub cranker: Pubkey,
+    pub claims: u64,
+    pub amount: u64,
+    pub tips: u64,
+}
+
+#[event]
+pub struct CrankClaimSkipped {
+    pub pool: Pubkey,
+    pub wallet: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ClaimSettingsUpdated {
+    pub pool: Pubkey,
+    pub wallet: Pubkey,
+    pub delegate: Pubkey,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct OfferClaimed {
+    pub pool: Pubkey,
+    pub wallet: Pubkey,
+    pub signer: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+}
+
+#[event]
+pub struct UnsoldFinalized {
+    pub pool: Pubkey,
+    pub policy: UnsoldPolicy,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct LiquiditySeeded {
+    pub pool: Pubkey,
+    pub amm_program: Pubkey,
+    pub purchase_amount: u64,
+    pub offer_amount: u64,
+    pub lp_amount: u64,
+    pub unlock_time: i64,
+}
+
+#[event]
+pub struct MilestonesDeclared {
+    pub pool: Pubkey,
+    pub milestones: Vec<Milestone>,
+}
+
+#[event]
+pub struct MilestoneReleased {
+    pub pool: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub released_amount: u64,
+}
+
+#[event]
+pub struct RefundApproved {
+    pub pool: Pubkey,
+    pub proposal: Pubkey,
+    pub refundable_amount: u64,
+    pub refund_base: u64,
+}
+
+#[event]
+pub struct MilestoneRefunded {
+    pub pool: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub pool: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u32,
+    pub kind: ProposalKind,
+    pub proposer: Pubkey,
+    pub deadline: i64,
+    pub quorum: u16,
+}
+
+#[event]
+pub struct ProposalVoted {
+    pub proposal: Pubkey,
+    pub wallet: Pubkey,
+    pub weight: u64,
+    pub approve: bool,
+}
+
+#[event]
+pub struct ProposalFinalized {
+    pub pool: Pubkey,
+    pub proposal: Pubkey,
+    pub status: ProposalStatus,
+    pub votes_for: u64,
+    pub votes_against: u64,
+}
+
+#[event]
+pub struct PriceProtectionClaimed {
+    pub pool: Pubkey,
+    pub wallet: Pubkey,
+    pub purchase_amount: u64,
+    pub offer_amount: u64,
+    pub protected_amount: u64,
+}
+
+#[event]
+pub struct ProtectionReserveReclaimed {
+    pub pool: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VaultsSwept {
+    pub pool: Pubkey,
+    pub offer_amount: u64,
+    pub purchase_amount: u64,
+}
+
+#[event]
+pub struct TierTableSet {
+    pub pool: Pubkey,
+    pub stake_pool: Pubkey,
+    pub tiers: Vec<Tier>,
+    pub snapshot_time: i64,
+    pub continuous_stake: bool,
+}
+
+#[event]
+pub struct TierRegistered {
+    pub pool: Pubkey,
+    pub wallet: Pubkey,
+    pub tier: u8,
+    pub weight: u16,
+    pub staked_amount: u64,
+    pub total_registered_weight: u64,
+}
+
+#[event]
+pub struct PoolMetadataUpdated {
+    pub pool: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub website: String,
+    pub content_hash: [u8; 32],
+}
+
+#[event]
+pub struct RoundAdded {
+    pub project: Pubkey,
+    pub index: u8,
+    pub round: Round,
+}
+
+#[event]
+pub struct RoundPurchased {
+    pub project: Pubkey,
+    pub round: u8,
+    pub wallet: Pubkey,
+    pub purchase_amount: u64,
+    pub offer_amount: u64,
+}
+
+#[event]
+pub struct RoundOfferClaimed {
+    pub project: Pubkey,
+    pub round: u8,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+}
+
+#[event]
+pub struct RoundRaiseWithdrawn {
+    pub project: Pubkey,
+    pub round: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProjectOfferWithdrawn {
+    pub project: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProjectOwnerProposed {
+    pub project: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct ProjectOwnerAccepted {
+    pub project: Pubkey,
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+}
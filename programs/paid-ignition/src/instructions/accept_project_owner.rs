@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Project, ProjectOwnerAccepted };
+
+#[derive(Accounts)]
+pub struct AcceptProjectOwner<'info> {
+    pub pending_owner: Signer<'info>,
+
+    // @dev project account
+    #[account(
+        mut,
+        constraint = project.pending_owner != Pubkey::default() @ ErrCode::NoPendingOwner,
+        has_one = pending_owner @ ErrCode::NotPendingOwner
+    )]
+    pub project: Box<Account<'info, Project>>,
+}
+
+// @dev it allows to take over the project by the nominated owner
+pub fn accept_project_owner_handler(ctx: Context<AcceptProjectOwner>) -> Result<()> {
+    let project: &mut Box<Account<Project>> = &mut ctx.accounts.project;
+    let previous_owner: Pubkey = project.owner;
+    project.owner = project.pending_owner;
+    project.pending_owner = Pubkey::default();
+    emit!(ProjectOwnerAccepted {
+        project: project.key(),
+        previous_owner,
+        owner: project.owner,
+    });
+    msg!("Accepted project owner");
+    Ok(())
+}
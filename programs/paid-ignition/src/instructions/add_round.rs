@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Project, Round, RoundAdded, RoundParams, MAX_ROUNDS };
+
+#[derive(Accounts)]
+pub struct AddRound<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev project account
+    #[account(mut, has_one = owner)]
+    pub project: Box<Account<'info, Project>>,
+}
+
+// @dev allowed to append a round by project owner, rounds are fixed once added
+pub fn add_round_handler(ctx: Context<AddRound>, params: RoundParams) -> Result<()> {
+    let project: &mut Box<Account<Project>> = &mut ctx.accounts.project;
+    if project.rounds.len() >= MAX_ROUNDS {
+        return err!(ErrCode::InvalidRound);
+    }
+    let round: Round = Round::new(params);
+    if !round.is_valid(project.rounds.last(), Clock::get()?.unix_timestamp) {
+        return err!(ErrCode::InvalidRound);
+    }
+    project.rounds.push(round);
+    emit!(RoundAdded {
+        project: project.key(),
+        index: (project.rounds.len() - 1) as u8,
+        round,
+    });
+    msg!("Added round");
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrCode,
+    MilestonePlan,
+    Pool,
+    PoolStatus,
+    Proposal,
+    ProposalKind,
+    RefundApproved,
+};
+
+#[derive(Accounts)]
+pub struct ApproveRefund<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev milestone plan of pool, single tranche at tge if never declared
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = MilestonePlan::space(),
+        seeds = [b"milestones", pool.key().as_ref()],
+        bump
+    )]
+    pub milestone_plan: Box<Account<'info, MilestonePlan>>,
+
+    // @dev approved refund proposal of pool, consumed by this instruction
+    #[account(mut, has_one = pool)]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// @dev allowed to act on approved refund proposal by anyone while milestones remain
+// @dev unreleased raise becomes refundable and blocks further releases
+pub fn approve_refund_handler(ctx: Context<ApproveRefund>) -> Result<()> {
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    pool.require_status(&[PoolStatus::Succeeded, PoolStatus::Claiming, PoolStatus::Finalized])?;
+    let plan: &mut Box<Account<MilestonePlan>> = &mut ctx.accounts.milestone_plan;
+    if plan.milestones.is_empty() {
+        let rent_payer: Pubkey = ctx.accounts.signer.key();
+        plan.set_default(pool.key(), pool.tge_date, rent_payer, ctx.bumps.milestone_plan);
+    }
+    if plan.refund_passed {
+        return err!(ErrCode::RefundVotePassed);
+    }
+    if (plan.released_count as usize) >= plan.milestones.len() {
+        return err!(ErrCode::AllMilestonesReleased);
+    }
+    let proposal: &mut Box<Account<Proposal>> = &mut ctx.accounts.proposal;
+    if !proposal.approves(&ProposalKind::RefundMilestones) {
+        return err!(ErrCode::ProposalNotApproved);
+    }
+    proposal.executed = true;
+    plan.refund_passed = true;
+    plan.refundable_amount = pool.releasable_purchase_amount() - plan.released_amount;
+    plan.refund_base = pool.outstanding_purchase_amount();
+    emit!(RefundApproved {
+        pool: pool.key(),
+        proposal: proposal.key(),
+        refundable_amount: plan.refundable_amount,
+        refund_base: plan.refund_base,
+    });
+    msg!("Approved refund");
+    Ok(())
+}
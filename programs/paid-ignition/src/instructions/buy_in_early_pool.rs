@@ -4,9 +4,13 @@ use anchor_spl::token::{ self, Mint, Token, TokenAccount, Transfer };
 use crate::{
     calculate_participiant_fee,
     error::ErrCode,
-    max_purchase_amount_for_early_access,
+    fill_purchase_amount,
     Buyer,
+    Config,
     Pool,
+    PoolStatus,
+    TierRegistration,
+    TierTable,
 };
 use std::mem::size_of;
 
@@ -25,8 +29,19 @@ pub struct BuyInEarlyPool<'info> {
     )]
     pub user_purchase_token: Account<'info, TokenAccount>,
 
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
     // @dev pool account
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
     pub pool: Box<Account<'info, Pool>>,
 
     // @dev purchase vault
@@ -52,6 +67,20 @@ pub struct BuyInEarlyPool<'info> {
     )]
     pub buyer: Box<Account<'info, Buyer>>,
 
+    // @dev tier table of pool, required if pool is tiered
+    #[account(
+        seeds = [b"tiers", pool.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Box<Account<'info, TierTable>>>,
+
+    // @dev tier registration of signer, required if pool is tiered
+    #[account(
+        seeds = [b"tier-registration", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub tier_registration: Option<Box<Account<'info, TierRegistration>>>,
+
     pub token_program: Program<'info, Token>,
     pub clock: Sysvar<'info, Clock>,
     pub system_program: Program<'info, System>,
@@ -70,38 +99,52 @@ impl<'info> BuyInEarlyPool<'info> {
 // @dev allowed to buy ido token by user after started early pool
 pub fn buy_in_early_pool_handler(
     ctx: Context<BuyInEarlyPool>,
-    purchase_amount: u64,
-    bump: u8
+    purchase_amount: u64
 ) -> Result<()> {
+    // validate status
+    let now: i64 = ctx.accounts.clock.unix_timestamp;
+    ctx.accounts.pool.refresh_status(now)?;
+    ctx.accounts.pool.require_status(&[PoolStatus::EarlyOpen])?;
     let pool_storage: &Box<Account<Pool>> = &ctx.accounts.pool;
     // validate stake amount
     // if ctx.accounts.buyer.total_staked < EAELRY_POOL_PARTICIPANT_STAKE_AMOUNT {
     //     return err!(ErrCode::NotEnoughStaker);
     // }
-    // validate time
-    let now: i64 = ctx.accounts.clock.unix_timestamp as i64;
-    if now > pool_storage.early_pool_close_time {
-        return err!(ErrCode::TimeOutBuyIDOToken);
-    }
-    if now < pool_storage.early_pool_open_time {
-        return err!(ErrCode::TimeOutBuyIDOToken);
-    }
     // validate amount
     if purchase_amount == 0 {
         return err!(ErrCode::InvalidAmount);
     }
-    // calculate purchaseable amounts
-    let early_purchased: u64 = ctx.accounts.buyer.early_purchased;
-
-    let allow_purchase_amount: u64 = max_purchase_amount_for_early_access(
-        pool_storage.total_raise_amount,
-        pool_storage.open_pool_proportion as u64,
-        pool_storage.early_pool_proportion as u64
-    );
-
-    if early_purchased + purchase_amount > allow_purchase_amount {
+    // calculate purchaseable amounts, early pool is capped at its share and at hard cap
+    let mut remaining_amount: u64 = pool_storage.remaining_early_amount();
+    if remaining_amount == 0 {
         return err!(ErrCode::ExceedMaxPurchaseAmountForEarlyAccess);
     }
+    // tiered early pool caps each wallet at its weighted share
+    if pool_storage.tiered {
+        let allocation: u64 = match (&ctx.accounts.tier_table, &ctx.accounts.tier_registration) {
+            (Some(tier_table), Some(registration)) =>
+                tier_table.allocation(pool_storage.early_pool_total(), registration.weight),
+            _ => {
+                return err!(ErrCode::NotRegistered);
+            }
+        };
+        let remaining_allocation: u64 = allocation.saturating_sub(
+            ctx.accounts.buyer.early_purchased
+        );
+        if remaining_allocation == 0 {
+            return err!(ErrCode::ExceedsTierAllocation);
+        }
+        remaining_amount = remaining_amount.min(remaining_allocation);
+    }
+    // partially fill purchase crossing the cap
+    let purchase_amount: u64 = fill_purchase_amount(
+        purchase_amount,
+        pool_storage.early_pool_participation_fee_percentage,
+        remaining_amount
+    );
+    if purchase_amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
 
     // calculate fee amount
     let participant_fee: u64 = calculate_participiant_fee(
@@ -109,26 +152,33 @@ pub fn buy_in_early_pool_handler(
         pool_storage.early_pool_participation_fee_percentage
     );
     let ido_amount: u64 = (purchase_amount - participant_fee) * pool_storage.offered_currency.rate;
-    let vesting_storage = &ctx.accounts.pool;
-    if !vesting_storage.funded {
-        return err!(ErrCode::NotFunded);
-    }
 
-    // send token to purchase vault
+    // send net amount to purchase vault, fee is deducted and never held by the vault
     token::transfer(ctx.accounts.transfer_ctx(), purchase_amount - participant_fee)?;
     // send fee to stake program
     // token::transfer(ctx.accounts.transfer_fee_ctx(), participant_fee)?;
 
     // update pool info
     let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
-    pool.purchase_bump = bump;
-    pool.purchased_amount += purchase_amount;
+    pool.purchase_bump = ctx.bumps.purchase_vault;
+    if pool.purchase_vault_payer == Pubkey::default() {
+        pool.purchase_vault_payer = ctx.accounts.signer.key();
+    }
+    pool.purchased_amount += purchase_amount - participant_fee;
+    pool.purchased_amount_in_early_access += purchase_amount - participant_fee;
     // update user vesting info
     let buyer: &mut Box<Account<Buyer>> = &mut ctx.accounts.buyer;
     buyer.total_amount += ido_amount;
     // update user purchase info
     buyer.early_purchased += purchase_amount - participant_fee;
     buyer.total_purchase += purchase_amount - participant_fee;
+    buyer.record_purchase(
+        purchase_amount - participant_fee,
+        purchase_amount - participant_fee,
+        ido_amount,
+        now
+    );
+    buyer.wallet = ctx.accounts.signer.key();
 
     msg!("Bought token");
     Ok(())
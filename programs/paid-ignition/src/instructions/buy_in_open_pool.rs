@@ -1,7 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{ self, Mint, Token, TokenAccount, Transfer };
 
-use crate::{ calculate_participiant_fee, error::ErrCode, Buyer, Pool };
+use crate::{
+    calculate_participiant_fee,
+    error::ErrCode,
+    fill_purchase_amount,
+    Buyer,
+    Config,
+    Pool,
+    PoolStatus,
+};
 use std::mem::size_of;
 
 #[derive(Accounts)]
@@ -19,8 +27,19 @@ pub struct BuyInOpenPool<'info> {
     )]
     pub user_purchase_token: Account<'info, TokenAccount>,
 
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
     // @dev pool account
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
     pub pool: Box<Account<'info, Pool>>,
 
     // @dev purchase token vault
@@ -64,17 +83,12 @@ impl<'info> BuyInOpenPool<'info> {
 // @dev allow to buy token by user in open pool
 pub fn buy_in_open_pool_handler(
     ctx: Context<BuyInOpenPool>,
-    purchase_amount: u64,
-    bump: u8
+    purchase_amount: u64
 ) -> Result<()> {
-    // validate time
+    // validate status
     let clock: Clock = Clock::get()?;
-    if clock.unix_timestamp > ctx.accounts.pool.open_pool_close_time {
-        return err!(ErrCode::TimeOutBuyIDOToken);
-    }
-    if clock.unix_timestamp < ctx.accounts.pool.open_pool_open_time {
-        return err!(ErrCode::TimeOutBuyIDOToken);
-    }
+    ctx.accounts.pool.refresh_status(clock.unix_timestamp)?;
+    ctx.accounts.pool.require_status(&[PoolStatus::OpenOpen])?;
     // validate amount
     if purchase_amount == 0 {
         return err!(ErrCode::InvalidAmount);
@@ -111,27 +125,45 @@ pub fn buy_in_open_pool_handler(
     //     return err!(ErrCode::ExceedMaxPurchaseAmountForEarlyAccess);
     // }
 
+    // open pool absorbs unsold early allocation up to hard cap
+    let remaining_amount: u64 = ctx.accounts.pool.remaining_open_amount();
+    if remaining_amount == 0 {
+        return err!(ErrCode::HardCapReached);
+    }
+    // partially fill purchase crossing the cap
+    let purchase_amount: u64 = fill_purchase_amount(
+        purchase_amount,
+        ctx.accounts.pool.open_pool_participation_fee_percentage,
+        remaining_amount
+    );
+    if purchase_amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+
     // calculate fee amount
     let participant_fee: u64 = calculate_participiant_fee(
         purchase_amount,
-        ctx.accounts.pool.early_pool_participation_fee_percentage
+        ctx.accounts.pool.open_pool_participation_fee_percentage
     );
     let ido_amount: u64 = (purchase_amount - participant_fee) * ctx.accounts.pool.offered_currency.rate;
-    if !ctx.accounts.pool.funded {
-        return err!(ErrCode::NotFunded);
-    }
 
-    // send token to purchase vault
-    token::transfer(ctx.accounts.transfer_ctx(), purchase_amount)?;
+    // send net amount to purchase vault, same fee model as early pool
+    token::transfer(ctx.accounts.transfer_ctx(), purchase_amount - participant_fee)?;
     // update pool info
     let pool: &mut Box<Account<Pool>> =&mut ctx.accounts.pool;
-    pool.purchase_bump = bump;
-    pool.purchased_amount += purchase_amount;
+    pool.purchase_bump = ctx.bumps.purchase_vault;
+    if pool.purchase_vault_payer == Pubkey::default() {
+        pool.purchase_vault_payer = ctx.accounts.signer.key();
+    }
+    pool.purchased_amount += purchase_amount - participant_fee;
+    pool.purchased_amount_in_open_pool += purchase_amount - participant_fee;
     // update user vesting info
     let buyer = &mut ctx.accounts.buyer;
     buyer.total_amount += ido_amount;
     // update user purchase info
     buyer.total_purchase += purchase_amount - participant_fee;
+    buyer.record_purchase(purchase_amount - participant_fee, 0, ido_amount, clock.unix_timestamp);
+    buyer.wallet = ctx.accounts.signer.key();
     msg!("Bought token");
     Ok(())
 }
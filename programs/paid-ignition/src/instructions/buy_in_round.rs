@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Mint, Token, TokenAccount, Transfer };
+use paid_stake::states::{ StakeCheckpoints, Staker };
+use std::mem::size_of;
+
+use crate::{
+    compression,
+    error::ErrCode,
+    Config,
+    Eligibility,
+    EligibilityProof,
+    Project,
+    Round,
+    RoundBuyer,
+    RoundPurchased,
+};
+
+#[derive(Accounts)]
+#[instruction(round: u8)]
+pub struct BuyInRound<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // @dev mint address of purchase token
+    #[account(
+        constraint = purchase_mint.key() == project.purchase_mint @ ErrCode::InvalidMint
+    )]
+    pub purchase_mint: Box<Account<'info, Mint>>,
+
+    // @dev user purchase token account
+    #[account(
+        mut,
+        token::mint = purchase_mint,
+    )]
+    pub user_purchase_token: Account<'info, TokenAccount>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev project account
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    // @dev purchase vault shared by all rounds
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [b"project-purchase-vault", project.key().as_ref()],
+        bump,
+        owner = token_program.key(),
+        rent_exempt = enforce,
+        token::mint = purchase_mint,
+        token::authority = purchase_vault
+    )]
+    pub purchase_vault: Account<'info, TokenAccount>,
+
+    // @dev allocation of signer in round, vests independently of other rounds
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = size_of::<RoundBuyer>() + 8,
+        seeds = [b"round-buyer", project.key().as_ref(), &[round], signer.key().as_ref()],
+        bump
+    )]
+    pub round_buyer: Box<Account<'info, RoundBuyer>>,
+
+    // @dev paidStake staker account of signer, required by stake rounds
+    pub staker: Option<Box<Account<'info, Staker>>>,
+
+    // @dev paidStake balance history of signer, required by stake rounds if it exists
+    pub checkpoints: Option<Box<Account<'info, StakeCheckpoints>>>,
+
+    /// CHECK: kycMerkleTree tree, checked against round and by compression program
+    pub merkle_tree: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: spl account compression program, required by kyc rounds
+    #[account(address = compression::ID)]
+    pub compression_program: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BuyInRound<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), Transfer {
+            from: self.user_purchase_token.to_account_info(),
+            to: self.purchase_vault.to_account_info(),
+            authority: self.signer.to_account_info(),
+        })
+    }
+
+    // @dev stake held without interruption since round opened
+    fn held_stake(&self, stake_pool: &Pubkey, open_time: i64) -> Result<u64> {
+        let signer: Pubkey = self.signer.key();
+        let staker: &Account<Staker> = match &self.staker {
+            Some(staker) => staker,
+            None => {
+                return Ok(0);
+            }
+        };
+        let (staker_key, _) = Pubkey::find_program_address(
+            &[stake_pool.as_ref(), signer.as_ref()],
+            &paid_stake::ID
+        );
+        if staker.key() != staker_key {
+            return err!(ErrCode::NotEligible);
+        }
+        match &self.checkpoints {
+            Some(checkpoints) => {
+                let (checkpoints_key, _) = Pubkey::find_program_address(
+                    &[b"checkpoints", stake_pool.as_ref(), signer.as_ref()],
+                    &paid_stake::ID
+                );
+                if checkpoints.key() != checkpoints_key {
+                    return err!(ErrCode::NotEligible);
+                }
+                Ok(checkpoints.min_balance_since(open_time))
+            }
+            // stake untouched since before checkpoints existed
+            None if staker.last_update <= open_time => Ok(staker.total_staked),
+            None => Ok(0),
+        }
+    }
+}
+
+// @dev allowed to buy ido token in an open round by eligible wallets
+pub fn buy_in_round_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyInRound<'info>>,
+    round: u8,
+    purchase_amount: u64,
+    proof: EligibilityProof
+) -> Result<()> {
+    let now: i64 = Clock::get()?.unix_timestamp;
+    let round_config: Round = match ctx.accounts.project.rounds.get(round as usize) {
+        Some(round_config) => *round_config,
+        None => {
+            return err!(ErrCode::InvalidRound);
+        }
+    };
+    if !round_config.is_open(now) {
+        return err!(ErrCode::RoundNotOpen);
+    }
+    if purchase_amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+
+    // check eligibility of signer
+    let signer: Pubkey = ctx.accounts.signer.key();
+    match (round_config.eligibility, proof) {
+        (Eligibility::Public, _) => {}
+        (Eligibility::Stake { stake_pool, min_stake }, _) => {
+            if ctx.accounts.held_stake(&stake_pool, round_config.open_time)? < min_stake {
+                return err!(ErrCode::NotEnoughStaker);
+            }
+        }
+        (Eligibility::Kyc { merkle_tree, authority }, EligibilityProof::Kyc { root, index }) => {
+            let (tree, compression_program) = match
+                (&ctx.accounts.merkle_tree, &ctx.accounts.compression_program)
+            {
+                (Some(tree), Some(compression_program)) if tree.key() == merkle_tree =>
+                    (tree, compression_program),
+                _ => {
+                    return err!(ErrCode::NotEligible);
+                }
+            };
+            compression::verify_leaf(
+                &compression_program.to_account_info(),
+                &tree.to_account_info(),
+                ctx.remaining_accounts,
+                root,
+                Eligibility::kyc_leaf(&signer, &authority),
+                index
+            )?;
+        }
+        (Eligibility::Allowlist { merkle_root }, EligibilityProof::Allowlist { proof }) => {
+            if !Eligibility::verify_allowlist(&merkle_root, &proof, &signer) {
+                return err!(ErrCode::NotEligible);
+            }
+        }
+        _ => {
+            return err!(ErrCode::NotEligible);
+        }
+    }
+
+    // partially fill purchase crossing round or wallet cap
+    if round_config.purchased_amount >= round_config.max_raise_amount {
+        return err!(ErrCode::HardCapReached);
+    }
+    let purchase_amount: u64 = round_config.fill_purchase_amount(
+        purchase_amount,
+        ctx.accounts.round_buyer.purchase_amount
+    );
+    if purchase_amount == 0 {
+        return err!(ErrCode::WalletCapReached);
+    }
+    let offer_amount: u64 = purchase_amount
+        .checked_mul(round_config.rate)
+        .ok_or(ErrCode::InvalidAmount)?;
+    // every allocation must be covered by offer vault
+    let project: &Account<Project> = &ctx.accounts.project;
+    if project.sold_offer_amount + offer_amount > project.total_funded_amount {
+        return err!(ErrCode::NotFunded);
+    }
+
+    token::transfer(ctx.accounts.transfer_ctx(), purchase_amount)?;
+    let project: &mut Box<Account<Project>> = &mut ctx.accounts.project;
+    project.purchase_bump = ctx.bumps.purchase_vault;
+    if project.purchase_vault_payer == Pubkey::default() {
+        project.purchase_vault_payer = signer;
+    }
+    project.sold_offer_amount += offer_amount;
+    project.rounds[round as usize].purchased_amount += purchase_amount;
+    let project_key: Pubkey = project.key();
+    let round_buyer: &mut Box<Account<RoundBuyer>> = &mut ctx.accounts.round_buyer;
+    round_buyer.project = project_key;
+    round_buyer.wallet = signer;
+    round_buyer.round = round;
+    round_buyer.purchase_amount += purchase_amount;
+    round_buyer.offer_amount += offer_amount;
+    emit!(RoundPurchased {
+        project: project_key,
+        round,
+        wallet: signer,
+        purchase_amount,
+        offer_amount,
+    });
+    msg!("Bought token in round");
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Pool, PoolStatus, Role, RoleAssignment };
+
+#[derive(Accounts)]
+pub struct CancelPool<'info> {
+    pub authority: Signer<'info>,
+
+    // @dev pool operator role of signer, none if signer is pool owner
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::PoolOperator as u8], authority.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Box<Account<'info, RoleAssignment>>>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev it allows to cancel pool by pool owner or pool operator before claims start
+pub fn cancel_pool_handler(ctx: Context<CancelPool>) -> Result<()> {
+    ctx.accounts.pool.require_authority(
+        ctx.accounts.authority.key,
+        ctx.accounts.operator_role.is_some()
+    )?;
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    // released raise is no longer in purchase vault to refund every buyer in full
+    if pool.released_purchase_amount > 0 {
+        return err!(ErrCode::RaiseAlreadyReleased);
+    }
+    pool.transition(PoolStatus::Cancelled)?;
+    msg!("Cancelled pool");
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Token, TokenAccount, Transfer };
+
+use crate::{ error::ErrCode, Buyer, Config, Pool, PoolStatus, LOCKUP_DURATION };
+
+#[derive(Accounts)]
+pub struct CancelPurchase<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // @dev purchase token account of user
+    #[account(
+        mut,
+        token::mint = pool.purchase_currency.mint,
+        token::authority = signer
+    )]
+    pub user_purchase_token: Account<'info, TokenAccount>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev buyer account
+    #[account(
+        mut,
+        seeds = [b"buyer", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub buyer: Box<Account<'info, Buyer>>,
+
+    // @dev purchase vault
+    #[account(
+        mut,
+        seeds = [b"purchase-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub purchase_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CancelPurchase<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), Transfer {
+            from: self.purchase_vault.to_account_info(),
+            to: self.user_purchase_token.to_account_info(),
+            authority: self.purchase_vault.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to cancel purchase by user within lockup duration of first purchase of the period, before pool closes
+// @dev refunds net purchase made within the period only, which is all buyer transferred
+pub fn cancel_purchase_handler(ctx: Context<CancelPurchase>) -> Result<()> {
+    let now: i64 = Clock::get()?.unix_timestamp;
+    // validate status
+    ctx.accounts.pool.refresh_status(now)?;
+    ctx.accounts.pool.require_status(&[PoolStatus::EarlyOpen, PoolStatus::OpenOpen])?;
+    // validate time
+    if now > ctx.accounts.buyer.cooling_off_start + LOCKUP_DURATION {
+        return err!(ErrCode::CoolingOffPeriodEnded);
+    }
+    // validate amount
+    let refund_amount: u64 = ctx.accounts.buyer.cancellable_purchase;
+    let early_refund_amount: u64 = ctx.accounts.buyer.cancellable_early_purchase;
+    if refund_amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+    // seeds of authority pda of purchase vault
+    let seeds: &[&[u8]; 3] = &[
+        b"purchase-vault",
+        ctx.accounts.pool.to_account_info().key.as_ref(),
+        &[ctx.bumps.purchase_vault],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    // transfer token to user token account
+    token::transfer(ctx.accounts.transfer_ctx().with_signer(signer), refund_amount)?;
+
+    // update pool info
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.purchased_amount -= refund_amount;
+    pool.purchased_amount_in_early_access -= early_refund_amount;
+    pool.purchased_amount_in_open_pool -= refund_amount - early_refund_amount;
+    // update buyer info
+    let buyer: &mut Box<Account<Buyer>> = &mut ctx.accounts.buyer;
+    buyer.total_purchase -= refund_amount;
+    buyer.early_purchased -= early_refund_amount;
+    buyer.total_amount -= buyer.cancellable_amount;
+    buyer.cancellable_purchase = 0;
+    buyer.cancellable_early_purchase = 0;
+    buyer.cancellable_amount = 0;
+    msg!("Cancelled purchase");
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+use crate::{
+    error::ErrCode,
+    Buyer,
+    Pool,
+    Proposal,
+    ProposalStatus,
+    ProposalVote,
+    ProposalVoted,
+};
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // @dev pool account
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev buyer account, vote weight is its total purchase
+    #[account(
+        seeds = [b"buyer", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub buyer: Box<Account<'info, Buyer>>,
+
+    // @dev proposal of pool
+    #[account(mut, has_one = pool)]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    // @dev vote record, fails to init if wallet already voted
+    #[account(
+        init,
+        payer = signer,
+        space = size_of::<ProposalVote>() + 8,
+        seeds = [b"proposal-vote", proposal.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub proposal_vote: Box<Account<'info, ProposalVote>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// @dev allowed to vote on active proposal by buyer until deadline
+pub fn cast_vote_handler(ctx: Context<CastVote>, approve: bool) -> Result<()> {
+    let proposal: &mut Box<Account<Proposal>> = &mut ctx.accounts.proposal;
+    if proposal.status != ProposalStatus::Active {
+        return err!(ErrCode::ProposalNotActive);
+    }
+    if Clock::get()?.unix_timestamp >= proposal.deadline {
+        return err!(ErrCode::VotingEnded);
+    }
+    let weight: u64 = ctx.accounts.buyer.total_purchase;
+    if weight == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+    if approve {
+        proposal.votes_for += weight;
+    } else {
+        proposal.votes_against += weight;
+    }
+    let vote: &mut Box<Account<ProposalVote>> = &mut ctx.accounts.proposal_vote;
+    vote.proposal = proposal.key();
+    vote.wallet = ctx.accounts.signer.key();
+    vote.weight = weight;
+    vote.approve = approve;
+    emit!(ProposalVoted {
+        proposal: proposal.key(),
+        wallet: vote.wallet,
+        weight,
+        approve,
+    });
+    msg!("Voted on proposal");
+    Ok(())
+}
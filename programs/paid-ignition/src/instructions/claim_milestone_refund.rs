@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Token, TokenAccount, Transfer };
+
+use crate::{ error::ErrCode, Buyer, MilestonePlan, MilestoneRefunded, Pool };
+
+#[derive(Accounts)]
+pub struct ClaimMilestoneRefund<'info> {
+    pub signer: Signer<'info>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev buyer account
+    #[account(
+        mut,
+        seeds = [b"buyer", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub buyer: Box<Account<'info, Buyer>>,
+
+    // @dev milestone plan of pool
+    #[account(
+        mut,
+        seeds = [b"milestones", pool.key().as_ref()],
+        bump = milestone_plan.bump
+    )]
+    pub milestone_plan: Box<Account<'info, MilestonePlan>>,
+
+    // @dev purchase vault, source of refund
+    #[account(
+        mut,
+        seeds = [b"purchase-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub purchase_vault: Box<Account<'info, TokenAccount>>,
+
+    // @dev purchase token account of buyer
+    #[account(
+        mut,
+        token::mint = pool.purchase_currency.mint,
+        token::authority = signer
+    )]
+    pub user_purchase_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// @dev allowed to withdraw share of unreleased raise by buyer once buyers approved refund
+// @dev ido token allocation of buyer is scaled down by the same share, forfeited part becomes unsold
+// @dev refund path, intentionally not blocked by pause
+pub fn claim_milestone_refund_handler(ctx: Context<ClaimMilestoneRefund>) -> Result<()> {
+    if !ctx.accounts.milestone_plan.refund_passed {
+        return err!(ErrCode::RefundVoteNotPassed);
+    }
+    if ctx.accounts.buyer.milestone_refunded {
+        return err!(ErrCode::AlreadyRefunded);
+    }
+    let buyer: &Account<Buyer> = &ctx.accounts.buyer;
+    let plan: &Account<MilestonePlan> = &ctx.accounts.milestone_plan;
+    // allocation shrinks to released share, anything claimed beyond it is paid back out of refund
+    let kept_amount: u64 = plan.kept_amount(buyer.total_amount);
+    let rate: u64 = ctx.accounts.pool.offered_currency.rate;
+    if rate == 0 {
+        return err!(ErrCode::InvalidParam);
+    }
+    let overclaimed_amount: u64 = buyer.cliamed_amount.saturating_sub(kept_amount);
+    let overclaimed_purchase: u64 = overclaimed_amount.div_ceil(rate);
+    let amount: u64 = plan.refund_amount(buyer.total_purchase).saturating_sub(overclaimed_purchase);
+    if amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+    // seed of authority pda of purchase vault
+    let pool_key: Pubkey = ctx.accounts.pool.key();
+    let seeds: &[&[u8]; 3] = &[
+        b"purchase-vault",
+        pool_key.as_ref(),
+        &[ctx.bumps.purchase_vault],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.purchase_vault.to_account_info(),
+            to: ctx.accounts.user_purchase_token.to_account_info(),
+            authority: ctx.accounts.purchase_vault.to_account_info(),
+        },
+        signer
+    );
+    token::transfer(transfer_ctx, amount)?;
+    // forfeited allocation goes back to unsold, finalize_unsold carries it out
+    let buyer: &mut Box<Account<Buyer>> = &mut ctx.accounts.buyer;
+    let total_amount: u64 = kept_amount.max(buyer.cliamed_amount);
+    ctx.accounts.pool.forfeited_offer_amount += buyer.total_amount - total_amount;
+    buyer.total_amount = total_amount;
+    buyer.milestone_refunded = true;
+    ctx.accounts.milestone_plan.refunded_amount += amount;
+    ctx.accounts.milestone_plan.refunded_purchase += buyer.total_purchase;
+    emit!(MilestoneRefunded {
+        pool: pool_key,
+        wallet: ctx.accounts.signer.key(),
+        amount,
+    });
+    msg!("Refunded unreleased raise");
+    Ok(())
+}
@@ -1,31 +1,86 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::{ self, AssociatedToken, Create },
+    associated_token::AssociatedToken,
     token::{ self, Mint, Token, TokenAccount, Transfer },
 };
 
-use crate::{ calculate_claimable_amount, error::ErrCode, Buyer, Pool };
+use crate::{
+    calculate_claimable_amount,
+    error::ErrCode,
+    Buyer,
+    ClaimHistory,
+    ClaimRecord,
+    Config,
+    OfferClaimed,
+    Pool,
+    PoolStatus,
+    MAX_CLAIM_RECORDS,
+};
 
 #[derive(Accounts)]
 pub struct ClaimOffer<'info> {
+    // @dev buyer wallet or its claim delegate
     #[account(mut)]
     pub signer: Signer<'info>,
 
-    /// CHECK: we don't read and write this account
-    #[account(mut)]
-    pub user_token: AccountInfo<'info>,
+    /// CHECK: only used as seed of buyer account
+    pub wallet: UncheckedAccount<'info>,
 
     // mint address of ido token
+    #[account(
+        constraint = offer_mint.key() == pool.offered_currency.mint @ ErrCode::InvalidMint
+    )]
     pub offer_mint: Box<Account<'info, Mint>>,
 
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
     // pool account
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
     pub pool: Box<Account<'info, Pool>>,
 
     // buyer account
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"buyer", pool.key().as_ref(), wallet.key().as_ref()],
+        bump,
+        constraint = buyer.is_claim_authority(signer.key) @ ErrCode::NotClaimAuthority
+    )]
     pub buyer: Box<Account<'info, Buyer>>,
 
+    // @dev claim history of buyer, sized for MAX_CLAIM_RECORDS so only the first claim pays rent
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ClaimHistory::space(MAX_CLAIM_RECORDS),
+        seeds = [b"claim-history", pool.key().as_ref(), wallet.key().as_ref()],
+        bump
+    )]
+    pub claim_history: Box<Account<'info, ClaimHistory>>,
+
+    /// CHECK: owner of destination token account, checked against buyer claim destination
+    #[account(
+        constraint = destination_wallet.key() == buyer.claim_destination() @ ErrCode::InvalidClaimAccounts
+    )]
+    pub destination_wallet: UncheckedAccount<'info>,
+
+    // @dev associated ido token account of destination wallet, created if empty
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = offer_mint,
+        associated_token::authority = destination_wallet
+    )]
+    pub user_token: Box<Account<'info, TokenAccount>>,
+
     // offer vault
     #[account(
         mut,
@@ -42,16 +97,6 @@ pub struct ClaimOffer<'info> {
 }
 
 impl<'info> ClaimOffer<'info> {
-    pub fn create_ctx(&self) -> CpiContext<'info, 'info, 'info, 'info, Create<'info>> {
-        CpiContext::new(self.associated_token_program.to_account_info(), Create {
-            payer: self.signer.to_account_info(),
-            associated_token: self.user_token.clone(),
-            authority: self.signer.to_account_info(),
-            mint: self.offer_mint.to_account_info(),
-            system_program: self.system_program.to_account_info(),
-            token_program: self.token_program.to_account_info(),
-        })
-    }
     fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(self.token_program.to_account_info(), Transfer {
             from: self.offer_vault.to_account_info(),
@@ -61,23 +106,17 @@ impl<'info> ClaimOffer<'info> {
     }
 }
 
-// @dev allowed to unlock ido token by user after success
-pub fn claim_offer_handler(ctx: Context<ClaimOffer>) -> Result<()> {
-    let buyer: &Box<Account<Buyer>> = &ctx.accounts.buyer;
+// @dev allowed to unlock vested ido token by user or claim delegate after success
+pub fn claim_offer_handler(ctx: Context<ClaimOffer>, amount: u64) -> Result<()> {
     // check if allowed to claim
-    if !ctx.accounts.pool.claimable {
-        return err!(ErrCode::NotClaimable);
-    }
+    ctx.accounts.pool.require_status(&[PoolStatus::Claiming, PoolStatus::Finalized])?;
+    let buyer: &Account<Buyer> = &ctx.accounts.buyer;
 
     // check if has claimable amount
     if buyer.cliamed_amount >= buyer.total_amount {
         return err!(ErrCode::AlreadyClaimedTotoalAmount);
     }
 
-    // create token account if empty
-    if ctx.accounts.user_token.data_is_empty() {
-        associated_token::create(ctx.accounts.create_ctx())?;
-    }
     // check vesting amount
     let now: i64 = ctx.accounts.clock.unix_timestamp as i64;
     let claimable_amount: u64 = calculate_claimable_amount(
@@ -94,17 +133,41 @@ pub fn claim_offer_handler(ctx: Context<ClaimOffer>) -> Result<()> {
     if claimable_amount == 0 {
         return err!(ErrCode::NotclaimableAmount);
     }
+    // claim explicit amount up to vested maximum
+    if amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+    if amount > claimable_amount {
+        return err!(ErrCode::ExceedsClaimableAmount);
+    }
     // seeds of authority pda of offer vault
-    let seeds: &[&[u8]; 3] = &[
-        b"ido-vault",
-        ctx.accounts.pool.to_account_info().key.as_ref(),
-        &[ctx.accounts.pool.offered_bump],
-    ];
+    let pool_key: Pubkey = ctx.accounts.pool.key();
+    let seeds: &[&[u8]; 3] = &[b"offer-vault", pool_key.as_ref(), &[ctx.bumps.offer_vault]];
     let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
-    //   transfer token to user token account
-    token::transfer(ctx.accounts.transfer_ctx().with_signer(signer), claimable_amount)?;
-    // send ido token to user
-    // let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    // transfer token to destination token account
+    token::transfer(ctx.accounts.transfer_ctx().with_signer(signer), amount)?;
+    ctx.accounts.pool.fund_claimed_amount += amount;
+    let buyer: &mut Box<Account<Buyer>> = &mut ctx.accounts.buyer;
+    buyer.cliamed_amount += amount;
+    let claimed_amount: u64 = buyer.cliamed_amount;
+
+    // signer of the first claim paid rent of claim history
+    let claim_history: &mut Box<Account<ClaimHistory>> = &mut ctx.accounts.claim_history;
+    if claim_history.rent_payer == Pubkey::default() {
+        claim_history.rent_payer = ctx.accounts.signer.key();
+    }
+    claim_history.pool = pool_key;
+    claim_history.wallet = ctx.accounts.wallet.key();
+    claim_history.record(ClaimRecord { claimed_at: now, amount });
+
+    emit!(OfferClaimed {
+        pool: pool_key,
+        wallet: ctx.accounts.wallet.key(),
+        signer: ctx.accounts.signer.key(),
+        destination: ctx.accounts.user_token.key(),
+        amount,
+        claimed_amount,
+    });
     msg!("Unlocked IDO");
     Ok(())
 }
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Token, TokenAccount, Transfer };
+
+use crate::{ error::ErrCode, Buyer, Config, Pool, PoolStatus, PriceProtectionClaimed };
+
+#[derive(Accounts)]
+pub struct ClaimPriceProtection<'info> {
+    pub signer: Signer<'info>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev buyer account
+    #[account(
+        mut,
+        seeds = [b"buyer", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub buyer: Box<Account<'info, Buyer>>,
+
+    // @dev purchase vault, holds protection reserve
+    #[account(
+        mut,
+        seeds = [b"purchase-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub purchase_vault: Box<Account<'info, TokenAccount>>,
+
+    // @dev purchase token account of buyer
+    #[account(
+        mut,
+        token::mint = pool.purchase_currency.mint,
+        token::authority = signer
+    )]
+    pub user_purchase_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// @dev allowed to return whole ido token allocation for purchase token by buyer within window after tge
+// @dev only buyers who never claimed, refunds are paid from protection reserve until it runs out
+pub fn claim_price_protection_handler(ctx: Context<ClaimPriceProtection>) -> Result<()> {
+    let now: i64 = Clock::get()?.unix_timestamp;
+    ctx.accounts.pool.refresh_status(now)?;
+    ctx.accounts.pool.require_status(
+        &[PoolStatus::Succeeded, PoolStatus::Claiming, PoolStatus::Finalized]
+    )?;
+    if ctx.accounts.pool.protection_window == 0 {
+        return err!(ErrCode::ProtectionNotConfigured);
+    }
+    if !ctx.accounts.pool.in_protection_window(now) {
+        return err!(ErrCode::ProtectionWindowClosed);
+    }
+    let buyer: &Account<Buyer> = &ctx.accounts.buyer;
+    if buyer.cliamed_amount > 0 || buyer.milestone_refunded {
+        return err!(ErrCode::AlreadyClaimed);
+    }
+    let amount: u64 = buyer.total_purchase;
+    if amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+    if ctx.accounts.pool.protected_amount + amount > ctx.accounts.pool.protection_reserve() {
+        return err!(ErrCode::ProtectionCapReached);
+    }
+    // seed of authority pda of purchase vault
+    let pool_key: Pubkey = ctx.accounts.pool.key();
+    let seeds: &[&[u8]; 3] = &[
+        b"purchase-vault",
+        pool_key.as_ref(),
+        &[ctx.bumps.purchase_vault],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.purchase_vault.to_account_info(),
+            to: ctx.accounts.user_purchase_token.to_account_info(),
+            authority: ctx.accounts.purchase_vault.to_account_info(),
+        },
+        signer
+    );
+    token::transfer(transfer_ctx, amount)?;
+    // returned allocation stays in offer vault and counts as unsold
+    let offer_amount: u64 = ctx.accounts.buyer.total_amount;
+    ctx.accounts.pool.protected_amount += amount;
+    let buyer: &mut Box<Account<Buyer>> = &mut ctx.accounts.buyer;
+    buyer.total_purchase = 0;
+    buyer.early_purchased = 0;
+    buyer.total_amount = 0;
+    emit!(PriceProtectionClaimed {
+        pool: pool_key,
+        wallet: ctx.accounts.signer.key(),
+        purchase_amount: amount,
+        offer_amount,
+        protected_amount: ctx.accounts.pool.protected_amount,
+    });
+    msg!("Claimed price protection");
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ self, Mint, Token, TokenAccount, Transfer },
+};
+
+use crate::{ error::ErrCode, Config, Project, RoundBuyer, RoundOfferClaimed };
+
+#[derive(Accounts)]
+#[instruction(round: u8)]
+pub struct ClaimRoundOffer<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // mint address of ido token
+    #[account(
+        constraint = offer_mint.key() == project.offer_mint @ ErrCode::InvalidMint
+    )]
+    pub offer_mint: Box<Account<'info, Mint>>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev project account
+    pub project: Box<Account<'info, Project>>,
+
+    // @dev allocation of signer in round
+    #[account(
+        mut,
+        seeds = [b"round-buyer", project.key().as_ref(), &[round], signer.key().as_ref()],
+        bump
+    )]
+    pub round_buyer: Box<Account<'info, RoundBuyer>>,
+
+    // @dev associated ido token account of signer, created if empty
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = offer_mint,
+        associated_token::authority = signer
+    )]
+    pub user_token: Box<Account<'info, TokenAccount>>,
+
+    // @dev offer vault shared by all rounds
+    #[account(
+        mut,
+        seeds = [b"project-offer-vault", project.key().as_ref()],
+        bump = project.offer_bump,
+        token::mint = offer_mint,
+    )]
+    pub offer_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimRoundOffer<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), Transfer {
+            from: self.offer_vault.to_account_info(),
+            to: self.user_token.to_account_info(),
+            authority: self.offer_vault.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to unlock ido token vested by vesting schedule of round after its tge
+pub fn claim_round_offer_handler(ctx: Context<ClaimRoundOffer>, round: u8, amount: u64) -> Result<()> {
+    let project: &Account<Project> = &ctx.accounts.project;
+    let round_buyer: &Account<RoundBuyer> = &ctx.accounts.round_buyer;
+    if round_buyer.claimed_amount >= round_buyer.offer_amount {
+        return err!(ErrCode::AlreadyClaimedTotoalAmount);
+    }
+    let claimable_amount: u64 = project.rounds[round as usize].vesting.claimable_amount(
+        round_buyer.offer_amount,
+        round_buyer.claimed_amount,
+        Clock::get()?.unix_timestamp
+    );
+    if claimable_amount == 0 {
+        return err!(ErrCode::NotclaimableAmount);
+    }
+    if amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+    if amount > claimable_amount {
+        return err!(ErrCode::ExceedsClaimableAmount);
+    }
+    // seeds of authority pda of offer vault
+    let project_key: Pubkey = project.key();
+    let seeds: &[&[u8]; 3] = &[b"project-offer-vault", project_key.as_ref(), &[project.offer_bump]];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    token::transfer(ctx.accounts.transfer_ctx().with_signer(signer), amount)?;
+    let round_buyer: &mut Box<Account<RoundBuyer>> = &mut ctx.accounts.round_buyer;
+    round_buyer.claimed_amount += amount;
+    emit!(RoundOfferClaimed {
+        project: project_key,
+        round,
+        wallet: round_buyer.wallet,
+        amount,
+        claimed_amount: round_buyer.claimed_amount,
+    });
+    msg!("Unlocked IDO of round");
+    Ok(())
+}
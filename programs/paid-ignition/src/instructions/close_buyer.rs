@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Buyer };
+
+#[derive(Accounts)]
+pub struct CloseBuyer<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: only used as seed of buyer account, may be closed already
+    pub pool: UncheckedAccount<'info>,
+
+    // @dev buyer account, rent goes back to buyer who paid it
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"buyer", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub buyer: Box<Account<'info, Buyer>>,
+}
+
+// @dev allowed to close buyer account by user once fully claimed or refunded
+pub fn close_buyer_handler(ctx: Context<CloseBuyer>) -> Result<()> {
+    let buyer: &Account<Buyer> = &ctx.accounts.buyer;
+    if buyer.total_purchase != 0 && buyer.cliamed_amount < buyer.total_amount {
+        return err!(ErrCode::NotSettled);
+    }
+    msg!("Closed buyer");
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, ClaimHistory };
+
+#[derive(Accounts)]
+pub struct CloseClaimHistory<'info> {
+    pub signer: Signer<'info>,
+
+    /// CHECK: receives rent, checked against claim history
+    #[account(mut, address = claim_history.rent_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    /// CHECK: only used as seed of claim history, may be closed already
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: buyer account of signer, must be closed already
+    #[account(seeds = [b"buyer", pool.key().as_ref(), signer.key().as_ref()], bump)]
+    pub buyer: UncheckedAccount<'info>,
+
+    // @dev claim history of signer, rent goes back to wallet that paid it
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"claim-history", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub claim_history: Box<Account<'info, ClaimHistory>>,
+}
+
+// @dev allowed to close claim history by user once buyer account is closed as settled
+pub fn close_claim_history_handler(ctx: Context<CloseClaimHistory>) -> Result<()> {
+    if ctx.accounts.buyer.lamports() != 0 {
+        return err!(ErrCode::NotSettled);
+    }
+    msg!("Closed claim history");
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, MilestonePlan };
+
+#[derive(Accounts)]
+pub struct CloseMilestonePlan<'info> {
+    /// CHECK: receives rent, checked against milestone plan
+    #[account(mut, address = milestone_plan.rent_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    /// CHECK: only used as seed of milestone plan, may be closed already
+    pub pool: UncheckedAccount<'info>,
+
+    // @dev milestone plan of pool
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"milestones", pool.key().as_ref()],
+        bump = milestone_plan.bump
+    )]
+    pub milestone_plan: Box<Account<'info, MilestonePlan>>,
+
+    /// CHECK: purchase vault, must be closed already
+    #[account(seeds = [b"purchase-vault", pool.key().as_ref()], bump)]
+    pub purchase_vault: UncheckedAccount<'info>,
+}
+
+// @dev allowed to close milestone plan by anyone once purchase vault is closed, rent goes back to payer
+// @dev closed vault means no tranche or refund is left to pay out of it
+pub fn close_milestone_plan_handler(ctx: Context<CloseMilestonePlan>) -> Result<()> {
+    if !ctx.accounts.purchase_vault.data_is_empty() {
+        return err!(ErrCode::VaultNotClosed);
+    }
+    msg!("Closed milestone plan");
+    Ok(())
+}
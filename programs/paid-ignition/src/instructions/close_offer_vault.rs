@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, CloseAccount, Token, TokenAccount };
+
+use crate::{ error::ErrCode, Pool, PoolStatus };
+
+#[derive(Accounts)]
+pub struct CloseOfferVault<'info> {
+    /// CHECK: receives rent, checked against pool
+    #[account(mut, address = pool.offer_vault_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev purchase vault
+    #[account(
+        mut,
+        seeds = [b"offer-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub offer_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseOfferVault<'info> {
+    fn close_ctx(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), CloseAccount {
+            account: self.offer_vault.to_account_info(),
+            destination: self.rent_payer.to_account_info(),
+            authority: self.offer_vault.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to close empty offer vault by anyone after sale, rent goes back to payer
+pub fn close_offer_vault_handler(ctx: Context<CloseOfferVault>) -> Result<()> {
+    // validate status
+    ctx.accounts.pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    ctx.accounts.pool.require_status(
+        &[PoolStatus::Failed, PoolStatus::Cancelled, PoolStatus::Claiming, PoolStatus::Finalized]
+    )?;
+    if ctx.accounts.offer_vault.amount != 0 {
+        return err!(ErrCode::NotSettled);
+    }
+    // seeds of authority pda of purchase vault
+    let seeds: &[&[u8]; 3] = &[
+        b"offer-vault",
+        ctx.accounts.pool.to_account_info().key.as_ref(),
+        &[ctx.bumps.offer_vault],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    token::close_account(ctx.accounts.close_ctx().with_signer(signer))?;
+    ctx.accounts.pool.offer_vault_payer = Pubkey::default();
+    msg!("Closed offer vault");
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Pool, PoolMetadata, PoolStatus };
+
+#[derive(Accounts)]
+pub struct ClosePool<'info> {
+    pub owner: Signer<'info>,
+
+    /// CHECK: receives rent, checked against pool
+    #[account(mut, address = pool.rent_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        has_one = owner,
+        close = rent_payer
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev display metadata of pool, closed with it
+    #[account(
+        mut,
+        seeds = [b"metadata", pool.key().as_ref()],
+        bump = pool_metadata.bump,
+        close = rent_payer
+    )]
+    pub pool_metadata: Box<Account<'info, PoolMetadata>>,
+
+    /// CHECK: purchase vault, must be closed already
+    #[account(seeds = [b"purchase-vault", pool.key().as_ref()], bump)]
+    pub purchase_vault: UncheckedAccount<'info>,
+
+    /// CHECK: offer vault, must be closed already
+    #[account(seeds = [b"offer-vault", pool.key().as_ref()], bump)]
+    pub offer_vault: UncheckedAccount<'info>,
+}
+
+// @dev allowed to close finalized pool by owner once both vaults are closed
+pub fn close_pool_handler(ctx: Context<ClosePool>) -> Result<()> {
+    ctx.accounts.pool.require_status(&[PoolStatus::Finalized])?;
+    if !ctx.accounts.purchase_vault.data_is_empty() || !ctx.accounts.offer_vault.data_is_empty() {
+        return err!(ErrCode::VaultNotClosed);
+    }
+    msg!("Closed pool");
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Project };
+
+#[derive(Accounts)]
+pub struct CloseProject<'info> {
+    pub owner: Signer<'info>,
+
+    /// CHECK: receives rent, checked against project
+    #[account(mut, address = project.rent_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    // @dev project account
+    #[account(
+        mut,
+        has_one = owner,
+        close = rent_payer
+    )]
+    pub project: Box<Account<'info, Project>>,
+
+    /// CHECK: purchase vault, must be closed or never created
+    #[account(seeds = [b"project-purchase-vault", project.key().as_ref()], bump)]
+    pub purchase_vault: UncheckedAccount<'info>,
+
+    /// CHECK: offer vault, must be closed or never created
+    #[account(seeds = [b"project-offer-vault", project.key().as_ref()], bump)]
+    pub offer_vault: UncheckedAccount<'info>,
+}
+
+// @dev allowed to close project by owner once every round closed and both vaults are closed
+pub fn close_project_handler(ctx: Context<CloseProject>) -> Result<()> {
+    let project: &Account<Project> = &ctx.accounts.project;
+    if !project.rounds.is_empty() && !project.is_closed(Clock::get()?.unix_timestamp) {
+        return err!(ErrCode::RoundNotClosed);
+    }
+    if !ctx.accounts.purchase_vault.data_is_empty() || !ctx.accounts.offer_vault.data_is_empty() {
+        return err!(ErrCode::VaultNotClosed);
+    }
+    msg!("Closed project");
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, CloseAccount, Token, TokenAccount };
+
+use crate::{ error::ErrCode, Project };
+
+#[derive(Accounts)]
+pub struct CloseProjectOfferVault<'info> {
+    /// CHECK: receives rent, checked against project
+    #[account(mut, address = project.offer_vault_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    // @dev project account
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    // @dev offer vault shared by all rounds
+    #[account(
+        mut,
+        seeds = [b"project-offer-vault", project.key().as_ref()],
+        bump = project.offer_bump
+    )]
+    pub offer_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseProjectOfferVault<'info> {
+    fn close_ctx(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), CloseAccount {
+            account: self.offer_vault.to_account_info(),
+            destination: self.rent_payer.to_account_info(),
+            authority: self.offer_vault.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to close empty offer vault by anyone once every round closed, allocations are
+// claimed and excess is withdrawn, rent goes back to payer
+pub fn close_project_offer_vault_handler(ctx: Context<CloseProjectOfferVault>) -> Result<()> {
+    if !ctx.accounts.project.is_closed(Clock::get()?.unix_timestamp) {
+        return err!(ErrCode::RoundNotClosed);
+    }
+    if ctx.accounts.offer_vault.amount != 0 {
+        return err!(ErrCode::NotSettled);
+    }
+    // seeds of authority pda of offer vault
+    let project_key: Pubkey = ctx.accounts.project.key();
+    let seeds: &[&[u8]; 3] = &[
+        b"project-offer-vault",
+        project_key.as_ref(),
+        &[ctx.accounts.project.offer_bump],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    token::close_account(ctx.accounts.close_ctx().with_signer(signer))?;
+    ctx.accounts.project.offer_vault_payer = Pubkey::default();
+    msg!("Closed project offer vault");
+    Ok(())
+}
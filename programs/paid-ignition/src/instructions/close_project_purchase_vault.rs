@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, CloseAccount, Token, TokenAccount };
+
+use crate::{ error::ErrCode, Project };
+
+#[derive(Accounts)]
+pub struct CloseProjectPurchaseVault<'info> {
+    /// CHECK: receives rent, checked against project
+    #[account(mut, address = project.purchase_vault_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    // @dev project account
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    // @dev purchase vault shared by all rounds
+    #[account(
+        mut,
+        seeds = [b"project-purchase-vault", project.key().as_ref()],
+        bump = project.purchase_bump
+    )]
+    pub purchase_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseProjectPurchaseVault<'info> {
+    fn close_ctx(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), CloseAccount {
+            account: self.purchase_vault.to_account_info(),
+            destination: self.rent_payer.to_account_info(),
+            authority: self.purchase_vault.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to close empty purchase vault by anyone once every round closed and its raise
+// is withdrawn, rent goes back to payer
+pub fn close_project_purchase_vault_handler(ctx: Context<CloseProjectPurchaseVault>) -> Result<()> {
+    if !ctx.accounts.project.is_closed(Clock::get()?.unix_timestamp) {
+        return err!(ErrCode::RoundNotClosed);
+    }
+    if ctx.accounts.purchase_vault.amount != 0 {
+        return err!(ErrCode::NotSettled);
+    }
+    // seeds of authority pda of purchase vault
+    let project_key: Pubkey = ctx.accounts.project.key();
+    let seeds: &[&[u8]; 3] = &[
+        b"project-purchase-vault",
+        project_key.as_ref(),
+        &[ctx.accounts.project.purchase_bump],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    token::close_account(ctx.accounts.close_ctx().with_signer(signer))?;
+    ctx.accounts.project.purchase_vault_payer = Pubkey::default();
+    msg!("Closed project purchase vault");
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Proposal, ProposalStatus };
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    /// CHECK: receives rent, checked against proposal
+    #[account(mut, address = proposal.proposer)]
+    pub proposer: AccountInfo<'info>,
+
+    /// CHECK: only used as seed of proposal, may be closed already
+    pub pool: UncheckedAccount<'info>,
+
+    // @dev proposal of pool
+    #[account(
+        mut,
+        close = proposer,
+        has_one = pool,
+        seeds = [b"proposal", pool.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+// @dev allowed to close proposal by anyone once rejected or executed, rent goes back to proposer
+// @dev approved proposal nobody acted on can be closed once pool is closed
+pub fn close_proposal_handler(ctx: Context<CloseProposal>) -> Result<()> {
+    let proposal: &Account<Proposal> = &ctx.accounts.proposal;
+    let settled: bool = match proposal.status {
+        ProposalStatus::Active => false,
+        ProposalStatus::Rejected => true,
+        ProposalStatus::Approved => proposal.executed || ctx.accounts.pool.data_is_empty(),
+    };
+    if !settled {
+        return err!(ErrCode::ProposalNotSettled);
+    }
+    msg!("Closed proposal");
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Proposal, ProposalStatus, ProposalVote };
+
+#[derive(Accounts)]
+pub struct CloseProposalVote<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: only used as seed of vote record, may be closed already
+    pub proposal: UncheckedAccount<'info>,
+
+    // @dev vote record of signer, rent goes back to voter who paid it
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"proposal-vote", proposal.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub proposal_vote: Box<Account<'info, ProposalVote>>,
+}
+
+// @dev allowed to close vote record by voter once voting on proposal ended or proposal is closed
+pub fn close_proposal_vote_handler(ctx: Context<CloseProposalVote>) -> Result<()> {
+    // vote record keeps wallet from voting twice while proposal is active
+    if !ctx.accounts.proposal.data_is_empty() {
+        let proposal: Proposal = Proposal::try_deserialize(
+            &mut &ctx.accounts.proposal.try_borrow_data()?[..]
+        )?;
+        if proposal.status == ProposalStatus::Active {
+            return err!(ErrCode::ProposalNotSettled);
+        }
+    }
+    msg!("Closed proposal vote");
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, CloseAccount, Token, TokenAccount };
+
+use crate::{ error::ErrCode, Pool, PoolStatus };
+
+#[derive(Accounts)]
+pub struct ClosePurchaseVault<'info> {
+    /// CHECK: receives rent, checked against pool
+    #[account(mut, address = pool.purchase_vault_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev purchase vault
+    #[account(
+        mut,
+        seeds = [b"purchase-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub purchase_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClosePurchaseVault<'info> {
+    fn close_ctx(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), CloseAccount {
+            account: self.purchase_vault.to_account_info(),
+            destination: self.rent_payer.to_account_info(),
+            authority: self.purchase_vault.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to close empty purchase vault by anyone after sale, rent goes back to payer
+pub fn close_purchase_vault_handler(ctx: Context<ClosePurchaseVault>) -> Result<()> {
+    // validate status
+    ctx.accounts.pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    ctx.accounts.pool.require_status(
+        &[PoolStatus::Failed, PoolStatus::Cancelled, PoolStatus::Claiming, PoolStatus::Finalized]
+    )?;
+    if ctx.accounts.purchase_vault.amount != 0 {
+        return err!(ErrCode::NotSettled);
+    }
+    // seeds of authority pda of purchase vault
+    let seeds: &[&[u8]; 3] = &[
+        b"purchase-vault",
+        ctx.accounts.pool.to_account_info().key.as_ref(),
+        &[ctx.bumps.purchase_vault],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    token::close_account(ctx.accounts.close_ctx().with_signer(signer))?;
+    ctx.accounts.pool.purchase_vault_payer = Pubkey::default();
+    msg!("Closed purchase vault");
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, RoundBuyer };
+
+#[derive(Accounts)]
+#[instruction(round: u8)]
+pub struct CloseRoundBuyer<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: only used as seed of round buyer, may be closed already
+    pub project: UncheckedAccount<'info>,
+
+    // @dev allocation of signer in round, rent goes back to buyer who paid it
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"round-buyer", project.key().as_ref(), &[round], signer.key().as_ref()],
+        bump
+    )]
+    pub round_buyer: Box<Account<'info, RoundBuyer>>,
+}
+
+// @dev allowed to close round allocation by user once it is fully claimed
+pub fn close_round_buyer_handler(ctx: Context<CloseRoundBuyer>, _round: u8) -> Result<()> {
+    let round_buyer: &Account<RoundBuyer> = &ctx.accounts.round_buyer;
+    if round_buyer.claimed_amount < round_buyer.offer_amount {
+        return err!(ErrCode::NotSettled);
+    }
+    msg!("Closed round buyer");
+    Ok(())
+}
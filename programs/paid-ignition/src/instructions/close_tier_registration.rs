@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Pool, TierRegistration };
+
+#[derive(Accounts)]
+pub struct CloseTierRegistration<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: only used as seed of tier registration, may be closed already
+    pub pool: UncheckedAccount<'info>,
+
+    // @dev tier registration of signer, rent goes back to registered wallet who paid it
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"tier-registration", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub tier_registration: Box<Account<'info, TierRegistration>>,
+}
+
+// @dev allowed to close tier registration by user once early pool closed or pool is closed
+pub fn close_tier_registration_handler(ctx: Context<CloseTierRegistration>) -> Result<()> {
+    // registration backs early pool allocation until early pool closes
+    if !ctx.accounts.pool.data_is_empty() {
+        let pool: Pool = Pool::try_deserialize(&mut &ctx.accounts.pool.try_borrow_data()?[..])?;
+        if Clock::get()?.unix_timestamp < pool.early_pool_close_time {
+            return err!(ErrCode::InvalidTime);
+        }
+    }
+    msg!("Closed tier registration");
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Pool, TierTable };
+
+#[derive(Accounts)]
+pub struct CloseTierTable<'info> {
+    /// CHECK: receives rent, checked against tier table
+    #[account(mut, address = tier_table.rent_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    /// CHECK: only used as seed of tier table, may be closed already
+    pub pool: UncheckedAccount<'info>,
+
+    // @dev tier table of pool
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"tiers", pool.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Box<Account<'info, TierTable>>,
+}
+
+// @dev allowed to close tier table by anyone once early pool closed, rent goes back to payer
+pub fn close_tier_table_handler(ctx: Context<CloseTierTable>) -> Result<()> {
+    // tier table sizes early pool allocations until early pool closes
+    if !ctx.accounts.pool.data_is_empty() {
+        let pool: Pool = Pool::try_deserialize(&mut &ctx.accounts.pool.try_borrow_data()?[..])?;
+        if Clock::get()?.unix_timestamp < pool.early_pool_close_time {
+            return err!(ErrCode::InvalidTime);
+        }
+    }
+    msg!("Closed tier table");
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{ Pool, TipVault };
+
+#[derive(Accounts)]
+pub struct CloseTipVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(has_one = owner)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev lamport vault paying crankers, unpaid tips go back to owner
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"tip-vault", pool.key().as_ref()],
+        bump = tip_vault.bump
+    )]
+    pub tip_vault: Box<Account<'info, TipVault>>,
+}
+
+// @dev allowed to stop crank tips and reclaim lamports by pool owner
+pub fn close_tip_vault_handler(_ctx: Context<CloseTipVault>) -> Result<()> {
+    msg!("Closed tip vault");
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{ self, Mint, Token, TokenAccount, Transfer },
+};
+
+use crate::{
+    calculate_claimable_amount,
+    error::ErrCode,
+    Buyer,
+    ClaimsCranked,
+    Config,
+    CrankClaimSkipped,
+    Pool,
+    PoolStatus,
+    TipVault,
+};
+
+#[derive(Accounts)]
+pub struct CrankClaims<'info> {
+    // @dev anyone, receives tips if tip vault is given
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    // @dev mint address of ido token
+    #[account(
+        constraint = offer_mint.key() == pool.offered_currency.mint @ ErrCode::InvalidMint
+    )]
+    pub offer_mint: Box<Account<'info, Mint>>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev offer vault
+    #[account(
+        mut,
+        seeds = [b"offer-vault", pool.key().as_ref()],
+        bump,
+        token::mint = offer_mint
+    )]
+    pub offer_vault: Account<'info, TokenAccount>,
+
+    // @dev lamport vault paying crankers, optional
+    #[account(
+        mut,
+        seeds = [b"tip-vault", pool.key().as_ref()],
+        bump = tip_vault.bump
+    )]
+    pub tip_vault: Option<Box<Account<'info, TipVault>>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// @dev pushes vested ido token for (buyer, destination) pairs in remaining accounts,
+// destination follows claim destination of buyer
+// @dev missing or closed destination is skipped with CrankClaimSkipped, buyer claims it later
+// @dev cranked claims are not appended to claim history of buyers, ClaimsCranked records them
+pub fn crank_claims_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CrankClaims<'info>>
+) -> Result<()> {
+    // check if allowed to claim
+    ctx.accounts.pool.require_status(&[PoolStatus::Claiming, PoolStatus::Finalized])?;
+    let remaining_accounts: &[AccountInfo] = ctx.remaining_accounts;
+    let pairs = remaining_accounts.chunks_exact(2);
+    if remaining_accounts.is_empty() || !pairs.remainder().is_empty() {
+        return err!(ErrCode::InvalidClaimAccounts);
+    }
+    let now: i64 = Clock::get()?.unix_timestamp;
+    // pushing a claim would take price protection away from buyer
+    if ctx.accounts.pool.in_protection_window(now) {
+        return err!(ErrCode::ProtectionWindowOpen);
+    }
+    let pool_key: Pubkey = ctx.accounts.pool.key();
+    let offer_mint: Pubkey = ctx.accounts.offer_mint.key();
+    // seeds of authority pda of offer vault
+    let seeds: &[&[u8]; 3] = &[b"offer-vault", pool_key.as_ref(), &[ctx.bumps.offer_vault]];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+
+    let mut claims: u64 = 0;
+    let mut claimed_amount: u64 = 0;
+    for pair in pairs {
+        let mut buyer: Account<Buyer> = Account::try_from(&pair[0])?;
+        let (buyer_key, _) = Pubkey::find_program_address(
+            &[b"buyer", pool_key.as_ref(), buyer.wallet.as_ref()],
+            ctx.program_id
+        );
+        // destination must be associated token account of claim destination of buyer
+        let destination: Pubkey = get_associated_token_address(
+            &buyer.claim_destination(),
+            &offer_mint
+        );
+        if pair[0].key() != buyer_key || pair[1].key() != destination {
+            return err!(ErrCode::InvalidClaimAccounts);
+        }
+
+        // skip buyers with nothing vested since last claim
+        if buyer.cliamed_amount >= buyer.total_amount {
+            continue;
+        }
+        let pool: &Account<Pool> = &ctx.accounts.pool;
+        let amount: u64 = calculate_claimable_amount(
+            buyer.total_amount,
+            buyer.cliamed_amount,
+            pool.tge_percentage,
+            pool.tge_date,
+            pool.vesting_cliff,
+            pool.vesting_frequency as u64,
+            pool.number_of_vesting as u64,
+            now
+        );
+        if amount == 0 {
+            continue;
+        }
+        // one missing token account does not fail the batch of other buyers
+        if pair[1].owner != &token::ID || pair[1].data_is_empty() {
+            emit!(CrankClaimSkipped {
+                pool: pool_key,
+                wallet: buyer.wallet,
+                destination,
+                amount,
+            });
+            continue;
+        }
+
+        // transfer token to buyer token account
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.offer_vault.to_account_info(),
+                to: pair[1].clone(),
+                authority: ctx.accounts.offer_vault.to_account_info(),
+            },
+            signer
+        );
+        token::transfer(transfer_ctx, amount)?;
+        buyer.cliamed_amount += amount;
+        buyer.exit(ctx.program_id)?;
+        claims += 1;
+        claimed_amount += amount;
+    }
+    ctx.accounts.pool.fund_claimed_amount += claimed_amount;
+
+    // pay tips above rent reserve of tip vault
+    let mut tips: u64 = 0;
+    if let Some(tip_vault) = &ctx.accounts.tip_vault {
+        let tip_vault_info: AccountInfo = tip_vault.to_account_info();
+        let reserve: u64 = Rent::get()?.minimum_balance(tip_vault_info.data_len());
+        tips = tip_vault.tip_per_claim
+            .saturating_mul(claims)
+            .min(tip_vault_info.lamports().saturating_sub(reserve));
+        if tips > 0 {
+            **tip_vault_info.try_borrow_mut_lamports()? -= tips;
+            **ctx.accounts.cranker.to_account_info().try_borrow_mut_lamports()? += tips;
+        }
+    }
+
+    emit!(ClaimsCranked {
+        pool: pool_key,
+        cranker: ctx.accounts.cranker.key(),
+        claims,
+        amount: claimed_amount,
+        tips,
+    });
+    msg!("Cranked claims");
+    Ok(())
+}
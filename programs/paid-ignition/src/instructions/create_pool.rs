@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{ Mint, Token };
-use crate::{error::ErrCode, state::Pool, PERCENTAGE_DENOMINATOR};
+use crate::{
+    error::ErrCode,
+    state::{ MetadataArgs, Pool, PoolMetadata, PoolStatus, Role, RoleAssignment, UnsoldPolicy },
+    PoolMetadataUpdated,
+    PERCENTAGE_DENOMINATOR,
+};
 use std::mem::size_of;
 
 #[derive(Accounts)]
@@ -8,6 +13,13 @@ pub struct CreatePool<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    // pool operator role of creator
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::PoolOperator as u8], creator.key().as_ref()],
+        bump = creator_role.bump
+    )]
+    pub creator_role: Box<Account<'info, RoleAssignment>>,
+
     // mint address of purchase token
     pub purchase_mint: Box<Account<'info, Mint>>,
     // mint address of ido token
@@ -17,12 +29,28 @@ pub struct CreatePool<'info> {
     #[account(init, payer = creator, space = size_of::<Pool>() + 8)]
     pub pool: Box<Account<'info, Pool>>,
 
+    // @dev display metadata of pool
+    #[account(
+        init,
+        payer = creator,
+        space = PoolMetadata::space(),
+        seeds = [b"metadata", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_metadata: Box<Account<'info, PoolMetadata>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 // create launchpad
-pub fn create_pool_handler(ctx: Context<CreatePool>, uints: [u64; 18]) -> Result<()> {
+pub fn create_pool_handler(
+    ctx: Context<CreatePool>,
+    uints: [u64; 18],
+    unsold_policy: UnsoldPolicy,
+    unsold_recipient: Pubkey,
+    metadata: MetadataArgs
+) -> Result<()> {
     // validate inputs
     // token fee percentage
     if uints[2] > PERCENTAGE_DENOMINATOR {
@@ -45,6 +73,13 @@ pub fn create_pool_handler(ctx: Context<CreatePool>, uints: [u64; 18]) -> Result
     if uints[10] > uints[13] {
         return err!(ErrCode::InvalidTime);
     }
+    // recipient is required only when unsold token is sent to it
+    if (unsold_policy == UnsoldPolicy::SendToRecipient) == (unsold_recipient == Pubkey::default()) {
+        return err!(ErrCode::InvalidParam);
+    }
+    if !PoolMetadata::is_valid(&metadata) {
+        return err!(ErrCode::InvalidMetadata);
+    }
     // format pool info
     let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
     pool.max_purchase_amount_for_kyc_user = uints[0];
@@ -63,14 +98,32 @@ pub fn create_pool_handler(ctx: Context<CreatePool>, uints: [u64; 18]) -> Result
     pool.offered_currency.rate = uints[11];
     pool.offered_currency.decimals = uints[12] as u8;
     pool.tge_date = uints[13] as i64;
+    pool.original_tge_date = uints[13] as i64;
     pool.tge_percentage = uints[14] as u16;
     pool.vesting_cliff = uints[15] as i64;
     pool.vesting_frequency = uints[16] as i64;
     pool.number_of_vesting = uints[17] as i64;
     pool.owner = ctx.accounts.creator.key();
+    pool.rent_payer = ctx.accounts.creator.key();
     pool.total_funded_amount = 0;
+    pool.status = PoolStatus::Created;
     pool.offered_currency.mint = ctx.accounts.offer_mint.key();
     pool.purchase_currency.mint = ctx.accounts.purchase_mint.key();
+    pool.unsold_policy = unsold_policy;
+    pool.unsold_recipient = unsold_recipient;
+
+    let pool_metadata: &mut Box<Account<PoolMetadata>> = &mut ctx.accounts.pool_metadata;
+    pool_metadata.pool = pool.key();
+    pool_metadata.set(metadata);
+    pool_metadata.bump = ctx.bumps.pool_metadata;
+    emit!(PoolMetadataUpdated {
+        pool: pool_metadata.pool,
+        name: pool_metadata.name.clone(),
+        symbol: pool_metadata.symbol.clone(),
+        uri: pool_metadata.uri.clone(),
+        website: pool_metadata.website.clone(),
+        content_hash: pool_metadata.content_hash,
+    });
     msg!("Pool created");
     Ok(())
 }
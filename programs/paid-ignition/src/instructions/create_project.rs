@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{ Project, Role, RoleAssignment };
+
+#[derive(Accounts)]
+pub struct CreateProject<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    // pool operator role of creator
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::PoolOperator as u8], creator.key().as_ref()],
+        bump = creator_role.bump
+    )]
+    pub creator_role: Box<Account<'info, RoleAssignment>>,
+
+    // mint address of purchase token
+    pub purchase_mint: Box<Account<'info, Mint>>,
+    // mint address of ido token
+    pub offer_mint: Box<Account<'info, Mint>>,
+
+    // @dev project account, rounds are added afterwards
+    #[account(init, payer = creator, space = Project::space())]
+    pub project: Box<Account<'info, Project>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// @dev allowed to create a multi round project by pool operator
+// @dev projects deliberately bypass pool safeguards: no status machine, tge limits, milestone
+// @dev escrow or price protection, raise of a round is withdrawable once it closes
+pub fn create_project_handler(ctx: Context<CreateProject>) -> Result<()> {
+    let project: &mut Box<Account<Project>> = &mut ctx.accounts.project;
+    project.owner = ctx.accounts.creator.key();
+    project.rent_payer = ctx.accounts.creator.key();
+    project.purchase_mint = ctx.accounts.purchase_mint.key();
+    project.offer_mint = ctx.accounts.offer_mint.key();
+    msg!("Project created");
+    Ok(())
+}
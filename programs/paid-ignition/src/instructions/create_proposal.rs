@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+use crate::{
+    error::ErrCode,
+    Buyer,
+    Pool,
+    PoolStatus,
+    Proposal,
+    ProposalCreated,
+    ProposalKind,
+    ProposalStatus,
+    Role,
+    RoleAssignment,
+    MIN_PROPOSAL_QUORUM,
+    MIN_REFUND_QUORUM,
+    MIN_VOTING_PERIOD,
+    PERCENTAGE_DENOMINATOR,
+};
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    // @dev super admin role of signer, none if signer is pool owner
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::SuperAdmin as u8], proposer.key().as_ref()],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Option<Box<Account<'info, RoleAssignment>>>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev buyer account of signer, only needed by buyer asking for refund
+    #[account(
+        seeds = [b"buyer", pool.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub buyer: Option<Box<Account<'info, Buyer>>>,
+
+    // @dev new proposal at next index of pool
+    #[account(
+        init,
+        payer = proposer,
+        space = size_of::<Proposal>() + 8,
+        seeds = [b"proposal", pool.key().as_ref(), pool.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// @dev allowed to open a proposal by pool owner or super admin once sale closed
+// @dev buyers may open refund proposals too, those need a majority quorum
+pub fn create_proposal_handler(
+    ctx: Context<CreateProposal>,
+    kind: ProposalKind,
+    deadline: i64,
+    quorum: u16
+) -> Result<()> {
+    let proposer: Pubkey = ctx.accounts.proposer.key();
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    let refund: bool = kind == ProposalKind::RefundMilestones;
+    let buyer_allowed: bool =
+        refund && matches!(&ctx.accounts.buyer, Some(buyer) if buyer.total_purchase > 0);
+    if proposer != pool.owner && ctx.accounts.admin_role.is_none() && !buyer_allowed {
+        return err!(ErrCode::NotProposer);
+    }
+    let now: i64 = Clock::get()?.unix_timestamp;
+    pool.refresh_status(now)?;
+    // vote weights are final once sale closed
+    pool.require_status(&[PoolStatus::Succeeded, PoolStatus::Claiming, PoolStatus::Finalized])?;
+    // buyers get at least the minimum voting period, proposer cannot pick a token quorum
+    if deadline < now + MIN_VOTING_PERIOD {
+        return err!(ErrCode::InvalidTime);
+    }
+    let min_quorum: u16 = if refund { MIN_REFUND_QUORUM } else { MIN_PROPOSAL_QUORUM };
+    if quorum < min_quorum || (quorum as u64) > PERCENTAGE_DENOMINATOR {
+        return err!(ErrCode::InvalidParam);
+    }
+    let index: u32 = pool.proposal_count;
+    pool.proposal_count += 1;
+    let proposal: &mut Box<Account<Proposal>> = &mut ctx.accounts.proposal;
+    proposal.pool = pool.key();
+    proposal.index = index;
+    proposal.kind = kind;
+    proposal.proposer = proposer;
+    proposal.deadline = deadline;
+    proposal.quorum = quorum;
+    proposal.status = ProposalStatus::Active;
+    proposal.bump = ctx.bumps.proposal;
+    emit!(ProposalCreated {
+        pool: pool.key(),
+        proposal: proposal.key(),
+        index,
+        kind,
+        proposer,
+        deadline,
+        quorum,
+    });
+    msg!("Created proposal");
+    Ok(())
+}
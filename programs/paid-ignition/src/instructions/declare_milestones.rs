@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Milestone, MilestonePlan, MilestonesDeclared, Pool, PoolStatus };
+
+#[derive(Accounts)]
+pub struct DeclareMilestones<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(mut, has_one = owner)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev milestone plan of pool, replaced while sale is not open
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = MilestonePlan::space(),
+        seeds = [b"milestones", pool.key().as_ref()],
+        bump
+    )]
+    pub milestone_plan: Box<Account<'info, MilestonePlan>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// @dev allowed to declare release schedule of raise by pool owner before sale opens
+// @dev buyers see the schedule before purchasing, so it is fixed afterwards
+pub fn declare_milestones_handler(
+    ctx: Context<DeclareMilestones>,
+    milestones: Vec<Milestone>
+) -> Result<()> {
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    pool.require_status(&[PoolStatus::Created, PoolStatus::Funded])?;
+    if
+        !MilestonePlan::is_valid(&milestones) ||
+        milestones[0].release_time < pool.open_pool_close_time
+    {
+        return err!(ErrCode::InvalidMilestones);
+    }
+    let pool_key: Pubkey = pool.key();
+    let plan: &mut Box<Account<MilestonePlan>> = &mut ctx.accounts.milestone_plan;
+    plan.pool = pool_key;
+    plan.milestones = milestones;
+    if plan.rent_payer == Pubkey::default() {
+        plan.rent_payer = ctx.accounts.owner.key();
+    }
+    plan.bump = ctx.bumps.milestone_plan;
+    emit!(MilestonesDeclared {
+        pool: pool_key,
+        milestones: plan.milestones.clone(),
+    });
+    msg!("Declared milestones");
+    Ok(())
+}
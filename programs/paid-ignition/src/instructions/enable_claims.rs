@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{ Pool, PoolStatus, Role, RoleAssignment };
+
+#[derive(Accounts)]
+pub struct EnableClaims<'info> {
+    pub authority: Signer<'info>,
+
+    // @dev pool operator role of signer, none if signer is pool owner
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::PoolOperator as u8], authority.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Box<Account<'info, RoleAssignment>>>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev it allows buyers to claim by pool owner or pool operator after success
+pub fn enable_claims_handler(ctx: Context<EnableClaims>) -> Result<()> {
+    ctx.accounts.pool.require_authority(
+        ctx.accounts.authority.key,
+        ctx.accounts.operator_role.is_some()
+    )?;
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    pool.transition(PoolStatus::Claiming)?;
+    msg!("Enabled claims");
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, vesting_end_time, Pool, PoolStatus, Role, RoleAssignment };
+
+#[derive(Accounts)]
+pub struct FinalizePool<'info> {
+    pub authority: Signer<'info>,
+
+    // @dev pool operator role of signer, none if signer is pool owner
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::PoolOperator as u8], authority.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Box<Account<'info, RoleAssignment>>>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev it allows to finalize pool by pool owner or pool operator
+// @dev once vesting ended or all refunds are done
+pub fn finalize_pool_handler(ctx: Context<FinalizePool>) -> Result<()> {
+    ctx.accounts.pool.require_authority(
+        ctx.accounts.authority.key,
+        ctx.accounts.operator_role.is_some()
+    )?;
+    let now: i64 = Clock::get()?.unix_timestamp;
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(now)?;
+    let end_time: i64 = vesting_end_time(
+        pool.tge_date,
+        pool.vesting_cliff,
+        pool.vesting_frequency,
+        pool.number_of_vesting
+    );
+    match pool.status {
+        PoolStatus::Claiming if now < end_time => {
+            return err!(ErrCode::InvalidTime);
+        }
+        PoolStatus::Failed | PoolStatus::Cancelled if
+            pool.purchased_amount != 0 || pool.total_funded_amount != 0
+        => {
+            return err!(ErrCode::NotSettled);
+        }
+        _ => {}
+    }
+    pool.transition(PoolStatus::Finalized)?;
+    msg!("Finalized pool");
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Pool, Proposal, ProposalFinalized, ProposalStatus };
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    // @dev pool account
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev proposal of pool
+    #[account(mut, has_one = pool)]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+// @dev allowed to record outcome of proposal by anyone after deadline
+pub fn finalize_proposal_handler(ctx: Context<FinalizeProposal>) -> Result<()> {
+    let proposal: &mut Box<Account<Proposal>> = &mut ctx.accounts.proposal;
+    if proposal.status != ProposalStatus::Active {
+        return err!(ErrCode::ProposalNotActive);
+    }
+    if Clock::get()?.unix_timestamp < proposal.deadline {
+        return err!(ErrCode::VotingNotEnded);
+    }
+    proposal.status = proposal.outcome(ctx.accounts.pool.outstanding_purchase_amount());
+    emit!(ProposalFinalized {
+        pool: proposal.pool,
+        proposal: proposal.key(),
+        status: proposal.status,
+        votes_for: proposal.votes_for,
+        votes_against: proposal.votes_against,
+    });
+    msg!("Finalized proposal");
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Burn, Mint, Token, TokenAccount, Transfer };
+
+use crate::{ error::ErrCode, Config, Pool, PoolStatus, UnsoldFinalized, UnsoldPolicy };
+
+#[derive(Accounts)]
+pub struct FinalizeUnsold<'info> {
+    // @dev mint address of ido token
+    #[account(
+        mut,
+        constraint = offer_mint.key() == pool.offered_currency.mint @ ErrCode::InvalidMint
+    )]
+    pub offer_mint: Box<Account<'info, Mint>>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev offer vault
+    #[account(
+        mut,
+        seeds = [b"offer-vault", pool.key().as_ref()],
+        bump,
+        token::mint = offer_mint
+    )]
+    pub offer_vault: Account<'info, TokenAccount>,
+
+    // @dev ido token account of unsold destination, none if burned
+    #[account(
+        mut,
+        token::mint = offer_mint,
+        constraint = destination.owner == pool.unsold_destination() @ ErrCode::InvalidUnsoldDestination
+    )]
+    pub destination: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// @dev anyone is allowed to carry out unsold policy of pool after a successful close, again for allocation returned later
+pub fn finalize_unsold_handler(ctx: Context<FinalizeUnsold>) -> Result<()> {
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    pool.require_status(&[PoolStatus::Succeeded, PoolStatus::Claiming, PoolStatus::Finalized])?;
+    // unsold share of funding only, never what buyers and liquidity are owed
+    let amount: u64 = pool.pending_unsold_offer_amount();
+    if amount == 0 {
+        return err!(ErrCode::NoUnsoldAmount);
+    }
+    pool.total_funded_amount -= amount;
+    pool.unsold_finalized_amount += amount;
+    let policy: UnsoldPolicy = pool.unsold_policy;
+    let pool_key: Pubkey = pool.key();
+
+    // seeds of authority pda of offer vault
+    let seeds: &[&[u8]; 3] = &[b"offer-vault", pool_key.as_ref(), &[ctx.bumps.offer_vault]];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    let destination: Pubkey = match (policy, &ctx.accounts.destination) {
+        (UnsoldPolicy::Burn, None) => {
+            let burn_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.offer_mint.to_account_info(),
+                    from: ctx.accounts.offer_vault.to_account_info(),
+                    authority: ctx.accounts.offer_vault.to_account_info(),
+                },
+                signer
+            );
+            token::burn(burn_ctx, amount)?;
+            Pubkey::default()
+        }
+        (UnsoldPolicy::ReturnToCreator | UnsoldPolicy::SendToRecipient, Some(destination)) => {
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.offer_vault.to_account_info(),
+                    to: destination.to_account_info(),
+                    authority: ctx.accounts.offer_vault.to_account_info(),
+                },
+                signer
+            );
+            token::transfer(transfer_ctx, amount)?;
+            destination.key()
+        }
+        _ => {
+            return err!(ErrCode::InvalidUnsoldDestination);
+        }
+    };
+
+    emit!(UnsoldFinalized {
+        pool: pool_key,
+        policy,
+        destination,
+        amount,
+    });
+    msg!("Finalized unsold ido token");
+    Ok(())
+}
@@ -4,14 +4,24 @@ use anchor_spl::{
     token::{ self, Mint, Token, TokenAccount, Transfer },
 };
 
-use crate::Pool;
+use crate::{ error::ErrCode, required_offer_amount, Config, Pool, PoolStatus, Role, RoleAssignment };
 
 #[derive(Accounts)]
 pub struct FundOffer<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    // funder role of signer
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::Funder as u8], owner.key().as_ref()],
+        bump = funder_role.bump
+    )]
+    pub funder_role: Box<Account<'info, RoleAssignment>>,
+
     // mint address of ido token
+    #[account(
+        constraint = offer_mint.key() == pool.offered_currency.mint @ ErrCode::InvalidMint
+    )]
     pub offer_mint: Box<Account<'info, Mint>>,
 
     // ido token account of owner
@@ -21,8 +31,20 @@ pub struct FundOffer<'info> {
     )]
     pub owner_token: Account<'info, TokenAccount>,
 
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
     // pool account
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
     pub pool: Box<Account<'info, Pool>>,
 
     // offer vault
@@ -52,16 +74,35 @@ impl<'info> FundOffer<'info> {
         })
     }
 }
-// @dev allowed to deposit ido token by creator after creating pool
-pub fn fund_offer_handler(ctx: Context<FundOffer>, amount: u64, bump: u8) -> Result<()> {
+// @dev allowed to deposit ido token by owner after creating pool, until early pool opens
+pub fn fund_offer_handler(ctx: Context<FundOffer>, amount: u64) -> Result<()> {
+    // validate status
+    let now: i64 = Clock::get()?.unix_timestamp;
+    ctx.accounts.pool.refresh_status(now)?;
+    ctx.accounts.pool.require_status(&[PoolStatus::Created, PoolStatus::Funded])?;
+    // validate amount
+    if amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
     // transfer token
     token::transfer(ctx.accounts.transfer_ctx(), amount)?;
     // update info
     let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
-    pool.offered_currency.mint = ctx.accounts.offer_mint.key();
-    pool.funded = true;
-    pool.offered_bump = bump;
+    pool.offered_bump = ctx.bumps.offer_vault;
+    if pool.offer_vault_payer == Pubkey::default() {
+        pool.offer_vault_payer = ctx.accounts.owner.key();
+    }
     pool.total_funded_amount += amount;
+    // funded once offer covers the whole raise plus token fee and liquidity share
+    let required_amount: u64 =
+        required_offer_amount(
+            pool.total_raise_amount,
+            pool.offered_currency.rate,
+            pool.token_fee_percentage
+        ) + pool.liquidity_offer_reserve();
+    if pool.status == PoolStatus::Created && pool.total_funded_amount >= required_amount {
+        pool.transition(PoolStatus::Funded)?;
+    }
     msg!("Funded IDO");
     Ok(())
 }
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Mint, Token, TokenAccount, Transfer };
+
+use crate::{ error::ErrCode, Config, Project, Role, RoleAssignment };
+
+#[derive(Accounts)]
+pub struct FundProject<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // funder role of signer
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::Funder as u8], owner.key().as_ref()],
+        bump = funder_role.bump
+    )]
+    pub funder_role: Box<Account<'info, RoleAssignment>>,
+
+    // mint address of ido token
+    #[account(
+        constraint = offer_mint.key() == project.offer_mint @ ErrCode::InvalidMint
+    )]
+    pub offer_mint: Box<Account<'info, Mint>>,
+
+    // ido token account of owner
+    #[account(
+        mut,
+        token::mint = offer_mint,
+    )]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev project account
+    #[account(mut, has_one = owner)]
+    pub project: Box<Account<'info, Project>>,
+
+    // @dev offer vault shared by all rounds
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [b"project-offer-vault", project.key().as_ref()],
+        bump,
+        owner = token_program.key(),
+        rent_exempt = enforce,
+        token::mint = offer_mint,
+        token::authority = offer_vault
+    )]
+    pub offer_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundProject<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), Transfer {
+            from: self.owner_token.to_account_info(),
+            to: self.offer_vault.to_account_info(),
+            authority: self.owner.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to deposit ido token for any round by project owner until the last round closes
+pub fn fund_project_handler(ctx: Context<FundProject>, amount: u64) -> Result<()> {
+    if ctx.accounts.project.is_closed(Clock::get()?.unix_timestamp) {
+        return err!(ErrCode::InvalidTime);
+    }
+    if amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+    token::transfer(ctx.accounts.transfer_ctx(), amount)?;
+    let project: &mut Box<Account<Project>> = &mut ctx.accounts.project;
+    project.offer_bump = ctx.bumps.offer_vault;
+    if project.offer_vault_payer == Pubkey::default() {
+        project.offer_vault_payer = ctx.accounts.owner.key();
+    }
+    project.total_funded_amount += amount;
+    msg!("Funded project");
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::{ prelude::*, system_program::{ self, Transfer } };
+use std::mem::size_of;
+
+use crate::{ Pool, TipVault };
+
+#[derive(Accounts)]
+pub struct FundTipVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(has_one = owner)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev lamport vault paying crankers
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = size_of::<TipVault>() + 8,
+        seeds = [b"tip-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub tip_vault: Box<Account<'info, TipVault>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundTipVault<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(self.system_program.to_account_info(), Transfer {
+            from: self.owner.to_account_info(),
+            to: self.tip_vault.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to deposit crank tips and set tip per claim by pool owner
+pub fn fund_tip_vault_handler(
+    ctx: Context<FundTipVault>,
+    tip_per_claim: u64,
+    amount: u64
+) -> Result<()> {
+    if amount > 0 {
+        system_program::transfer(ctx.accounts.transfer_ctx(), amount)?;
+    }
+    let tip_vault: &mut Box<Account<TipVault>> = &mut ctx.accounts.tip_vault;
+    tip_vault.pool = ctx.accounts.pool.key();
+    tip_vault.tip_per_claim = tip_per_claim;
+    tip_vault.bump = ctx.bumps.tip_vault;
+    msg!("Funded tip vault");
+    Ok(())
+}
@@ -7,6 +7,70 @@ pub mod update_time;
 pub mod claim_offer;
 pub mod user_withdraw_purchase;
 pub mod withdraw_offer;
+pub mod init_config;
+pub mod set_guardian;
+pub mod set_amm_programs;
+pub mod set_paused;
+pub mod set_pool_paused;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod update_fees;
+pub mod propose_owner;
+pub mod accept_owner;
+pub mod cancel_owner_transfer;
+pub mod cancel_purchase;
+pub mod close_buyer;
+pub mod close_claim_history;
+pub mod close_purchase_vault;
+pub mod close_offer_vault;
+pub mod sweep_vaults;
+pub mod close_pool;
+pub mod enable_claims;
+pub mod cancel_pool;
+pub mod finalize_pool;
+pub mod refresh_pool_status;
+pub mod quote_purchase;
+pub mod quote_claimable;
+pub mod crank_claims;
+pub mod fund_tip_vault;
+pub mod close_tip_vault;
+pub mod set_claim_settings;
+pub mod finalize_unsold;
+pub mod set_liquidity_config;
+pub mod seed_liquidity;
+pub mod withdraw_locked_lp;
+pub mod declare_milestones;
+pub mod release_milestone;
+pub mod approve_refund;
+pub mod claim_milestone_refund;
+pub mod close_milestone_plan;
+pub mod create_proposal;
+pub mod cast_vote;
+pub mod finalize_proposal;
+pub mod close_proposal;
+pub mod close_proposal_vote;
+pub mod set_tge_governance;
+pub mod set_protection_config;
+pub mod claim_price_protection;
+pub mod reclaim_protection_reserve;
+pub mod set_tier_table;
+pub mod register_tier;
+pub mod close_tier_registration;
+pub mod close_tier_table;
+pub mod update_pool_metadata;
+pub mod create_project;
+pub mod add_round;
+pub mod fund_project;
+pub mod buy_in_round;
+pub mod claim_round_offer;
+pub mod withdraw_round_raise;
+pub mod withdraw_project_offer;
+pub mod close_round_buyer;
+pub mod close_project_purchase_vault;
+pub mod close_project_offer_vault;
+pub mod close_project;
+pub mod propose_project_owner;
+pub mod accept_project_owner;
 
 pub use create_pool::*;
 pub use fund_offer::*;
@@ -16,4 +80,68 @@ pub use update_tge_date::*;
 pub use update_time::*;
 pub use claim_offer::*;
 pub use user_withdraw_purchase::*;
-pub use withdraw_offer::*;
\ No newline at end of file
+pub use withdraw_offer::*;
+pub use init_config::*;
+pub use set_guardian::*;
+pub use set_amm_programs::*;
+pub use set_paused::*;
+pub use set_pool_paused::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use update_fees::*;
+pub use propose_owner::*;
+pub use accept_owner::*;
+pub use cancel_owner_transfer::*;
+pub use cancel_purchase::*;
+pub use close_buyer::*;
+pub use close_claim_history::*;
+pub use close_purchase_vault::*;
+pub use close_offer_vault::*;
+pub use sweep_vaults::*;
+pub use close_pool::*;
+pub use enable_claims::*;
+pub use cancel_pool::*;
+pub use finalize_pool::*;
+pub use refresh_pool_status::*;
+pub use quote_purchase::*;
+pub use quote_claimable::*;
+pub use crank_claims::*;
+pub use fund_tip_vault::*;
+pub use close_tip_vault::*;
+pub use set_claim_settings::*;
+pub use finalize_unsold::*;
+pub use set_liquidity_config::*;
+pub use seed_liquidity::*;
+pub use withdraw_locked_lp::*;
+pub use declare_milestones::*;
+pub use release_milestone::*;
+pub use approve_refund::*;
+pub use claim_milestone_refund::*;
+pub use close_milestone_plan::*;
+pub use create_proposal::*;
+pub use cast_vote::*;
+pub use finalize_proposal::*;
+pub use close_proposal::*;
+pub use close_proposal_vote::*;
+pub use set_tge_governance::*;
+pub use set_protection_config::*;
+pub use claim_price_protection::*;
+pub use reclaim_protection_reserve::*;
+pub use set_tier_table::*;
+pub use register_tier::*;
+pub use close_tier_registration::*;
+pub use close_tier_table::*;
+pub use update_pool_metadata::*;
+pub use create_project::*;
+pub use add_round::*;
+pub use fund_project::*;
+pub use buy_in_round::*;
+pub use claim_round_offer::*;
+pub use withdraw_round_raise::*;
+pub use withdraw_project_offer::*;
+pub use close_round_buyer::*;
+pub use close_project_purchase_vault::*;
+pub use close_project_offer_vault::*;
+pub use close_project::*;
+pub use propose_project_owner::*;
+pub use accept_project_owner::*;
\ No newline at end of file
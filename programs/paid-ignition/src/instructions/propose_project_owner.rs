@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Project, ProjectOwnerProposed };
+
+#[derive(Accounts)]
+pub struct ProposeProjectOwner<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev project account
+    #[account(mut, has_one = owner)]
+    pub project: Box<Account<'info, Project>>,
+}
+
+// @dev it allows to nominate a new project owner by current owner, default withdraws nomination
+pub fn propose_project_owner_handler(
+    ctx: Context<ProposeProjectOwner>,
+    pending_owner: Pubkey
+) -> Result<()> {
+    if pending_owner == ctx.accounts.project.owner {
+        return err!(ErrCode::InvalidParam);
+    }
+    let project: &mut Box<Account<Project>> = &mut ctx.accounts.project;
+    project.pending_owner = pending_owner;
+    emit!(ProjectOwnerProposed {
+        project: project.key(),
+        owner: project.owner,
+        pending_owner,
+    });
+    msg!("Proposed project owner");
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{ calculate_claimable_amount, Buyer, Pool, PoolStatus };
+
+#[derive(Accounts)]
+pub struct QuoteClaimable<'info> {
+    /// CHECK: wallet of buyer, only used as seed of buyer account
+    pub wallet: UncheckedAccount<'info>,
+
+    // @dev pool account
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev buyer account
+    #[account(
+        seeds = [b"buyer", pool.key().as_ref(), wallet.key().as_ref()],
+        bump
+    )]
+    pub buyer: Box<Account<'info, Buyer>>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimQuote {
+    // @dev pool status at quote time
+    pub status: PoolStatus,
+    // @dev locked total amount of ido token
+    pub total_amount: u64,
+    // @dev claimed amount of ido token
+    pub claimed_amount: u64,
+    // @dev ido token claimable now, zero until claims are enabled
+    pub claimable_amount: u64,
+}
+
+// @dev view only, returns what claim_offer would unlock now
+pub fn quote_claimable_handler(ctx: Context<QuoteClaimable>) -> Result<ClaimQuote> {
+    let pool: &Account<Pool> = &ctx.accounts.pool;
+    let buyer: &Account<Buyer> = &ctx.accounts.buyer;
+    let claimable: bool =
+        matches!(pool.status, PoolStatus::Claiming | PoolStatus::Finalized) &&
+        buyer.cliamed_amount < buyer.total_amount;
+    let claimable_amount: u64 = if claimable {
+        calculate_claimable_amount(
+            buyer.total_amount,
+            buyer.cliamed_amount,
+            pool.tge_percentage,
+            pool.tge_date,
+            pool.vesting_cliff,
+            pool.vesting_frequency as u64,
+            pool.number_of_vesting as u64,
+            Clock::get()?.unix_timestamp
+        )
+    } else {
+        0
+    };
+    Ok(ClaimQuote {
+        status: pool.status,
+        total_amount: buyer.total_amount,
+        claimed_amount: buyer.cliamed_amount,
+        claimable_amount,
+    })
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    calculate_participiant_fee,
+    fill_purchase_amount,
+    Buyer,
+    Pool,
+    PoolStatus,
+    TierRegistration,
+    TierTable,
+};
+
+#[derive(Accounts)]
+pub struct QuotePurchase<'info> {
+    // @dev pool account
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev buyer account of quoted wallet, omitted if wallet has not bought yet
+    #[account(seeds = [b"buyer", pool.key().as_ref(), buyer.wallet.as_ref()], bump)]
+    pub buyer: Option<Box<Account<'info, Buyer>>>,
+
+    // @dev tier table of pool, required with tier registration
+    #[account(seeds = [b"tiers", pool.key().as_ref()], bump = tier_table.bump)]
+    pub tier_table: Option<Box<Account<'info, TierTable>>>,
+
+    // @dev tier registration of quoted wallet, omitted if pool is not tiered
+    #[account(
+        seeds = [b"tier-registration", pool.key().as_ref(), tier_registration.wallet.as_ref()],
+        bump,
+        constraint = buyer.iter().all(|buyer| buyer.wallet == tier_registration.wallet)
+    )]
+    pub tier_registration: Option<Box<Account<'info, TierRegistration>>>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct PurchaseQuote {
+    // @dev pool status at quote time
+    pub status: PoolStatus,
+    // @dev filled purchase amount, based on purchase token
+    pub purchase_amount: u64,
+    // @dev participant fee deducted from filled amount, buyer transfers the net amount only
+    pub participant_fee: u64,
+    // @dev locked amount of ido token
+    pub offered_amount: u64,
+    // @dev purchase amount pool still accepts before this purchase
+    pub remaining_amount: u64,
+    // @dev purchase amount quoted wallet may still buy, pool remaining amount if no wallet given
+    pub wallet_remaining_amount: u64,
+}
+
+// @dev view only, returns what buying purchase_amount would get now
+// @dev passing buyer or tier registration quotes for that wallet, capped at its tier allocation
+pub fn quote_purchase_handler(
+    ctx: Context<QuotePurchase>,
+    purchase_amount: u64
+) -> Result<PurchaseQuote> {
+    // status as the buy instructions would see it, not written back
+    let mut pool: Pool = Pool::clone(&ctx.accounts.pool);
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    let (participation_fee_percentage, remaining_amount) = match pool.status {
+        PoolStatus::EarlyOpen =>
+            (pool.early_pool_participation_fee_percentage, pool.remaining_early_amount()),
+        PoolStatus::OpenOpen =>
+            (pool.open_pool_participation_fee_percentage, pool.remaining_open_amount()),
+        _ => (0, 0),
+    };
+    // tiered early pool caps each wallet at its weighted share, as buy_in_early_pool does
+    let mut wallet_remaining_amount: u64 = remaining_amount;
+    let quotes_wallet: bool =
+        ctx.accounts.buyer.is_some() || ctx.accounts.tier_registration.is_some();
+    if pool.status == PoolStatus::EarlyOpen && pool.tiered && quotes_wallet {
+        let early_purchased: u64 = ctx.accounts.buyer
+            .as_ref()
+            .map(|buyer| buyer.early_purchased)
+            .unwrap_or(0);
+        let allocation: u64 = match (&ctx.accounts.tier_table, &ctx.accounts.tier_registration) {
+            (Some(tier_table), Some(registration)) =>
+                tier_table.allocation(pool.early_pool_total(), registration.weight),
+            _ => 0,
+        };
+        wallet_remaining_amount = remaining_amount.min(allocation.saturating_sub(early_purchased));
+    }
+    let purchase_amount: u64 = fill_purchase_amount(
+        purchase_amount,
+        participation_fee_percentage,
+        wallet_remaining_amount
+    );
+    let participant_fee: u64 = calculate_participiant_fee(
+        purchase_amount,
+        participation_fee_percentage
+    );
+    Ok(PurchaseQuote {
+        status: pool.status,
+        purchase_amount,
+        participant_fee,
+        offered_amount: (purchase_amount - participant_fee) * pool.offered_currency.rate,
+        remaining_amount,
+        wallet_remaining_amount,
+    })
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Token, TokenAccount, Transfer };
+
+use crate::{ error::ErrCode, Pool, PoolStatus, ProtectionReserveReclaimed };
+
+#[derive(Accounts)]
+pub struct ReclaimProtectionReserve<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev purchase vault, holds protection reserve
+    #[account(
+        mut,
+        seeds = [b"purchase-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub purchase_vault: Box<Account<'info, TokenAccount>>,
+
+    // @dev purchase token account of pool owner
+    #[account(
+        mut,
+        token::mint = pool.purchase_currency.mint,
+        token::authority = owner
+    )]
+    pub owner_purchase_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// @dev allowed to withdraw unused protection reserve by pool owner once window ended
+// @dev only after a successful close, failed and cancelled pools refund buyers in full
+pub fn reclaim_protection_reserve_handler(ctx: Context<ReclaimProtectionReserve>) -> Result<()> {
+    let now: i64 = Clock::get()?.unix_timestamp;
+    ctx.accounts.pool.refresh_status(now)?;
+    ctx.accounts.pool.require_status(
+        &[PoolStatus::Succeeded, PoolStatus::Claiming, PoolStatus::Finalized]
+    )?;
+    let pool: &Account<Pool> = &ctx.accounts.pool;
+    if pool.protection_window == 0 {
+        return err!(ErrCode::ProtectionNotConfigured);
+    }
+    if now < pool.protection_end_time() {
+        return err!(ErrCode::ProtectionWindowOpen);
+    }
+    if pool.protection_reserve_reclaimed {
+        return err!(ErrCode::ProtectionReserveReclaimed);
+    }
+    let amount: u64 = pool.protection_reserve() - pool.protected_amount;
+    // seed of authority pda of purchase vault
+    let pool_key: Pubkey = pool.key();
+    let seeds: &[&[u8]; 3] = &[b"purchase-vault", pool_key.as_ref(), &[ctx.bumps.purchase_vault]];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.purchase_vault.to_account_info(),
+            to: ctx.accounts.owner_purchase_token.to_account_info(),
+            authority: ctx.accounts.purchase_vault.to_account_info(),
+        },
+        signer
+    );
+    token::transfer(transfer_ctx, amount)?;
+    ctx.accounts.pool.protection_reserve_reclaimed = true;
+    ctx.accounts.pool.released_purchase_amount += amount;
+    emit!(ProtectionReserveReclaimed {
+        pool: pool_key,
+        amount,
+    });
+    msg!("Reclaimed protection reserve");
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::Pool;
+
+#[derive(Accounts)]
+pub struct RefreshPoolStatus<'info> {
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev allowed to persist time driven status by anyone
+pub fn refresh_pool_status_handler(ctx: Context<RefreshPoolStatus>) -> Result<()> {
+    ctx.accounts.pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    msg!("Refreshed pool status");
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use paid_stake::states::{ StakeCheckpoints, Staker };
+use std::mem::size_of;
+
+use crate::{ error::ErrCode, Pool, PoolStatus, TierRegistered, TierRegistration, TierTable };
+
+#[derive(Accounts)]
+pub struct RegisterTier<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev tier table of pool
+    #[account(
+        mut,
+        seeds = [b"tiers", pool.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Box<Account<'info, TierTable>>,
+
+    // @dev paidStake staker account of signer in tier stake pool
+    #[account(
+        seeds = [tier_table.stake_pool.as_ref(), signer.key().as_ref()],
+        bump,
+        seeds::program = paid_stake::ID
+    )]
+    pub staker: Box<Account<'info, Staker>>,
+
+    // @dev paidStake balance history of signer, none if stake is untouched since checkpoints exist
+    #[account(
+        seeds = [b"checkpoints", tier_table.stake_pool.as_ref(), signer.key().as_ref()],
+        bump,
+        seeds::program = paid_stake::ID
+    )]
+    pub checkpoints: Option<Box<Account<'info, StakeCheckpoints>>>,
+
+    // @dev registration of signer, fails to init if already registered
+    #[account(
+        init,
+        payer = signer,
+        space = size_of::<TierRegistration>() + 8,
+        seeds = [b"tier-registration", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub tier_registration: Box<Account<'info, TierRegistration>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// @dev allowed to lock in stake tier by user before early pool opens
+// @dev stake is read as of snapshot time of tier table from paidStake checkpoints
+pub fn register_tier_handler(ctx: Context<RegisterTier>) -> Result<()> {
+    let now: i64 = Clock::get()?.unix_timestamp;
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(now)?;
+    pool.require_status(&[PoolStatus::Created, PoolStatus::Funded])?;
+    if !pool.tiered {
+        return err!(ErrCode::InvalidTiers);
+    }
+    let table: &mut Box<Account<TierTable>> = &mut ctx.accounts.tier_table;
+    // stake as of snapshot can not be flash staked right before registration
+    if now < table.snapshot_time {
+        return err!(ErrCode::InvalidTime);
+    }
+    let staked_amount: u64 = match &ctx.accounts.checkpoints {
+        Some(checkpoints) if table.continuous_stake =>
+            checkpoints.min_balance_since(table.snapshot_time),
+        Some(checkpoints) => checkpoints.balance_at(table.snapshot_time),
+        // stake untouched since before checkpoints existed
+        None if ctx.accounts.staker.last_update <= table.snapshot_time =>
+            ctx.accounts.staker.total_staked,
+        None => 0,
+    };
+    let tier: usize = match table.tier_of(staked_amount) {
+        Some(tier) => tier,
+        None => {
+            return err!(ErrCode::NotEnoughStaker);
+        }
+    };
+    let weight: u16 = table.tiers[tier].weight;
+    table.total_registered_weight += weight as u64;
+    table.registered_count += 1;
+    let registration: &mut Box<Account<TierRegistration>> = &mut ctx.accounts.tier_registration;
+    registration.pool = pool.key();
+    registration.wallet = ctx.accounts.signer.key();
+    registration.tier = tier as u8;
+    registration.weight = weight;
+    registration.staked_amount = staked_amount;
+    emit!(TierRegistered {
+        pool: pool.key(),
+        wallet: registration.wallet,
+        tier: registration.tier,
+        weight,
+        staked_amount,
+        total_registered_weight: table.total_registered_weight,
+    });
+    msg!("Registered tier");
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Token, TokenAccount, Transfer };
+
+use crate::{ error::ErrCode, MilestonePlan, MilestoneReleased, Pool, PoolStatus };
+
+#[derive(Accounts)]
+pub struct ReleaseMilestone<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev milestone plan of pool, single tranche at tge if never declared
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = MilestonePlan::space(),
+        seeds = [b"milestones", pool.key().as_ref()],
+        bump
+    )]
+    pub milestone_plan: Box<Account<'info, MilestonePlan>>,
+
+    // @dev purchase vault, source of released raise
+    #[account(
+        mut,
+        seeds = [b"purchase-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub purchase_vault: Box<Account<'info, TokenAccount>>,
+
+    // @dev purchase token account of pool owner
+    #[account(
+        mut,
+        token::mint = pool.purchase_currency.mint,
+        token::authority = owner
+    )]
+    pub owner_purchase_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// @dev allowed to release next milestone tranche by pool owner once its time passed
+// @dev blocked for good once buyers voted for refund
+// @dev pool without declared milestones releases the whole raise at tge
+pub fn release_milestone_handler(ctx: Context<ReleaseMilestone>) -> Result<()> {
+    let now: i64 = Clock::get()?.unix_timestamp;
+    ctx.accounts.pool.refresh_status(now)?;
+    ctx.accounts.pool.require_status(
+        &[PoolStatus::Succeeded, PoolStatus::Claiming, PoolStatus::Finalized]
+    )?;
+    // raise of a pool without declared milestones is never locked for good
+    if ctx.accounts.milestone_plan.milestones.is_empty() {
+        let (pool_key, tge_date) = (ctx.accounts.pool.key(), ctx.accounts.pool.tge_date);
+        let owner: Pubkey = ctx.accounts.owner.key();
+        ctx.accounts.milestone_plan.set_default(pool_key, tge_date, owner, ctx.bumps.milestone_plan);
+    }
+    let plan: &Account<MilestonePlan> = &ctx.accounts.milestone_plan;
+    if plan.refund_passed {
+        return err!(ErrCode::RefundVotePassed);
+    }
+    let index: usize = plan.released_count as usize;
+    if index >= plan.milestones.len() {
+        return err!(ErrCode::AllMilestonesReleased);
+    }
+    if now < plan.milestones[index].release_time {
+        return err!(ErrCode::MilestoneNotReached);
+    }
+    let amount: u64 = plan.tranche_amount(
+        index,
+        ctx.accounts.pool.releasable_purchase_amount()
+    );
+    // seed of authority pda of purchase vault
+    let pool_key: Pubkey = ctx.accounts.pool.key();
+    let seeds: &[&[u8]; 3] = &[
+        b"purchase-vault",
+        pool_key.as_ref(),
+        &[ctx.bumps.purchase_vault],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.purchase_vault.to_account_info(),
+            to: ctx.accounts.owner_purchase_token.to_account_info(),
+            authority: ctx.accounts.purchase_vault.to_account_info(),
+        },
+        signer
+    );
+    token::transfer(transfer_ctx, amount)?;
+    ctx.accounts.pool.released_purchase_amount += amount;
+    let plan: &mut Box<Account<MilestonePlan>> = &mut ctx.accounts.milestone_plan;
+    plan.released_count += 1;
+    plan.released_amount += amount;
+    emit!(MilestoneReleased {
+        pool: pool_key,
+        index: index as u8,
+        amount,
+        released_amount: plan.released_amount,
+    });
+    msg!("Released milestone");
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Role, RoleAssignment };
+
+#[derive(Accounts)]
+#[instruction(role: Role, holder: Pubkey)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // @dev super admin role of signer
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::SuperAdmin as u8], admin.key().as_ref()],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Box<Account<'info, RoleAssignment>>,
+
+    // @dev revoked role
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"role".as_ref(), &[role as u8], holder.as_ref()],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Box<Account<'info, RoleAssignment>>,
+}
+
+// @dev it allows to revoke a role by super admin
+pub fn revoke_role_handler(ctx: Context<RevokeRole>, role: Role, holder: Pubkey) -> Result<()> {
+    // keep at least the signer as super admin
+    if role == Role::SuperAdmin && holder == ctx.accounts.admin.key() {
+        return err!(ErrCode::CannotRevokeOwnSuperAdmin);
+    }
+    msg!("Revoked role");
+    Ok(())
+}
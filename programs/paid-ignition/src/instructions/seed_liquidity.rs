@@ -0,0 +1,278 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, CloseAccount, Mint, Token, TokenAccount, Transfer };
+use std::mem::size_of;
+
+use crate::{ amm, error::ErrCode, Config, LiquiditySeeded, LpLock, Pool, PoolStatus };
+
+#[derive(Accounts)]
+pub struct SeedLiquidity<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev mint address of purchase token
+    #[account(address = pool.purchase_currency.mint @ ErrCode::InvalidMint)]
+    pub purchase_mint: Box<Account<'info, Mint>>,
+
+    // @dev mint address of ido token
+    #[account(address = pool.offered_currency.mint @ ErrCode::InvalidMint)]
+    pub offer_mint: Box<Account<'info, Mint>>,
+
+    // @dev purchase vault, source of raise side
+    #[account(
+        mut,
+        seeds = [b"purchase-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub purchase_vault: Box<Account<'info, TokenAccount>>,
+
+    // @dev offer vault, source of ido token side
+    #[account(
+        mut,
+        seeds = [b"offer-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub offer_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: signs the amm deposit instead of the vaults, owns nothing but the escrows
+    #[account(seeds = [b"liquidity-authority", pool.key().as_ref()], bump)]
+    pub liquidity_authority: UncheckedAccount<'info>,
+
+    // @dev one-off escrow holding exactly the purchase token side, closed after the deposit
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"liquidity-escrow", pool.key().as_ref(), purchase_mint.key().as_ref()],
+        bump,
+        token::mint = purchase_mint,
+        token::authority = liquidity_authority
+    )]
+    pub purchase_escrow: Box<Account<'info, TokenAccount>>,
+
+    // @dev one-off escrow holding exactly the ido token side, closed after the deposit
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"liquidity-escrow", pool.key().as_ref(), offer_mint.key().as_ref()],
+        bump,
+        token::mint = offer_mint,
+        token::authority = liquidity_authority
+    )]
+    pub offer_escrow: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: configured amm program, must still be allowed by config
+    #[account(
+        executable,
+        address = pool.liquidity_amm_program @ ErrCode::LiquidityNotConfigured,
+        constraint = config.allows_amm_program(amm_program.key) @ ErrCode::AmmProgramNotAllowed
+    )]
+    pub amm_program: UncheckedAccount<'info>,
+
+    /// CHECK: amm pool of purchase and ido token, address follows the amm interface seeds
+    #[account(
+        seeds = [b"amm-pool", purchase_mint.key().as_ref(), offer_mint.key().as_ref()],
+        bump,
+        seeds::program = amm_program.key()
+    )]
+    pub amm_pool: UncheckedAccount<'info>,
+
+    /// CHECK: amm reserve of purchase token, address follows the amm interface seeds
+    #[account(
+        mut,
+        seeds = [b"vault", amm_pool.key().as_ref(), purchase_mint.key().as_ref()],
+        bump,
+        seeds::program = amm_program.key()
+    )]
+    pub amm_vault_a: UncheckedAccount<'info>,
+
+    /// CHECK: amm reserve of ido token, address follows the amm interface seeds
+    #[account(
+        mut,
+        seeds = [b"vault", amm_pool.key().as_ref(), offer_mint.key().as_ref()],
+        bump,
+        seeds::program = amm_program.key()
+    )]
+    pub amm_vault_b: UncheckedAccount<'info>,
+
+    // @dev lp token mint of amm pool
+    #[account(
+        mut,
+        seeds = [b"lp-mint", amm_pool.key().as_ref()],
+        bump,
+        seeds::program = amm_program.key()
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    // @dev timelock of seeded lp token
+    #[account(
+        init,
+        payer = owner,
+        space = size_of::<LpLock>() + 8,
+        seeds = [b"lp-lock", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_lock: Box<Account<'info, LpLock>>,
+
+    // @dev lp token vault owned by timelock
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"lp-vault", pool.key().as_ref()],
+        bump,
+        token::mint = lp_mint,
+        token::authority = lp_lock
+    )]
+    pub lp_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> SeedLiquidity<'info> {
+    fn transfer_ctx(
+        &self,
+        from: &Account<'info, TokenAccount>,
+        to: &Account<'info, TokenAccount>
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), Transfer {
+            from: from.to_account_info(),
+            to: to.to_account_info(),
+            authority: from.to_account_info(),
+        })
+    }
+    fn close_ctx(
+        &self,
+        escrow: &Account<'info, TokenAccount>
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), CloseAccount {
+            account: escrow.to_account_info(),
+            destination: self.owner.to_account_info(),
+            authority: self.liquidity_authority.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to seed amm with share of raise and matching ido token at sale price by pool owner
+// @dev once after a successful close, lp token is locked for configured duration
+pub fn seed_liquidity_handler(ctx: Context<SeedLiquidity>, min_lp: u64) -> Result<()> {
+    let now: i64 = Clock::get()?.unix_timestamp;
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(now)?;
+    pool.require_status(&[PoolStatus::Succeeded, PoolStatus::Claiming])?;
+    if pool.liquidity_seeded {
+        return err!(ErrCode::LiquidityAlreadySeeded);
+    }
+    let purchase_amount: u64 = pool.liquidity_purchase_amount();
+    let offer_amount: u64 = pool.liquidity_offer_amount();
+    if purchase_amount == 0 {
+        return err!(ErrCode::LiquidityNotConfigured);
+    }
+    pool.liquidity_seeded = true;
+    pool.released_purchase_amount += purchase_amount;
+    pool.total_funded_amount -= offer_amount;
+
+    // vaults move exact amounts into escrows, they never sign for the amm
+    let pool_key: Pubkey = ctx.accounts.pool.key();
+    let purchase_vault_before: u64 = ctx.accounts.purchase_vault.amount;
+    let offer_vault_before: u64 = ctx.accounts.offer_vault.amount;
+    let purchase_seeds: &[&[u8]; 3] = &[
+        b"purchase-vault",
+        pool_key.as_ref(),
+        &[ctx.bumps.purchase_vault],
+    ];
+    let offer_seeds: &[&[u8]; 3] = &[b"offer-vault", pool_key.as_ref(), &[ctx.bumps.offer_vault]];
+    token::transfer(
+        ctx.accounts
+            .transfer_ctx(&ctx.accounts.purchase_vault, &ctx.accounts.purchase_escrow)
+            .with_signer(&[&purchase_seeds[..]]),
+        purchase_amount
+    )?;
+    token::transfer(
+        ctx.accounts
+            .transfer_ctx(&ctx.accounts.offer_vault, &ctx.accounts.offer_escrow)
+            .with_signer(&[&offer_seeds[..]]),
+        offer_amount
+    )?;
+
+    // escrow authority signs for both sides
+    let authority_seeds: &[&[u8]; 3] = &[
+        b"liquidity-authority",
+        pool_key.as_ref(),
+        &[ctx.bumps.liquidity_authority],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&authority_seeds[..]];
+    amm::deposit_liquidity(
+        &ctx.accounts.amm_program.to_account_info(),
+        amm::DepositLiquidity {
+            authority_a: ctx.accounts.liquidity_authority.to_account_info(),
+            authority_b: ctx.accounts.liquidity_authority.to_account_info(),
+            source_a: ctx.accounts.purchase_escrow.to_account_info(),
+            source_b: ctx.accounts.offer_escrow.to_account_info(),
+            lp_destination: ctx.accounts.lp_vault.to_account_info(),
+            amm_pool: ctx.accounts.amm_pool.to_account_info(),
+            vault_a: ctx.accounts.amm_vault_a.to_account_info(),
+            vault_b: ctx.accounts.amm_vault_b.to_account_info(),
+            lp_mint: ctx.accounts.lp_mint.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        purchase_amount,
+        offer_amount,
+        min_lp,
+        signer
+    )?;
+
+    // amm must have taken both escrows in full and nothing else left the vaults
+    ctx.accounts.purchase_vault.reload()?;
+    ctx.accounts.offer_vault.reload()?;
+    ctx.accounts.purchase_escrow.reload()?;
+    ctx.accounts.offer_escrow.reload()?;
+    if
+        ctx.accounts.purchase_vault.amount != purchase_vault_before - purchase_amount ||
+        ctx.accounts.offer_vault.amount != offer_vault_before - offer_amount ||
+        ctx.accounts.purchase_escrow.amount != 0 ||
+        ctx.accounts.offer_escrow.amount != 0
+    {
+        return err!(ErrCode::LiquidityAmountMismatch);
+    }
+    // escrow rent goes back to owner
+    token::close_account(
+        ctx.accounts.close_ctx(&ctx.accounts.purchase_escrow).with_signer(signer)
+    )?;
+    token::close_account(ctx.accounts.close_ctx(&ctx.accounts.offer_escrow).with_signer(signer))?;
+    ctx.accounts.lp_vault.reload()?;
+
+    let unlock_time: i64 = now + ctx.accounts.pool.lp_lock_duration;
+    let lp_lock: &mut Box<Account<LpLock>> = &mut ctx.accounts.lp_lock;
+    lp_lock.pool = pool_key;
+    lp_lock.lp_mint = ctx.accounts.lp_mint.key();
+    lp_lock.amount = ctx.accounts.lp_vault.amount;
+    lp_lock.unlock_time = unlock_time;
+    lp_lock.bump = ctx.bumps.lp_lock;
+
+    emit!(LiquiditySeeded {
+        pool: pool_key,
+        amm_program: ctx.accounts.amm_program.key(),
+        purchase_amount,
+        offer_amount,
+        lp_amount: lp_lock.amount,
+        unlock_time,
+    });
+    msg!("Seeded liquidity");
+    Ok(())
+}
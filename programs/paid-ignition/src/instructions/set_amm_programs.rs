@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Config, Role, RoleAssignment, MAX_AMM_PROGRAMS };
+
+#[derive(Accounts)]
+pub struct SetAmmPrograms<'info> {
+    pub admin: Signer<'info>,
+
+    // @dev super admin role of signer
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::SuperAdmin as u8], admin.key().as_ref()],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Box<Account<'info, RoleAssignment>>,
+
+    // @dev global config
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+// @dev it allows to replace amm programs pools may seed liquidity into by super admin
+pub fn set_amm_programs_handler(
+    ctx: Context<SetAmmPrograms>,
+    amm_programs: Vec<Pubkey>
+) -> Result<()> {
+    if
+        amm_programs.len() > MAX_AMM_PROGRAMS ||
+        amm_programs.contains(&Pubkey::default())
+    {
+        return err!(ErrCode::InvalidParam);
+    }
+    let config: &mut Box<Account<Config>> = &mut ctx.accounts.config;
+    config.amm_programs = [Pubkey::default(); MAX_AMM_PROGRAMS];
+    config.amm_programs[..amm_programs.len()].copy_from_slice(&amm_programs);
+    msg!("Updated amm programs");
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{ Buyer, ClaimSettingsUpdated };
+
+#[derive(Accounts)]
+pub struct SetClaimSettings<'info> {
+    pub signer: Signer<'info>,
+
+    /// CHECK: only used as seed of buyer account
+    pub pool: UncheckedAccount<'info>,
+
+    // @dev buyer account of signer
+    #[account(
+        mut,
+        seeds = [b"buyer", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub buyer: Box<Account<'info, Buyer>>,
+}
+
+// @dev allowed to set claim delegate and default destination wallet by buyer, default pubkey clears
+pub fn set_claim_settings_handler(
+    ctx: Context<SetClaimSettings>,
+    delegate: Pubkey,
+    destination: Pubkey
+) -> Result<()> {
+    let buyer: &mut Box<Account<Buyer>> = &mut ctx.accounts.buyer;
+    buyer.delegate = delegate;
+    buyer.destination = destination;
+    emit!(ClaimSettingsUpdated {
+        pool: ctx.accounts.pool.key(),
+        wallet: ctx.accounts.signer.key(),
+        delegate,
+        destination,
+    });
+    msg!("Updated claim settings");
+    Ok(())
+}
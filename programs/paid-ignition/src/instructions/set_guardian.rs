@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{ Config, Role, RoleAssignment };
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    pub admin: Signer<'info>,
+
+    // @dev super admin role of signer
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::SuperAdmin as u8], admin.key().as_ref()],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Box<Account<'info, RoleAssignment>>,
+
+    // @dev global config
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+// @dev it allows to rotate guardian by super admin
+pub fn set_guardian_handler(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    ctx.accounts.config.guardian = guardian;
+    msg!("Updated guardian");
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Config, Pool, PoolStatus, PERCENTAGE_DENOMINATOR };
+
+#[derive(Accounts)]
+pub struct SetLiquidityConfig<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev global config, holds allowed amm programs
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev pool account
+    #[account(mut, has_one = owner)]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev allowed to configure liquidity seeding by pool owner before funding completes
+// @dev zero percentage with default amm program turns seeding off
+pub fn set_liquidity_config_handler(
+    ctx: Context<SetLiquidityConfig>,
+    liquidity_percentage: u16,
+    amm_program: Pubkey,
+    lp_lock_duration: i64
+) -> Result<()> {
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    // funding requirement grows with liquidity share
+    pool.require_status(&[PoolStatus::Created])?;
+    if
+        (liquidity_percentage as u64) + (pool.protection_percentage as u64) >
+            PERCENTAGE_DENOMINATOR ||
+        (liquidity_percentage == 0) != (amm_program == Pubkey::default()) ||
+        lp_lock_duration < 0
+    {
+        return err!(ErrCode::InvalidParam);
+    }
+    if amm_program != Pubkey::default() && !ctx.accounts.config.allows_amm_program(&amm_program) {
+        return err!(ErrCode::AmmProgramNotAllowed);
+    }
+    pool.liquidity_percentage = liquidity_percentage;
+    pool.liquidity_amm_program = amm_program;
+    pool.lp_lock_duration = lp_lock_duration;
+    msg!("Updated liquidity config");
+    Ok(())
+}
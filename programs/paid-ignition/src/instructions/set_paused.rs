@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{ Config, Role, RoleAssignment };
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub pauser: Signer<'info>,
+
+    // @dev pauser role of signer, not required for guardian
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::Pauser as u8], pauser.key().as_ref()],
+        bump = pauser_role.bump
+    )]
+    pub pauser_role: Option<Box<Account<'info, RoleAssignment>>>,
+
+    // @dev global config
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = pauser_role.is_some() || pauser.key() == config.guardian
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+// @dev it allows to pause or unpause all pools by pauser or guardian
+pub fn set_paused_handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.config.paused = paused;
+    msg!("Updated global pause");
+    Ok(())
+}
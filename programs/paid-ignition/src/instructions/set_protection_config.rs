@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Pool, PoolStatus, PERCENTAGE_DENOMINATOR };
+
+#[derive(Accounts)]
+pub struct SetProtectionConfig<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(mut, has_one = owner)]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev allowed to configure post tge price protection by pool owner before funding completes
+// @dev zero window with zero percentage turns protection off
+pub fn set_protection_config_handler(
+    ctx: Context<SetProtectionConfig>,
+    protection_window: i64,
+    protection_percentage: u16
+) -> Result<()> {
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    // buyers see protection terms before sale opens
+    pool.require_status(&[PoolStatus::Created])?;
+    if
+        (protection_percentage as u64) + (pool.liquidity_percentage as u64) >
+            PERCENTAGE_DENOMINATOR ||
+        (protection_percentage == 0) != (protection_window == 0) ||
+        protection_window < 0
+    {
+        return err!(ErrCode::InvalidParam);
+    }
+    pool.protection_window = protection_window;
+    pool.protection_percentage = protection_percentage;
+    msg!("Updated protection config");
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{ Pool, PoolStatus };
+
+#[derive(Accounts)]
+pub struct SetTgeGovernance<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(mut, has_one = owner)]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev allowed to make tge date updates depend on buyer approval by pool owner before sale opens
+pub fn set_tge_governance_handler(
+    ctx: Context<SetTgeGovernance>,
+    requires_proposal: bool
+) -> Result<()> {
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    pool.require_status(&[PoolStatus::Created, PoolStatus::Funded])?;
+    pool.tge_update_requires_proposal = requires_proposal;
+    msg!("Updated tge governance");
+    Ok(())
+}
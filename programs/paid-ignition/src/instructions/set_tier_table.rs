@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, Pool, PoolStatus, Tier, TierTable, TierTableSet };
+
+#[derive(Accounts)]
+pub struct SetTierTable<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(mut, has_one = owner)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev tier table of pool, replaced until first registration
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = TierTable::space(),
+        seeds = [b"tiers", pool.key().as_ref()],
+        bump
+    )]
+    pub tier_table: Box<Account<'info, TierTable>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// @dev allowed to set stake tiers of early pool by pool owner before registration starts
+// @dev empty tiers turn tiered allocation off, otherwise snapshot time is required
+pub fn set_tier_table_handler(
+    ctx: Context<SetTierTable>,
+    stake_pool: Pubkey,
+    tiers: Vec<Tier>,
+    snapshot_time: i64,
+    continuous_stake: bool
+) -> Result<()> {
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    pool.require_status(&[PoolStatus::Created, PoolStatus::Funded])?;
+    let table: &mut Box<Account<TierTable>> = &mut ctx.accounts.tier_table;
+    if table.registered_count > 0 {
+        return err!(ErrCode::TierTableLocked);
+    }
+    if !tiers.is_empty() && !TierTable::is_valid(&tiers) {
+        return err!(ErrCode::InvalidTiers);
+    }
+    // stake is always measured at a snapshot, which must pass before early pool opens
+    if !tiers.is_empty() && (snapshot_time <= 0 || snapshot_time >= pool.early_pool_open_time) {
+        return err!(ErrCode::InvalidTime);
+    }
+    pool.tiered = !tiers.is_empty();
+    table.pool = pool.key();
+    table.stake_pool = stake_pool;
+    table.tiers = tiers;
+    table.snapshot_time = snapshot_time;
+    table.continuous_stake = continuous_stake;
+    if table.rent_payer == Pubkey::default() {
+        table.rent_payer = ctx.accounts.owner.key();
+    }
+    table.bump = ctx.bumps.tier_table;
+    emit!(TierTableSet {
+        pool: table.pool,
+        stake_pool,
+        tiers: table.tiers.clone(),
+        snapshot_time,
+        continuous_stake,
+    });
+    msg!("Set tier table");
+    Ok(())
+}
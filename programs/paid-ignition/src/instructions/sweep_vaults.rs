@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Token, TokenAccount, Transfer };
+
+use crate::{ error::ErrCode, MilestonePlan, Pool, PoolStatus, VaultsSwept };
+
+#[derive(Accounts)]
+pub struct SweepVaults<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(
+        has_one = owner,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev milestone plan of pool, none if never created
+    #[account(
+        seeds = [b"milestones", pool.key().as_ref()],
+        bump = milestone_plan.bump
+    )]
+    pub milestone_plan: Option<Box<Account<'info, MilestonePlan>>>,
+
+    // @dev offer vault
+    #[account(
+        mut,
+        seeds = [b"offer-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub offer_vault: Box<Account<'info, TokenAccount>>,
+
+    // @dev purchase vault
+    #[account(
+        mut,
+        seeds = [b"purchase-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub purchase_vault: Box<Account<'info, TokenAccount>>,
+
+    // @dev ido token account of pool owner
+    #[account(
+        mut,
+        token::mint = pool.offered_currency.mint,
+        token::authority = owner
+    )]
+    pub owner_offer_token: Box<Account<'info, TokenAccount>>,
+
+    // @dev purchase token account of pool owner
+    #[account(
+        mut,
+        token::mint = pool.purchase_currency.mint,
+        token::authority = owner
+    )]
+    pub owner_purchase_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// @dev allowed to move what finalized pool no longer owes anyone to pool owner, so vaults can close
+// @dev token fee share, unseeded liquidity and rounding dust are swept, while unclaimed
+// allocations, unsold ido token, unreleased milestones, refunds and protection reserve stay
+pub fn sweep_vaults_handler(ctx: Context<SweepVaults>) -> Result<()> {
+    let pool: &Account<Pool> = &ctx.accounts.pool;
+    pool.require_status(&[PoolStatus::Finalized])?;
+    let offer_owed: u64 = pool.owed_offer_amount() + pool.pending_unsold_offer_amount();
+    let offer_amount: u64 = ctx.accounts.offer_vault.amount.saturating_sub(offer_owed);
+    // raise without a plan has not been released at all
+    let unsettled_amount: u64 = match &ctx.accounts.milestone_plan {
+        Some(plan) => plan.unsettled_amount(pool.releasable_purchase_amount()),
+        None => pool.releasable_purchase_amount(),
+    };
+    let purchase_owed: u64 = unsettled_amount + pool.unreclaimed_protection_reserve();
+    let purchase_amount: u64 = ctx.accounts.purchase_vault.amount.saturating_sub(purchase_owed);
+    if offer_amount == 0 && purchase_amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+
+    let pool_key: Pubkey = pool.key();
+    if offer_amount > 0 {
+        // seeds of authority pda of offer vault
+        let seeds: &[&[u8]; 3] = &[b"offer-vault", pool_key.as_ref(), &[ctx.bumps.offer_vault]];
+        let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.offer_vault.to_account_info(),
+                to: ctx.accounts.owner_offer_token.to_account_info(),
+                authority: ctx.accounts.offer_vault.to_account_info(),
+            },
+            signer
+        );
+        token::transfer(transfer_ctx, offer_amount)?;
+    }
+    if purchase_amount > 0 {
+        // seeds of authority pda of purchase vault
+        let seeds: &[&[u8]; 3] = &[
+            b"purchase-vault",
+            pool_key.as_ref(),
+            &[ctx.bumps.purchase_vault],
+        ];
+        let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.purchase_vault.to_account_info(),
+                to: ctx.accounts.owner_purchase_token.to_account_info(),
+                authority: ctx.accounts.purchase_vault.to_account_info(),
+            },
+            signer
+        );
+        token::transfer(transfer_ctx, purchase_amount)?;
+    }
+    emit!(VaultsSwept {
+        pool: pool_key,
+        offer_amount,
+        purchase_amount,
+    });
+    msg!("Swept vaults");
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrCode,
+    Pool,
+    PoolStatus,
+    Role,
+    RoleAssignment,
+    MAX_CROWD_FUNDING_PARTICIPATION_FEE_PERCENTAGE,
+    MAX_GALAXY_PARTICIPATION_FEE_PERCENTAGE,
+    MIN_CROWD_FUNDING_PARTICIPATION_FEE_PERCENTAGE,
+    MIN_GALAXY_PARTICIPATION_FEE_PERCENTAGE,
+    PERCENTAGE_DENOMINATOR,
+};
+
+#[derive(Accounts)]
+pub struct UpdateFees<'info> {
+    pub authority: Signer<'info>,
+
+    // @dev fee manager role of signer, none if signer is pool owner
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::FeeManager as u8], authority.key().as_ref()],
+        bump = fee_manager_role.bump
+    )]
+    pub fee_manager_role: Option<Box<Account<'info, RoleAssignment>>>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev it allows to update fees by pool owner or fee manager before early pool opens
+pub fn update_fees_handler(
+    ctx: Context<UpdateFees>,
+    token_fee_percentage: u16,
+    early_pool_participation_fee_percentage: u16,
+    open_pool_participation_fee_percentage: u16
+) -> Result<()> {
+    ctx.accounts.pool.require_authority(
+        ctx.accounts.authority.key,
+        ctx.accounts.fee_manager_role.is_some()
+    )?;
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    let now: i64 = Clock::get()?.unix_timestamp;
+    pool.refresh_status(now)?;
+    pool.require_status(&[PoolStatus::Created, PoolStatus::Funded])?;
+    if (token_fee_percentage as u64) > PERCENTAGE_DENOMINATOR {
+        return err!(ErrCode::InvalidTokenFeePercentage);
+    }
+    if
+        !(MIN_GALAXY_PARTICIPATION_FEE_PERCENTAGE..=MAX_GALAXY_PARTICIPATION_FEE_PERCENTAGE).contains(
+            &early_pool_participation_fee_percentage
+        )
+    {
+        return err!(ErrCode::InvalidParam);
+    }
+    if
+        !(
+            MIN_CROWD_FUNDING_PARTICIPATION_FEE_PERCENTAGE..=MAX_CROWD_FUNDING_PARTICIPATION_FEE_PERCENTAGE
+        ).contains(&open_pool_participation_fee_percentage)
+    {
+        return err!(ErrCode::InvalidParam);
+    }
+    pool.token_fee_percentage = token_fee_percentage;
+    pool.early_pool_participation_fee_percentage = early_pool_participation_fee_percentage;
+    pool.open_pool_participation_fee_percentage = open_pool_participation_fee_percentage;
+    msg!("Updated fees");
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{ error::ErrCode, MetadataArgs, Pool, PoolMetadata, PoolMetadataUpdated, PoolStatus };
+
+#[derive(Accounts)]
+pub struct UpdatePoolMetadata<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(mut, has_one = owner)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev display metadata of pool
+    #[account(
+        mut,
+        seeds = [b"metadata", pool.key().as_ref()],
+        bump = pool_metadata.bump
+    )]
+    pub pool_metadata: Box<Account<'info, PoolMetadata>>,
+}
+
+// @dev allowed to replace pool metadata by pool owner before early pool opens
+// @dev buyers see the project they buy into, so it is fixed afterwards
+pub fn update_pool_metadata_handler(
+    ctx: Context<UpdatePoolMetadata>,
+    metadata: MetadataArgs
+) -> Result<()> {
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    pool.require_status(&[PoolStatus::Created, PoolStatus::Funded])?;
+    if !PoolMetadata::is_valid(&metadata) {
+        return err!(ErrCode::InvalidMetadata);
+    }
+    let pool_metadata: &mut Box<Account<PoolMetadata>> = &mut ctx.accounts.pool_metadata;
+    pool_metadata.set(metadata);
+    emit!(PoolMetadataUpdated {
+        pool: pool_metadata.pool,
+        name: pool_metadata.name.clone(),
+        symbol: pool_metadata.symbol.clone(),
+        uri: pool_metadata.uri.clone(),
+        website: pool_metadata.website.clone(),
+        content_hash: pool_metadata.content_hash,
+    });
+    msg!("Updated pool metadata");
+    Ok(())
+}
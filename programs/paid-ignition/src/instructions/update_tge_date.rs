@@ -1,23 +1,78 @@
 use anchor_lang::prelude::*;
 
-use crate::{error::ErrCode, Pool, MAX_TGE_DATE_ADJUSTMENT_ATTEMPTS};
+use crate::{
+  error::ErrCode,
+  Pool,
+  PoolStatus,
+  Proposal,
+  ProposalKind,
+  Role,
+  RoleAssignment,
+  TgeDateChange,
+  TgeDateUpdated,
+  TgeHistory,
+  MAX_TGE_DATE_ADJUSTMENT,
+  MAX_TGE_DATE_ADJUSTMENT_ATTEMPTS,
+};
 
 #[derive(Accounts)]
 pub struct UpdateTgeDate <'info> {
   #[account(mut)]
-  pub owner: Signer<'info>,
+  pub authority: Signer<'info>,
 
-  // @dev pool account
+  // @dev pool operator role of signer, none if signer is pool owner
   #[account(
-    mut,
-    has_one = owner
+    seeds = [b"role".as_ref(), &[Role::PoolOperator as u8], authority.key().as_ref()],
+    bump = operator_role.bump
   )]
+  pub operator_role: Option<Box<Account<'info, RoleAssignment>>>,
+
+  // @dev pool account
+  #[account(mut)]
   pub pool: Box<Account<'info, Pool>>,
+
+  // @dev tge date history of pool
+  #[account(
+    init_if_needed,
+    payer = authority,
+    space = TgeHistory::SPACE,
+    seeds = [b"tge-history", pool.key().as_ref()],
+    bump
+  )]
+  pub tge_history: Box<Account<'info, TgeHistory>>,
+
+  // @dev approved extend tge proposal, required if pool makes tge updates depend on buyers
+  #[account(mut, has_one = pool)]
+  pub proposal: Option<Box<Account<'info, Proposal>>>,
+
+  pub system_program: Program<'info, System>,
 }
 
-// @dev it allows to update tge date by creator
+// @dev it allows to update tge date by pool owner or pool operator
 pub fn update_tge_date_handler(ctx: Context<UpdateTgeDate>, tge_date: i64) -> Result<()> {
+  ctx.accounts.pool.require_authority(
+    ctx.accounts.authority.key,
+    ctx.accounts.operator_role.is_some()
+  )?;
   let pool = &mut ctx.accounts.pool;
+  let now: i64 = Clock::get()?.unix_timestamp;
+  pool.refresh_status(now)?;
+  pool.require_status(
+      &[
+          PoolStatus::Created,
+          PoolStatus::Funded,
+          PoolStatus::EarlyOpen,
+          PoolStatus::OpenOpen,
+          PoolStatus::Closed,
+          PoolStatus::Succeeded,
+          PoolStatus::Claiming,
+      ]
+  )?;
+
+  // tge already happened
+  if now >= pool.tge_date {
+      return err!(ErrCode::TGEAlreadyPassed);
+  }
 
   // validate new tge date
   if pool.open_pool_close_time > tge_date {
@@ -28,7 +83,40 @@ pub fn update_tge_date_handler(ctx: Context<UpdateTgeDate>, tge_date: i64) -> Re
       return err!(ErrCode::NotAllowedToAdjustTGEDateExceedsAttempts);
   }
 
+  if tge_date > pool.original_tge_date + MAX_TGE_DATE_ADJUSTMENT {
+      return err!(ErrCode::NotAllowedToAdjustTGEDateTooFar);
+  }
+
+  // buyer approval, consumed by this update
+  if pool.tge_update_requires_proposal || ctx.accounts.proposal.is_some() {
+      match &mut ctx.accounts.proposal {
+          Some(proposal) if proposal.approves(&ProposalKind::ExtendTge { tge_date }) => {
+              proposal.executed = true;
+          }
+          _ => {
+              return err!(ErrCode::ProposalNotApproved);
+          }
+      }
+  }
+
+  let previous_tge_date: i64 = pool.tge_date;
   pool.tge_date = tge_date;
+  pool.tge_update_attempts += 1;
+
+  // record change
+  let tge_history = &mut ctx.accounts.tge_history;
+  tge_history.pool = pool.key();
+  tge_history.changes.push(TgeDateChange {
+      previous_tge_date,
+      new_tge_date: tge_date,
+      updated_at: now,
+  });
+  emit!(TgeDateUpdated {
+      pool: pool.key(),
+      previous_tge_date,
+      new_tge_date: tge_date,
+      attempts: pool.tge_update_attempts,
+  });
   msg!("Updated tge date");
   Ok(())
-}
\ No newline at end of file
+}
@@ -1,27 +1,39 @@
 use anchor_lang::prelude::*;
 
-use crate::{ error::ErrCode, Pool };
+use crate::{ error::ErrCode, Pool, PoolStatus, Role, RoleAssignment };
 
 #[derive(Accounts)]
 pub struct UpdateTime<'info> {
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
 
-    // @dev pool account
+    // @dev pool operator role of signer, none if signer is pool owner
     #[account(
-      mut,
-      has_one = owner,
+        seeds = [b"role".as_ref(), &[Role::PoolOperator as u8], authority.key().as_ref()],
+        bump = operator_role.bump
     )]
+    pub operator_role: Option<Box<Account<'info, RoleAssignment>>>,
+
+    // @dev pool account
+    #[account(mut)]
     pub pool: Box<Account<'info, Pool>>,
 }
 
-// @dev it allows to update time by creator
+// @dev it allows to update time by pool owner or pool operator
 pub fn update_time_handler(
     ctx: Context<UpdateTime>,
     early_pool_close_time: i64,
     open_pool_close_time: i64
 ) -> Result<()> {
+    ctx.accounts.pool.require_authority(
+        ctx.accounts.authority.key,
+        ctx.accounts.operator_role.is_some()
+    )?;
     let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    pool.require_status(
+        &[PoolStatus::Created, PoolStatus::Funded, PoolStatus::EarlyOpen, PoolStatus::OpenOpen]
+    )?;
     if pool.early_pool_open_time > early_pool_close_time {
         return err!(ErrCode::InvalidTime);
     }
@@ -4,7 +4,7 @@ use anchor_spl::{
     token::{ self, Token, TokenAccount, Transfer },
 };
 
-use crate::{ Buyer, Pool };
+use crate::{ error::ErrCode, Buyer, Pool, PoolStatus };
 
 #[derive(Accounts)]
 pub struct UserWithdrawPurchase<'info> {
@@ -47,10 +47,17 @@ impl<'info> UserWithdrawPurchase<'info> {
 }
 
 // @dev allowed to withdraw purchase token by user if pool was failed
+// @dev emergency refund path, intentionally not blocked by pause
 pub fn user_withdraw_purchase_handler(
     ctx: Context<UserWithdrawPurchase>,
     amount: u64
 ) -> Result<()> {
+    // validate status
+    ctx.accounts.pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    ctx.accounts.pool.require_status(&[PoolStatus::Failed, PoolStatus::Cancelled])?;
+    if amount > ctx.accounts.buyer.total_purchase {
+        return err!(ErrCode::InvalidAmount);
+    }
     let pool_storage: &Box<Account<Pool>> = &ctx.accounts.pool;
     // seed of authority pda of purchase vault
     let seeds: &[&[u8]; 3] = &[
@@ -61,6 +68,16 @@ pub fn user_withdraw_purchase_handler(
     let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
     // transfer token to user token account
     token::transfer(ctx.accounts.transfer_ctx().with_signer(signer), amount)?;
+    // update pool info
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.purchased_amount -= amount;
+    // update buyer info
+    let buyer: &mut Box<Account<Buyer>> = &mut ctx.accounts.buyer;
+    buyer.total_purchase -= amount;
+    if buyer.total_purchase == 0 {
+        buyer.early_purchased = 0;
+        buyer.total_amount = 0;
+    }
     msg!("Withdraw purchase token");
     Ok(())
 }
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{ self, CloseAccount, Mint, Token, TokenAccount, Transfer },
+};
+
+use crate::{ error::ErrCode, LpLock, Pool };
+
+#[derive(Accounts)]
+pub struct WithdrawLockedLp<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(has_one = owner)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev timelock of seeded lp token, rent goes to pool owner who seeded it
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"lp-lock", pool.key().as_ref()],
+        bump = lp_lock.bump
+    )]
+    pub lp_lock: Box<Account<'info, LpLock>>,
+
+    // @dev lp token vault owned by timelock, closed once emptied
+    #[account(
+        mut,
+        seeds = [b"lp-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_vault: Box<Account<'info, TokenAccount>>,
+
+    // @dev lp token mint of amm pool
+    #[account(address = lp_lock.lp_mint @ ErrCode::InvalidMint)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    // @dev associated lp token account of pool owner, created if empty
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = lp_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_lp_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+// @dev allowed to withdraw locked lp token by pool owner after unlock time, closes lock and vault
+pub fn withdraw_locked_lp_handler(ctx: Context<WithdrawLockedLp>) -> Result<()> {
+    if Clock::get()?.unix_timestamp < ctx.accounts.lp_lock.unlock_time {
+        return err!(ErrCode::LpLocked);
+    }
+    let amount: u64 = ctx.accounts.lp_vault.amount;
+    if amount == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+    // seeds of lp lock, authority of lp vault
+    let pool_key: Pubkey = ctx.accounts.pool.key();
+    let seeds: &[&[u8]; 3] = &[b"lp-lock", pool_key.as_ref(), &[ctx.accounts.lp_lock.bump]];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.lp_vault.to_account_info(),
+            to: ctx.accounts.owner_lp_token.to_account_info(),
+            authority: ctx.accounts.lp_lock.to_account_info(),
+        },
+        signer
+    );
+    token::transfer(transfer_ctx, amount)?;
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.lp_vault.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.lp_lock.to_account_info(),
+        },
+        signer
+    );
+    token::close_account(close_ctx)?;
+    msg!("Withdrew locked lp token");
+    Ok(())
+}
@@ -4,15 +4,26 @@ use anchor_spl::{
     token::{ self, Mint, Token, TokenAccount, Transfer },
 };
 
-use crate::Pool;
+use crate::{ error::ErrCode, Config, Pool, PoolStatus, Role, RoleAssignment };
 
 #[derive(Accounts)]
 pub struct WithdrawOffer<'info> {
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
 
-    // @dev ido token account of owner
-    #[account(mut)]
+    // @dev pool operator role of signer, none if signer is pool owner
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::PoolOperator as u8], authority.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Box<Account<'info, RoleAssignment>>>,
+
+    // @dev ido token account of pool owner
+    #[account(
+        mut,
+        token::mint = offer_mint,
+        token::authority = pool.owner
+    )]
     pub owner_offer_token: Account<'info, TokenAccount>,
 
     // @dev mint address of ido token
@@ -21,12 +32,25 @@ pub struct WithdrawOffer<'info> {
     // @dev offer vault
     #[account(
         mut,
+        seeds = [b"offer-vault", pool.key().as_ref()],
+        bump = pool.offered_bump,
         token::mint = offer_mint
     )]
     pub offer_vault: Account<'info, TokenAccount>,
 
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
     // @dev pool account
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !pool.paused @ ErrCode::Paused
+    )]
     pub pool: Box<Account<'info, Pool>>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -44,8 +68,18 @@ impl<'info> WithdrawOffer<'info> {
     }
 }
 
-// @dev allowed to withdraw ido token by creator if failed
+// @dev allowed to withdraw ido token to pool owner by pool owner or pool operator if failed
 pub fn withdraw_offer_handler(ctx: Context<WithdrawOffer>, amount: u64) -> Result<()> {
+    ctx.accounts.pool.require_authority(
+        ctx.accounts.authority.key,
+        ctx.accounts.operator_role.is_some()
+    )?;
+    // validate status
+    ctx.accounts.pool.refresh_status(Clock::get()?.unix_timestamp)?;
+    ctx.accounts.pool.require_status(&[PoolStatus::Failed, PoolStatus::Cancelled])?;
+    if amount > ctx.accounts.pool.total_funded_amount {
+        return err!(ErrCode::InvalidAmount);
+    }
     let bump: u8 = ctx.accounts.pool.offered_bump;
     // seed of authority pda of offer vault
     let seeds: &[&[u8]; 3] = &[
@@ -56,6 +90,7 @@ pub fn withdraw_offer_handler(ctx: Context<WithdrawOffer>, amount: u64) -> Resul
     let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
     // transfer token to creator token account
     token::transfer(ctx.accounts.transfer_ctx().with_signer(signer), amount)?;
+    ctx.accounts.pool.total_funded_amount -= amount;
     msg!("Withdraw ido token");
     Ok(())
 }
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Token, TokenAccount, Transfer };
+
+use crate::{ error::ErrCode, Config, Project, ProjectOfferWithdrawn };
+
+#[derive(Accounts)]
+pub struct WithdrawProjectOffer<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev project account
+    #[account(mut, has_one = owner)]
+    pub project: Box<Account<'info, Project>>,
+
+    // @dev offer vault shared by all rounds
+    #[account(
+        mut,
+        seeds = [b"project-offer-vault", project.key().as_ref()],
+        bump = project.offer_bump
+    )]
+    pub offer_vault: Box<Account<'info, TokenAccount>>,
+
+    // @dev ido token account of project owner
+    #[account(
+        mut,
+        token::mint = project.offer_mint,
+        token::authority = owner
+    )]
+    pub owner_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// @dev allowed to withdraw unsold ido token by project owner once the last round closed
+// @dev allocations of buyers stay in vault until claimed
+pub fn withdraw_project_offer_handler(ctx: Context<WithdrawProjectOffer>) -> Result<()> {
+    let project: &Account<Project> = &ctx.accounts.project;
+    if !project.is_closed(Clock::get()?.unix_timestamp) {
+        return err!(ErrCode::RoundNotClosed);
+    }
+    let amount: u64 = project.excess_offer_amount();
+    if amount == 0 {
+        return err!(ErrCode::NoUnsoldAmount);
+    }
+    // seeds of authority pda of offer vault
+    let project_key: Pubkey = project.key();
+    let seeds: &[&[u8]; 3] = &[b"project-offer-vault", project_key.as_ref(), &[project.offer_bump]];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.offer_vault.to_account_info(),
+            to: ctx.accounts.owner_token.to_account_info(),
+            authority: ctx.accounts.offer_vault.to_account_info(),
+        },
+        signer
+    );
+    token::transfer(transfer_ctx, amount)?;
+    ctx.accounts.project.total_funded_amount -= amount;
+    emit!(ProjectOfferWithdrawn {
+        project: project_key,
+        amount,
+    });
+    msg!("Withdrew unsold IDO of project");
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Token, TokenAccount, Transfer };
+
+use crate::{ error::ErrCode, Config, Project, RoundRaiseWithdrawn };
+
+#[derive(Accounts)]
+pub struct WithdrawRoundRaise<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev project account
+    #[account(mut, has_one = owner)]
+    pub project: Box<Account<'info, Project>>,
+
+    // @dev purchase vault shared by all rounds
+    #[account(
+        mut,
+        seeds = [b"project-purchase-vault", project.key().as_ref()],
+        bump = project.purchase_bump
+    )]
+    pub purchase_vault: Box<Account<'info, TokenAccount>>,
+
+    // @dev purchase token account of project owner
+    #[account(
+        mut,
+        token::mint = project.purchase_mint,
+        token::authority = owner
+    )]
+    pub owner_purchase_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// @dev allowed to withdraw raise of a closed round by project owner
+// @dev raise is released as soon as the round closes, projects have no refund or milestone escrow
+pub fn withdraw_round_raise_handler(ctx: Context<WithdrawRoundRaise>, round: u8) -> Result<()> {
+    let project: &Account<Project> = &ctx.accounts.project;
+    let amount: u64 = match project.rounds.get(round as usize) {
+        Some(round_config) if Clock::get()?.unix_timestamp < round_config.close_time => {
+            return err!(ErrCode::RoundNotClosed);
+        }
+        Some(round_config) if round_config.raise_withdrawn => {
+            return err!(ErrCode::RaiseWithdrawn);
+        }
+        Some(round_config) => round_config.purchased_amount,
+        None => {
+            return err!(ErrCode::InvalidRound);
+        }
+    };
+    // seed of authority pda of purchase vault
+    let project_key: Pubkey = project.key();
+    let seeds: &[&[u8]; 3] = &[
+        b"project-purchase-vault",
+        project_key.as_ref(),
+        &[project.purchase_bump],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.purchase_vault.to_account_info(),
+            to: ctx.accounts.owner_purchase_token.to_account_info(),
+            authority: ctx.accounts.purchase_vault.to_account_info(),
+        },
+        signer
+    );
+    token::transfer(transfer_ctx, amount)?;
+    ctx.accounts.project.rounds[round as usize].raise_withdrawn = true;
+    emit!(RoundRaiseWithdrawn {
+        project: project_key,
+        round,
+        amount,
+    });
+    msg!("Withdrew raise of round");
+    Ok(())
+}
@@ -1,11 +1,15 @@
+pub mod amm;
+pub mod compression;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 use anchor_lang::prelude::*;
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -15,12 +19,18 @@ declare_id!("7bv1WyCMQMFB61TE76VWUtXZLq3n8wWnS88XNLYFNekd");
 pub mod paid_ignition {
     use super::*;
 
-    pub fn create_pool(ctx: Context<CreatePool>, uints: [u64; 18]) -> Result<()> {
-        create_pool_handler(ctx, uints)
+    pub fn create_pool(
+        ctx: Context<CreatePool>,
+        uints: [u64; 18],
+        unsold_policy: UnsoldPolicy,
+        unsold_recipient: Pubkey,
+        metadata: MetadataArgs
+    ) -> Result<()> {
+        create_pool_handler(ctx, uints, unsold_policy, unsold_recipient, metadata)
     }
 
-    pub fn fund_offer(ctx: Context<FundOffer>, amount: u64, bump: u8) -> Result<()> {
-        fund_offer_handler(ctx, amount, bump)
+    pub fn fund_offer(ctx: Context<FundOffer>, amount: u64) -> Result<()> {
+        fund_offer_handler(ctx, amount)
     }
 
     pub fn update_tge_date(ctx: Context<UpdateTgeDate>, tge_date: i64) -> Result<()> {
@@ -37,18 +47,16 @@ pub mod paid_ignition {
 
     pub fn buy_in_early_pool(
         ctx: Context<BuyInEarlyPool>,
-        purchase_amount: u64,
-        bump: u8
+        purchase_amount: u64
     ) -> Result<()> {
-        buy_in_early_pool_handler(ctx, purchase_amount, bump)
+        buy_in_early_pool_handler(ctx, purchase_amount)
     }
 
     pub fn buy_in_open_pool(
         ctx: Context<BuyInOpenPool>,
-        purchase_amount: u64,
-        bump: u8
+        purchase_amount: u64
     ) -> Result<()> {
-        buy_in_open_pool_handler(ctx, purchase_amount, bump)
+        buy_in_open_pool_handler(ctx, purchase_amount)
     }
 
     pub fn user_withdraw_purchase(ctx: Context<UserWithdrawPurchase>, amount: u64) -> Result<()> {
@@ -59,7 +67,322 @@ pub mod paid_ignition {
         withdraw_offer_handler(ctx, amount)
     }
 
-    pub fn claim_offer(ctx: Context<ClaimOffer>) -> Result<()> {
-        claim_offer_handler(ctx)
+    pub fn claim_offer(ctx: Context<ClaimOffer>, amount: u64) -> Result<()> {
+        claim_offer_handler(ctx, amount)
+    }
+
+    pub fn init_config(ctx: Context<InitConfig>) -> Result<()> {
+        init_config_handler(ctx)
+    }
+
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+        set_guardian_handler(ctx, guardian)
+    }
+
+    pub fn set_amm_programs(ctx: Context<SetAmmPrograms>, amm_programs: Vec<Pubkey>) -> Result<()> {
+        set_amm_programs_handler(ctx, amm_programs)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, holder: Pubkey) -> Result<()> {
+        grant_role_handler(ctx, role, holder)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role, holder: Pubkey) -> Result<()> {
+        revoke_role_handler(ctx, role, holder)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        set_paused_handler(ctx, paused)
+    }
+
+    pub fn set_pool_paused(ctx: Context<SetPoolPaused>, paused: bool) -> Result<()> {
+        set_pool_paused_handler(ctx, paused)
+    }
+
+    pub fn update_fees(
+        ctx: Context<UpdateFees>,
+        token_fee_percentage: u16,
+        early_pool_participation_fee_percentage: u16,
+        open_pool_participation_fee_percentage: u16
+    ) -> Result<()> {
+        update_fees_handler(
+            ctx,
+            token_fee_percentage,
+            early_pool_participation_fee_percentage,
+            open_pool_participation_fee_percentage
+        )
+    }
+
+    pub fn propose_owner(ctx: Context<ProposeOwner>, pending_owner: Pubkey) -> Result<()> {
+        propose_owner_handler(ctx, pending_owner)
+    }
+
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        accept_owner_handler(ctx)
+    }
+
+    pub fn cancel_owner_transfer(ctx: Context<CancelOwnerTransfer>) -> Result<()> {
+        cancel_owner_transfer_handler(ctx)
+    }
+
+    pub fn cancel_purchase(ctx: Context<CancelPurchase>) -> Result<()> {
+        cancel_purchase_handler(ctx)
+    }
+
+    pub fn close_buyer(ctx: Context<CloseBuyer>) -> Result<()> {
+        close_buyer_handler(ctx)
+    }
+
+    pub fn close_claim_history(ctx: Context<CloseClaimHistory>) -> Result<()> {
+        close_claim_history_handler(ctx)
+    }
+
+    pub fn close_purchase_vault(ctx: Context<ClosePurchaseVault>) -> Result<()> {
+        close_purchase_vault_handler(ctx)
+    }
+
+    pub fn close_offer_vault(ctx: Context<CloseOfferVault>) -> Result<()> {
+        close_offer_vault_handler(ctx)
+    }
+
+    pub fn sweep_vaults(ctx: Context<SweepVaults>) -> Result<()> {
+        sweep_vaults_handler(ctx)
+    }
+
+    pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+        close_pool_handler(ctx)
+    }
+
+    pub fn enable_claims(ctx: Context<EnableClaims>) -> Result<()> {
+        enable_claims_handler(ctx)
+    }
+
+    pub fn cancel_pool(ctx: Context<CancelPool>) -> Result<()> {
+        cancel_pool_handler(ctx)
+    }
+
+    pub fn finalize_pool(ctx: Context<FinalizePool>) -> Result<()> {
+        finalize_pool_handler(ctx)
+    }
+
+    pub fn refresh_pool_status(ctx: Context<RefreshPoolStatus>) -> Result<()> {
+        refresh_pool_status_handler(ctx)
+    }
+
+    pub fn crank_claims<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CrankClaims<'info>>
+    ) -> Result<()> {
+        crank_claims_handler(ctx)
+    }
+
+    pub fn fund_tip_vault(
+        ctx: Context<FundTipVault>,
+        tip_per_claim: u64,
+        amount: u64
+    ) -> Result<()> {
+        fund_tip_vault_handler(ctx, tip_per_claim, amount)
+    }
+
+    pub fn close_tip_vault(ctx: Context<CloseTipVault>) -> Result<()> {
+        close_tip_vault_handler(ctx)
+    }
+
+    pub fn finalize_unsold(ctx: Context<FinalizeUnsold>) -> Result<()> {
+        finalize_unsold_handler(ctx)
+    }
+
+    pub fn set_liquidity_config(
+        ctx: Context<SetLiquidityConfig>,
+        liquidity_percentage: u16,
+        amm_program: Pubkey,
+        lp_lock_duration: i64
+    ) -> Result<()> {
+        set_liquidity_config_handler(ctx, liquidity_percentage, amm_program, lp_lock_duration)
+    }
+
+    pub fn seed_liquidity(ctx: Context<SeedLiquidity>, min_lp: u64) -> Result<()> {
+        seed_liquidity_handler(ctx, min_lp)
+    }
+
+    pub fn withdraw_locked_lp(ctx: Context<WithdrawLockedLp>) -> Result<()> {
+        withdraw_locked_lp_handler(ctx)
+    }
+
+    pub fn declare_milestones(
+        ctx: Context<DeclareMilestones>,
+        milestones: Vec<Milestone>
+    ) -> Result<()> {
+        declare_milestones_handler(ctx, milestones)
+    }
+
+    pub fn release_milestone(ctx: Context<ReleaseMilestone>) -> Result<()> {
+        release_milestone_handler(ctx)
+    }
+
+    pub fn approve_refund(ctx: Context<ApproveRefund>) -> Result<()> {
+        approve_refund_handler(ctx)
+    }
+
+    pub fn claim_milestone_refund(ctx: Context<ClaimMilestoneRefund>) -> Result<()> {
+        claim_milestone_refund_handler(ctx)
+    }
+
+    pub fn close_milestone_plan(ctx: Context<CloseMilestonePlan>) -> Result<()> {
+        close_milestone_plan_handler(ctx)
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        kind: ProposalKind,
+        deadline: i64,
+        quorum: u16
+    ) -> Result<()> {
+        create_proposal_handler(ctx, kind, deadline, quorum)
+    }
+
+    pub fn cast_vote(ctx: Context<CastVote>, approve: bool) -> Result<()> {
+        cast_vote_handler(ctx, approve)
+    }
+
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        finalize_proposal_handler(ctx)
+    }
+
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        close_proposal_handler(ctx)
+    }
+
+    pub fn close_proposal_vote(ctx: Context<CloseProposalVote>) -> Result<()> {
+        close_proposal_vote_handler(ctx)
+    }
+
+    pub fn set_tge_governance(ctx: Context<SetTgeGovernance>, requires_proposal: bool) -> Result<()> {
+        set_tge_governance_handler(ctx, requires_proposal)
+    }
+
+    pub fn set_protection_config(
+        ctx: Context<SetProtectionConfig>,
+        protection_window: i64,
+        protection_percentage: u16
+    ) -> Result<()> {
+        set_protection_config_handler(ctx, protection_window, protection_percentage)
+    }
+
+    pub fn claim_price_protection(ctx: Context<ClaimPriceProtection>) -> Result<()> {
+        claim_price_protection_handler(ctx)
+    }
+
+    pub fn reclaim_protection_reserve(ctx: Context<ReclaimProtectionReserve>) -> Result<()> {
+        reclaim_protection_reserve_handler(ctx)
+    }
+
+    pub fn set_tier_table(
+        ctx: Context<SetTierTable>,
+        stake_pool: Pubkey,
+        tiers: Vec<Tier>,
+        snapshot_time: i64,
+        continuous_stake: bool
+    ) -> Result<()> {
+        set_tier_table_handler(ctx, stake_pool, tiers, snapshot_time, continuous_stake)
+    }
+
+    pub fn register_tier(ctx: Context<RegisterTier>) -> Result<()> {
+        register_tier_handler(ctx)
+    }
+
+    pub fn close_tier_registration(ctx: Context<CloseTierRegistration>) -> Result<()> {
+        close_tier_registration_handler(ctx)
+    }
+
+    pub fn close_tier_table(ctx: Context<CloseTierTable>) -> Result<()> {
+        close_tier_table_handler(ctx)
+    }
+
+    pub fn update_pool_metadata(
+        ctx: Context<UpdatePoolMetadata>,
+        metadata: MetadataArgs
+    ) -> Result<()> {
+        update_pool_metadata_handler(ctx, metadata)
+    }
+
+    pub fn create_project(ctx: Context<CreateProject>) -> Result<()> {
+        create_project_handler(ctx)
+    }
+
+    pub fn add_round(ctx: Context<AddRound>, params: RoundParams) -> Result<()> {
+        add_round_handler(ctx, params)
+    }
+
+    pub fn fund_project(ctx: Context<FundProject>, amount: u64) -> Result<()> {
+        fund_project_handler(ctx, amount)
+    }
+
+    pub fn buy_in_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyInRound<'info>>,
+        round: u8,
+        purchase_amount: u64,
+        proof: EligibilityProof
+    ) -> Result<()> {
+        buy_in_round_handler(ctx, round, purchase_amount, proof)
+    }
+
+    pub fn claim_round_offer(ctx: Context<ClaimRoundOffer>, round: u8, amount: u64) -> Result<()> {
+        claim_round_offer_handler(ctx, round, amount)
+    }
+
+    pub fn withdraw_round_raise(ctx: Context<WithdrawRoundRaise>, round: u8) -> Result<()> {
+        withdraw_round_raise_handler(ctx, round)
+    }
+
+    pub fn withdraw_project_offer(ctx: Context<WithdrawProjectOffer>) -> Result<()> {
+        withdraw_project_offer_handler(ctx)
+    }
+
+    pub fn close_round_buyer(ctx: Context<CloseRoundBuyer>, round: u8) -> Result<()> {
+        close_round_buyer_handler(ctx, round)
+    }
+
+    pub fn close_project_purchase_vault(ctx: Context<CloseProjectPurchaseVault>) -> Result<()> {
+        close_project_purchase_vault_handler(ctx)
+    }
+
+    pub fn close_project_offer_vault(ctx: Context<CloseProjectOfferVault>) -> Result<()> {
+        close_project_offer_vault_handler(ctx)
+    }
+
+    pub fn close_project(ctx: Context<CloseProject>) -> Result<()> {
+        close_project_handler(ctx)
+    }
+
+    pub fn propose_project_owner(
+        ctx: Context<ProposeProjectOwner>,
+        pending_owner: Pubkey
+    ) -> Result<()> {
+        propose_project_owner_handler(ctx, pending_owner)
+    }
+
+    pub fn accept_project_owner(ctx: Context<AcceptProjectOwner>) -> Result<()> {
+        accept_project_owner_handler(ctx)
+    }
+
+    pub fn set_claim_settings(
+        ctx: Context<SetClaimSettings>,
+        delegate: Pubkey,
+        destination: Pubkey
+    ) -> Result<()> {
+        set_claim_settings_handler(ctx, delegate, destination)
+    }
+
+    // @dev return value is set as return data, read it by simulation
+    pub fn quote_purchase(
+        ctx: Context<QuotePurchase>,
+        purchase_amount: u64
+    ) -> Result<PurchaseQuote> {
+        quote_purchase_handler(ctx, purchase_amount)
+    }
+
+    // @dev return value is set as return data, read it by simulation
+    pub fn quote_claimable(ctx: Context<QuoteClaimable>) -> Result<ClaimQuote> {
+        quote_claimable_handler(ctx)
     }
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::LOCKUP_DURATION;
+
 
 #[account]
 pub struct Buyer {
@@ -11,4 +13,106 @@ pub struct Buyer {
   pub total_amount: u64,
   // @dev claimed amount of ido token
   pub cliamed_amount: u64,
-}
\ No newline at end of file
+  // @dev unix time of latest purchase, starts cooling-off period
+  pub last_purchase_time: i64,
+  // @dev wallet that bought, seed of buyer account
+  pub wallet: Pubkey,
+  // @dev allowed to claim on behalf of wallet, default pubkey if none
+  pub delegate: Pubkey,
+  // @dev owner of destination token account of claims, wallet itself if default pubkey
+  pub destination: Pubkey,
+  // @dev set once refund of unreleased milestones is withdrawn
+  pub milestone_refunded: bool,
+  // @dev unix time of first purchase of current cooling-off period
+  pub cooling_off_start: i64,
+  // @dev paid amount of purchase token within current cooling-off period
+  pub cancellable_purchase: u64,
+  // @dev part of cancellable purchase paid in early pool
+  pub cancellable_early_purchase: u64,
+  // @dev ido token bought within current cooling-off period
+  pub cancellable_amount: u64,
+}
+
+impl Buyer {
+  pub fn claim_destination(&self) -> Pubkey {
+    if self.destination == Pubkey::default() { self.wallet } else { self.destination }
+  }
+
+  // @dev a purchase after the cooling-off period ended opens a new one, only its purchases are cancellable
+  pub fn record_purchase(&mut self, purchase_amount: u64, early_amount: u64, ido_amount: u64, now: i64) {
+    if now > self.cooling_off_start + LOCKUP_DURATION {
+      self.cooling_off_start = now;
+      self.cancellable_purchase = 0;
+      self.cancellable_early_purchase = 0;
+      self.cancellable_amount = 0;
+    }
+    self.cancellable_purchase += purchase_amount;
+    self.cancellable_early_purchase += early_amount;
+    self.cancellable_amount += ido_amount;
+    self.last_purchase_time = now;
+  }
+
+  pub fn is_claim_authority(&self, signer: &Pubkey) -> bool {
+    *signer == self.wallet || (self.delegate != Pubkey::default() && *signer == self.delegate)
+  }
+}
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn buyer() -> Buyer {
+    Buyer {
+      total_purchase: 0,
+      early_purchased: 0,
+      total_amount: 0,
+      cliamed_amount: 0,
+      last_purchase_time: 0,
+      wallet: Pubkey::new_unique(),
+      delegate: Pubkey::default(),
+      destination: Pubkey::default(),
+      milestone_refunded: false,
+      cooling_off_start: 0,
+      cancellable_purchase: 0,
+      cancellable_early_purchase: 0,
+      cancellable_amount: 0,
+    }
+  }
+
+  #[test]
+  fn purchases_within_cooling_off_period_add_up() {
+    let mut buyer: Buyer = buyer();
+    let start: i64 = 1_000_000;
+    buyer.record_purchase(100, 100, 1_000, start);
+    buyer.record_purchase(50, 0, 500, start + LOCKUP_DURATION);
+    assert_eq!(buyer.cooling_off_start, start);
+    assert_eq!(buyer.cancellable_purchase, 150);
+    assert_eq!(buyer.cancellable_early_purchase, 100);
+    assert_eq!(buyer.cancellable_amount, 1_500);
+    assert_eq!(buyer.last_purchase_time, start + LOCKUP_DURATION);
+  }
+
+  #[test]
+  fn purchase_after_cooling_off_period_starts_new_one() {
+    let mut buyer: Buyer = buyer();
+    let start: i64 = 1_000_000;
+    buyer.record_purchase(100, 100, 1_000, start);
+    buyer.record_purchase(1, 0, 10, start + LOCKUP_DURATION + 1);
+    assert_eq!(buyer.cooling_off_start, start + LOCKUP_DURATION + 1);
+    assert_eq!(buyer.cancellable_purchase, 1);
+    assert_eq!(buyer.cancellable_early_purchase, 0);
+    assert_eq!(buyer.cancellable_amount, 10);
+  }
+
+  #[test]
+  fn claims_go_to_wallet_or_destination() {
+    let mut buyer: Buyer = buyer();
+    let delegate: Pubkey = Pubkey::new_unique();
+    assert_eq!(buyer.claim_destination(), buyer.wallet);
+    assert!(buyer.is_claim_authority(&buyer.wallet.clone()));
+    assert!(!buyer.is_claim_authority(&Pubkey::default()));
+    buyer.delegate = delegate;
+    buyer.destination = delegate;
+    assert_eq!(buyer.claim_destination(), delegate);
+    assert!(buyer.is_claim_authority(&delegate));
+  }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+use crate::MAX_CLAIM_RECORDS;
+
+#[account]
+pub struct ClaimHistory {
+    // @dev pool of recorded claims
+    pub pool: Pubkey,
+    // @dev buyer wallet of recorded claims
+    pub wallet: Pubkey,
+    // @dev latest MAX_CLAIM_RECORDS claims through claim_offer in order, crank_claims is not
+    // recorded and shows up in ClaimsCranked events only
+    pub claims: Vec<ClaimRecord>,
+    // @dev wallet that paid rent of claim history, buyer or delegate claiming first
+    pub rent_payer: Pubkey,
+}
+
+impl ClaimHistory {
+    pub fn space(records: usize) -> usize {
+        8 + 32 + 32 + 4 + records.min(MAX_CLAIM_RECORDS) * size_of::<ClaimRecord>() + 32
+    }
+
+    // @dev appends record, dropping the oldest once history is full
+    pub fn record(&mut self, record: ClaimRecord) {
+        if self.claims.len() >= MAX_CLAIM_RECORDS {
+            self.claims.remove(0);
+        }
+        self.claims.push(record);
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimRecord {
+    // @dev unix time of the claim
+    pub claimed_at: i64,
+    // @dev claimed amount of ido token
+    pub amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_keeps_latest_records() {
+        let mut history = ClaimHistory {
+            pool: Pubkey::default(),
+            wallet: Pubkey::default(),
+            claims: Vec::new(),
+            rent_payer: Pubkey::default(),
+        };
+        for claimed_at in 0..(MAX_CLAIM_RECORDS as i64) + 3 {
+            history.record(ClaimRecord { claimed_at, amount: 1 });
+        }
+        assert_eq!(history.claims.len(), MAX_CLAIM_RECORDS);
+        assert_eq!(history.claims[0].claimed_at, 3);
+        assert_eq!(history.claims[MAX_CLAIM_RECORDS - 1].claimed_at, (MAX_CLAIM_RECORDS as i64) + 2);
+        assert_eq!(ClaimHistory::space(MAX_CLAIM_RECORDS + 1), ClaimHistory::space(MAX_CLAIM_RECORDS));
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_AMM_PROGRAMS;
+
+#[account]
+pub struct Config {
+    // @dev guardian allowed to pause and unpause besides pauser role holders
+    pub guardian: Pubkey,
+    // @dev true if all value-moving instructions are paused
+    pub paused: bool,
+    // @dev pda bump of config
+    pub bump: u8,
+    // @dev amm programs pools may seed liquidity into, default pubkey marks an empty slot
+    pub amm_programs: [Pubkey; MAX_AMM_PROGRAMS],
+}
+
+impl Config {
+    pub fn allows_amm_program(&self, amm_program: &Pubkey) -> bool {
+        *amm_program != Pubkey::default() && self.amm_programs.contains(amm_program)
+    }
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct LpLock {
+    // @dev pool that seeded the liquidity
+    pub pool: Pubkey,
+    // @dev lp token mint of amm pool
+    pub lp_mint: Pubkey,
+    // @dev locked amount of lp token
+    pub amount: u64,
+    // @dev unix time pool owner is allowed to withdraw lp token
+    pub unlock_time: i64,
+    // @dev pda bump of lp lock, authority of lp vault
+    pub bump: u8,
+}
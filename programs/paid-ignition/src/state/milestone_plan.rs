@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+use crate::{ MAX_MILESTONES, PERCENTAGE_DENOMINATOR };
+
+#[account]
+pub struct MilestonePlan {
+    // @dev pool whose raise is released by milestones
+    pub pool: Pubkey,
+    // @dev declared milestones ordered by release time, shares sum to 100%
+    pub milestones: Vec<Milestone>,
+    // @dev number of milestones already released to pool owner
+    pub released_count: u8,
+    // @dev released amount of purchase token
+    pub released_amount: u64,
+    // @dev set once buyers approved a refund proposal, blocks further releases
+    pub refund_passed: bool,
+    // @dev unreleased amount of purchase token when refund was approved
+    pub refundable_amount: u64,
+    // @dev outstanding purchase amount when refund was approved, base of pro-rata refunds
+    pub refund_base: u64,
+    // @dev amount of purchase token refunded to buyers
+    pub refunded_amount: u64,
+    // @dev total purchase of refunded buyers, part of refund base already paid out
+    pub refunded_purchase: u64,
+    // @dev payer of milestone plan rent, pool owner or buyer acting on refund
+    pub rent_payer: Pubkey,
+    // @dev pda bump of milestone plan
+    pub bump: u8,
+}
+
+impl MilestonePlan {
+    pub fn space() -> usize {
+        8 + 32 + 4 + MAX_MILESTONES * size_of::<Milestone>() + 1 + 8 + 1 + 8 + 8 + 8 + 8 + 32 + 1
+    }
+
+    // @dev release time strictly increasing, shares non zero and summing to 100%
+    pub fn is_valid(milestones: &[Milestone]) -> bool {
+        if milestones.is_empty() || milestones.len() > MAX_MILESTONES {
+            return false;
+        }
+        let ordered: bool = milestones
+            .windows(2)
+            .all(|pair| pair[0].release_time < pair[1].release_time);
+        let total_share: u64 = milestones
+            .iter()
+            .map(|milestone| milestone.share as u64)
+            .sum();
+        ordered &&
+            milestones.iter().all(|milestone| milestone.share > 0) &&
+            total_share == PERCENTAGE_DENOMINATOR
+    }
+
+    // @dev plan of a pool that never declared milestones, whole raise is released at tge
+    pub fn set_default(&mut self, pool: Pubkey, tge_date: i64, rent_payer: Pubkey, bump: u8) {
+        self.pool = pool;
+        self.milestones = vec![Milestone {
+            release_time: tge_date,
+            share: PERCENTAGE_DENOMINATOR as u16,
+        }];
+        self.rent_payer = rent_payer;
+        self.bump = bump;
+    }
+
+    // @dev purchase token released by milestone at index, last one takes rounding remainder
+    pub fn tranche_amount(&self, index: usize, releasable_amount: u64) -> u64 {
+        if index + 1 == self.milestones.len() {
+            return releasable_amount.saturating_sub(self.released_amount);
+        }
+        (((releasable_amount as u128) * (self.milestones[index].share as u128)) /
+            (PERCENTAGE_DENOMINATOR as u128)) as u64
+    }
+
+    // @dev buyer share of refundable amount, pro-rata to purchase
+    pub fn refund_amount(&self, total_purchase: u64) -> u64 {
+        if self.refund_base == 0 {
+            return 0;
+        }
+        (((self.refundable_amount as u128) * (total_purchase as u128)) /
+            (self.refund_base as u128)) as u64
+    }
+
+    // @dev purchase token still owed to buyers who did not withdraw their refund yet
+    // @dev pro-rata shares round down, so this never falls short of what is left to pay
+    pub fn owed_refund_amount(&self) -> u64 {
+        self.refund_amount(self.refund_base.saturating_sub(self.refunded_purchase))
+    }
+
+    // @dev purchase token still owed to pool owner by milestones, or to buyers once refund approved
+    pub fn unsettled_amount(&self, releasable_amount: u64) -> u64 {
+        if self.refund_passed {
+            self.owed_refund_amount()
+        } else {
+            releasable_amount.saturating_sub(self.released_amount)
+        }
+    }
+
+    // @dev ido token allocation buyer keeps after refund, share backed by released raise
+    pub fn kept_amount(&self, total_amount: u64) -> u64 {
+        total_amount - self.refund_amount(total_amount)
+    }
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct Milestone {
+    // @dev unix time tranche is allowed to be released
+    pub release_time: i64,
+    // @dev share of raise released, based on PERCENTAGE_DENOMINATOR
+    pub share: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(shares: &[u16]) -> MilestonePlan {
+        MilestonePlan {
+            pool: Pubkey::default(),
+            milestones: shares
+                .iter()
+                .enumerate()
+                .map(|(index, share)| Milestone { release_time: index as i64, share: *share })
+                .collect(),
+            released_count: 0,
+            released_amount: 0,
+            refund_passed: false,
+            refundable_amount: 0,
+            refund_base: 0,
+            refunded_amount: 0,
+            refunded_purchase: 0,
+            rent_payer: Pubkey::default(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn milestones_must_be_ordered_and_sum_to_whole() {
+        assert!(MilestonePlan::is_valid(&plan(&[3000, 7000]).milestones));
+        assert!(!MilestonePlan::is_valid(&plan(&[3000, 6999]).milestones));
+        assert!(!MilestonePlan::is_valid(&plan(&[0, 10000]).milestones));
+        assert!(!MilestonePlan::is_valid(&[]));
+        let mut unordered: MilestonePlan = plan(&[5000, 5000]);
+        unordered.milestones[1].release_time = 0;
+        assert!(!MilestonePlan::is_valid(&unordered.milestones));
+        assert!(!MilestonePlan::is_valid(&plan(&[1000; MAX_MILESTONES + 1]).milestones));
+    }
+
+    #[test]
+    fn last_tranche_takes_rounding_remainder() {
+        let mut plan: MilestonePlan = plan(&[3333, 3333, 3334]);
+        let mut tranches: Vec<u64> = Vec::new();
+        for index in 0..plan.milestones.len() {
+            let tranche: u64 = plan.tranche_amount(index, 1_000);
+            plan.released_amount += tranche;
+            tranches.push(tranche);
+        }
+        assert_eq!(tranches, vec![333, 333, 334]);
+        assert_eq!(plan.released_amount, 1_000);
+    }
+
+    #[test]
+    fn refund_is_pro_rata_to_purchase() {
+        let mut plan: MilestonePlan = plan(&[5000, 5000]);
+        assert_eq!(plan.refund_amount(1_000), 0);
+        plan.refundable_amount = 600;
+        plan.refund_base = 1_000;
+        assert_eq!(plan.refund_amount(250), 150);
+        assert_eq!(plan.refund_amount(1_000), 600);
+        // ido allocation shrinks by the same share
+        assert_eq!(plan.kept_amount(2_500), 1_000);
+        assert_eq!(plan.kept_amount(0), 0);
+    }
+
+    #[test]
+    fn refunded_buyer_claims_without_underflow() {
+        let mut plan: MilestonePlan = plan(&[5000, 5000]);
+        plan.refundable_amount = 500;
+        plan.refund_base = 1_000;
+        // buyer claimed 600 of 1_000 before refund, allocation is kept at claimed amount
+        let claimed_amount: u64 = 600;
+        let total_amount: u64 = plan.kept_amount(1_000).max(claimed_amount);
+        assert_eq!(total_amount, 600);
+        let claimable = |now: i64| {
+            crate::calculate_claimable_amount(total_amount, claimed_amount, 2000, 100, 50, 10, 4, now)
+        };
+        assert_eq!(claimable(100), 0);
+        assert_eq!(claimable(160), 0);
+        assert_eq!(claimable(1_000), 0);
+        // buyer who claimed less keeps vesting the smaller allocation
+        let total_amount: u64 = plan.kept_amount(1_000).max(100);
+        assert_eq!(crate::calculate_claimable_amount(total_amount, 100, 2000, 100, 50, 10, 4, 100), 0);
+        assert_eq!(crate::calculate_claimable_amount(total_amount, 100, 2000, 100, 50, 10, 4, 150), 100);
+        assert_eq!(crate::calculate_claimable_amount(total_amount, 100, 2000, 100, 50, 10, 4, 180), 400);
+    }
+
+    #[test]
+    fn unsettled_amount_covers_unpaid_refunds() {
+        let mut plan: MilestonePlan = plan(&[3000, 7000]);
+        plan.released_amount = 300;
+        assert_eq!(plan.unsettled_amount(1_000), 700);
+        plan.refund_passed = true;
+        plan.refundable_amount = 700;
+        plan.refund_base = 3;
+        assert_eq!(plan.unsettled_amount(1_000), 700);
+        // each of three buyers gets 233, one unit of rounding dust is left
+        plan.refunded_purchase = 2;
+        plan.refunded_amount = 466;
+        assert_eq!(plan.unsettled_amount(1_000), 233);
+        plan.refunded_purchase = 3;
+        assert_eq!(plan.unsettled_amount(1_000), 0);
+    }
+}
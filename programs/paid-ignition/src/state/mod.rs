@@ -1,5 +1,31 @@
 pub mod pool;
 pub mod buyer;
+pub mod config;
+pub mod role;
+pub mod tge_history;
+pub mod pool_status;
+pub mod tip_vault;
+pub mod claim_history;
+pub mod unsold_policy;
+pub mod lp_lock;
+pub mod milestone_plan;
+pub mod proposal;
+pub mod tier_table;
+pub mod pool_metadata;
+pub mod project;
 
 pub use pool::*;
-pub use buyer::*;
\ No newline at end of file
+pub use buyer::*;
+pub use config::*;
+pub use role::*;
+pub use tge_history::*;
+pub use pool_status::*;
+pub use tip_vault::*;
+pub use claim_history::*;
+pub use unsold_policy::*;
+pub use lp_lock::*;
+pub use milestone_plan::*;
+pub use proposal::*;
+pub use tier_table::*;
+pub use pool_metadata::*;
+pub use project::*;
@@ -1,9 +1,25 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    error::ErrCode,
+    max_purchase_amount_for_early_access,
+    PoolStatus,
+    UnsoldPolicy,
+    PERCENTAGE_DENOMINATOR,
+};
+
 #[account]
 pub struct Pool {
     // @dev pool owner 
     pub owner: Pubkey,
+    // @dev nominated owner until accepted or cancelled, default if none
+    pub pending_owner: Pubkey,
+    // @dev payer of pool account rent
+    pub rent_payer: Pubkey,
+    // @dev payer of purchase vault rent, default if vault not created
+    pub purchase_vault_payer: Pubkey,
+    // @dev payer of offer vault rent, default if vault not created
+    pub offer_vault_payer: Pubkey,
     // @dev info of purchase token
     pub purchase_currency: PurchaseCurrency,
     // @dev info of ido token
@@ -46,6 +62,8 @@ pub struct Pool {
     pub fund_claimed_amount: u64,
     // @dev unix time of tge date
     pub tge_date: i64,
+    // @dev unix time of tge date at creation, bounds later adjustments
+    pub original_tge_date: i64,
     // @dev tge percentage of ido token
     pub tge_percentage: u16,
     // @dev vesting cliff
@@ -56,12 +74,8 @@ pub struct Pool {
     pub number_of_vesting: i64,
     // @dev total funded amount of ido token
     pub total_funded_amount: u64,
-    // @dev true if collaborator fund enough ido token
-    pub funded: bool,
-    // @dev true if creator allow for user to claim
-    pub claimable: bool,
-    // @dev true if creator cancelled
-    pub emergency_cancelled: bool,
+    // @dev lifecycle status, advanced by time and by operator
+    pub status: PoolStatus,
     // @dev true if private sale
     pub private_raise: bool,
     // @dev bump for authority pda of purchase token account
@@ -70,6 +84,195 @@ pub struct Pool {
     pub offered_bump: u8,
     // @dev allowed updated attempts
     pub tge_update_attempts: u8,
+    // @dev true if guardian paused this pool
+    pub paused: bool,
+    // @dev what happens to unsold ido token after close, chosen at creation
+    pub unsold_policy: UnsoldPolicy,
+    // @dev owner of token account receiving unsold ido token if sent to recipient
+    pub unsold_recipient: Pubkey,
+    // @dev share of raise seeded into amm with matching ido token at sale price
+    pub liquidity_percentage: u16,
+    // @dev constant-product amm program seed_liquidity calls into, default if none
+    pub liquidity_amm_program: Pubkey,
+    // @dev seconds lp token stays locked after seeding
+    pub lp_lock_duration: i64,
+    // @dev true once liquidity is seeded
+    pub liquidity_seeded: bool,
+    // @dev number of proposals opened, index of next one
+    pub proposal_count: u32,
+    // @dev true if update_tge_date needs an approved extend tge proposal
+    pub tge_update_requires_proposal: bool,
+    // @dev seconds after tge buyers who never claimed may return their allocation, zero if off
+    pub protection_window: i64,
+    // @dev share of raise reserved for protection refunds, caps protected volume
+    pub protection_percentage: u16,
+    // @dev purchase token refunded through price protection
+    pub protected_amount: u64,
+    // @dev true once unused protection reserve went back to pool owner
+    pub protection_reserve_reclaimed: bool,
+    // @dev true if early pool allocation follows registered stake tiers
+    pub tiered: bool,
+    // @dev purchase token moved out of purchase vault to pool owner or amm, blocks cancelling
+    pub released_purchase_amount: u64,
+    // @dev unsold ido token already carried out by unsold policy
+    pub unsold_finalized_amount: u64,
+    // @dev ido token allocation given up by buyers through milestone refunds, no longer owed
+    pub forfeited_offer_amount: u64,
+}
+
+impl Pool {
+    // @dev move to next status if allowed by lifecycle
+    pub fn transition(&mut self, next: PoolStatus) -> Result<()> {
+        if !self.status.can_transition_to(next) {
+            return err!(ErrCode::InvalidStatusTransition);
+        }
+        self.status = next;
+        Ok(())
+    }
+
+    // @dev apply all time driven transitions up to now
+    pub fn refresh_status(&mut self, now: i64) -> Result<()> {
+        while
+            let Some(next) = self.status.next_timed(
+                now,
+                self.early_pool_open_time,
+                self.open_pool_open_time,
+                self.open_pool_close_time,
+                self.purchased_amount
+            )
+        {
+            self.transition(next)?;
+        }
+        Ok(())
+    }
+
+    // @dev purchase amount early pool still accepts, capped at its share and at hard cap
+    pub fn remaining_early_amount(&self) -> u64 {
+        self.early_pool_total()
+            .saturating_sub(self.purchased_amount_in_early_access)
+            .min(self.remaining_open_amount())
+    }
+
+    // @dev early pool share of raise, split among registered tiers if tiered
+    pub fn early_pool_total(&self) -> u64 {
+        max_purchase_amount_for_early_access(
+            self.total_raise_amount,
+            self.open_pool_proportion as u64,
+            self.early_pool_proportion as u64
+        )
+    }
+
+    // @dev purchase amount open pool still accepts, unsold early allocation included
+    pub fn remaining_open_amount(&self) -> u64 {
+        self.total_raise_amount.saturating_sub(self.purchased_amount)
+    }
+
+    // @dev ido token owed to buyers, every purchase is converted at rate
+    // @dev allocation returned through price protection or forfeited by refunds is no longer owed
+    pub fn sold_offer_amount(&self) -> u64 {
+        self.outstanding_purchase_amount() * self.offered_currency.rate - self.forfeited_offer_amount
+    }
+
+    // @dev ido token sold but not claimed by buyers yet
+    pub fn owed_offer_amount(&self) -> u64 {
+        self.sold_offer_amount().saturating_sub(self.fund_claimed_amount)
+    }
+
+    // @dev purchase amount still backing buyer allocations, vote and refund weight base
+    pub fn outstanding_purchase_amount(&self) -> u64 {
+        self.purchased_amount - self.protected_amount
+    }
+
+    // @dev purchase token set aside for price protection refunds
+    pub fn protection_reserve(&self) -> u64 {
+        (self.purchased_amount * (self.protection_percentage as u64)) / PERCENTAGE_DENOMINATOR
+    }
+
+    // @dev protection reserve still held in purchase vault, zero once reclaimed
+    pub fn unreclaimed_protection_reserve(&self) -> u64 {
+        if self.protection_reserve_reclaimed {
+            0
+        } else {
+            self.protection_reserve() - self.protected_amount
+        }
+    }
+
+    // @dev true while buyers may return their allocation
+    pub fn in_protection_window(&self, now: i64) -> bool {
+        self.protection_window > 0 && now >= self.tge_date && now < self.protection_end_time()
+    }
+
+    pub fn protection_end_time(&self) -> i64 {
+        self.tge_date + self.protection_window
+    }
+
+    // @dev purchase token seeded into amm, share of actual raise
+    pub fn liquidity_purchase_amount(&self) -> u64 {
+        (self.purchased_amount * (self.liquidity_percentage as u64)) / PERCENTAGE_DENOMINATOR
+    }
+
+    // @dev ido token matching liquidity purchase amount at sale price
+    pub fn liquidity_offer_amount(&self) -> u64 {
+        self.liquidity_purchase_amount() * self.offered_currency.rate
+    }
+
+    // @dev purchase token released to pool owner by milestones, raise net of liquidity and protection
+    pub fn releasable_purchase_amount(&self) -> u64 {
+        self.purchased_amount - self.liquidity_purchase_amount() - self.protection_reserve()
+    }
+
+    // @dev ido token funding must cover for liquidity if the whole raise is sold
+    pub fn liquidity_offer_reserve(&self) -> u64 {
+        ((self.total_raise_amount * (self.liquidity_percentage as u64)) / PERCENTAGE_DENOMINATOR) *
+            self.offered_currency.rate
+    }
+
+    // @dev ido token still owed to buyers and to liquidity if not seeded yet
+    pub fn reserved_offer_amount(&self) -> u64 {
+        if self.liquidity_seeded {
+            self.sold_offer_amount()
+        } else {
+            self.sold_offer_amount() + self.liquidity_offer_amount()
+        }
+    }
+
+    // @dev ido token of raise and liquidity reserve nobody bought, token fee share is kept
+    pub fn unsold_offer_amount(&self) -> u64 {
+        (self.total_raise_amount * self.offered_currency.rate + self.liquidity_offer_reserve())
+            .saturating_sub(self.sold_offer_amount() + self.liquidity_offer_amount())
+    }
+
+    // @dev unsold ido token not carried out yet, grows when allocation is returned after a finalize
+    pub fn pending_unsold_offer_amount(&self) -> u64 {
+        self.unsold_offer_amount()
+            .saturating_sub(self.unsold_finalized_amount)
+            .min(self.total_funded_amount.saturating_sub(self.reserved_offer_amount()))
+    }
+
+    // @dev owner of token account receiving unsold ido token, default if burned
+    pub fn unsold_destination(&self) -> Pubkey {
+        match self.unsold_policy {
+            UnsoldPolicy::ReturnToCreator => self.owner,
+            UnsoldPolicy::Burn => Pubkey::default(),
+            UnsoldPolicy::SendToRecipient => self.unsold_recipient,
+        }
+    }
+
+    // @dev fail unless signer is pool owner or acts through its role
+    pub fn require_authority(&self, signer: &Pubkey, role_held: bool) -> Result<()> {
+        if *signer != self.owner && !role_held {
+            return err!(ErrCode::NotPoolAuthority);
+        }
+        Ok(())
+    }
+
+    // @dev fail unless current status is one of allowed
+    pub fn require_status(&self, allowed: &[PoolStatus]) -> Result<()> {
+        if !allowed.contains(&self.status) {
+            return err!(ErrCode::InvalidPoolStatus);
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -91,3 +294,201 @@ pub struct PurchaseCurrency {
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @dev raise 1_000_000 at rate 10, early pool 40% of raise
+    fn pool() -> Pool {
+        Pool {
+            owner: Pubkey::new_unique(),
+            pending_owner: Pubkey::default(),
+            rent_payer: Pubkey::default(),
+            purchase_vault_payer: Pubkey::default(),
+            offer_vault_payer: Pubkey::default(),
+            purchase_currency: PurchaseCurrency { decimals: 6, mint: Pubkey::default() },
+            offered_currency: OfferedCurrency { rate: 10, decimals: 6, mint: Pubkey::default() },
+            max_purchase_amount_for_early_access: 0,
+            max_purchase_amount_for_kyc_user: 0,
+            max_purchase_amount_for_not_kyc_user: 0,
+            token_fee_percentage: 0,
+            token_fee_cliamed_status: false,
+            early_pool_participation_fee_percentage: 0,
+            open_pool_participation_fee_percentage: 0,
+            open_pool_proportion: 2000,
+            early_pool_proportion: 5000,
+            total_raise_amount: 1_000_000,
+            early_pool_open_time: 10,
+            early_pool_close_time: 20,
+            open_pool_open_time: 20,
+            open_pool_close_time: 30,
+            purchased_amount_in_open_pool: 0,
+            purchased_amount_in_early_access: 0,
+            purchased_amount: 0,
+            fund_claimed_amount: 0,
+            tge_date: 40,
+            original_tge_date: 40,
+            tge_percentage: 0,
+            vesting_cliff: 0,
+            vesting_frequency: 0,
+            number_of_vesting: 0,
+            total_funded_amount: 0,
+            status: PoolStatus::Created,
+            private_raise: false,
+            purchase_bump: 0,
+            offered_bump: 0,
+            tge_update_attempts: 0,
+            paused: false,
+            unsold_policy: UnsoldPolicy::ReturnToCreator,
+            unsold_recipient: Pubkey::default(),
+            liquidity_percentage: 0,
+            liquidity_amm_program: Pubkey::default(),
+            lp_lock_duration: 0,
+            liquidity_seeded: false,
+            proposal_count: 0,
+            tge_update_requires_proposal: false,
+            protection_window: 0,
+            protection_percentage: 0,
+            protected_amount: 0,
+            protection_reserve_reclaimed: false,
+            tiered: false,
+            released_purchase_amount: 0,
+            unsold_finalized_amount: 0,
+            forfeited_offer_amount: 0,
+        }
+    }
+
+    #[test]
+    fn refresh_walks_timed_transitions() {
+        let mut pool: Pool = pool();
+        pool.status = PoolStatus::Funded;
+        pool.refresh_status(9).unwrap();
+        assert_eq!(pool.status, PoolStatus::Funded);
+        pool.refresh_status(20).unwrap();
+        assert_eq!(pool.status, PoolStatus::OpenOpen);
+        pool.purchased_amount = 1;
+        pool.refresh_status(31).unwrap();
+        assert_eq!(pool.status, PoolStatus::Succeeded);
+        assert!(pool.transition(PoolStatus::Funded).is_err());
+        pool.transition(PoolStatus::Claiming).unwrap();
+        assert!(pool.require_status(&[PoolStatus::Claiming, PoolStatus::Finalized]).is_ok());
+        assert!(pool.require_status(&[PoolStatus::Succeeded]).is_err());
+    }
+
+    #[test]
+    fn unfunded_pool_fails_once_early_pool_opens() {
+        let mut pool: Pool = pool();
+        pool.refresh_status(10).unwrap();
+        assert_eq!(pool.status, PoolStatus::Failed);
+    }
+
+    #[test]
+    fn early_amount_is_capped_by_share_and_hard_cap() {
+        let mut pool: Pool = pool();
+        assert_eq!(pool.early_pool_total(), 400_000);
+        pool.purchased_amount_in_early_access = 350_000;
+        pool.purchased_amount = 900_000;
+        assert_eq!(pool.remaining_early_amount(), 50_000);
+        assert_eq!(pool.remaining_open_amount(), 100_000);
+        pool.purchased_amount = 980_000;
+        assert_eq!(pool.remaining_early_amount(), 20_000);
+        pool.purchased_amount = 1_000_000;
+        assert_eq!(pool.remaining_open_amount(), 0);
+    }
+
+    #[test]
+    fn reserves_split_raise_between_liquidity_protection_and_owner() {
+        let mut pool: Pool = pool();
+        pool.purchased_amount = 900_000;
+        pool.liquidity_percentage = 1000;
+        pool.protection_percentage = 500;
+        pool.protected_amount = 10_000;
+        assert_eq!(pool.outstanding_purchase_amount(), 890_000);
+        assert_eq!(pool.sold_offer_amount(), 8_900_000);
+        assert_eq!(pool.liquidity_purchase_amount(), 90_000);
+        assert_eq!(pool.liquidity_offer_amount(), 900_000);
+        assert_eq!(pool.liquidity_offer_reserve(), 1_000_000);
+        assert_eq!(pool.protection_reserve(), 45_000);
+        assert_eq!(pool.releasable_purchase_amount(), 765_000);
+        assert_eq!(pool.reserved_offer_amount(), 9_800_000);
+        pool.liquidity_seeded = true;
+        assert_eq!(pool.reserved_offer_amount(), 8_900_000);
+    }
+
+    #[test]
+    fn unsold_covers_unbought_raise_and_liquidity_reserve() {
+        let mut pool: Pool = pool();
+        assert_eq!(pool.unsold_offer_amount(), 10_000_000);
+        pool.purchased_amount = 900_000;
+        pool.liquidity_percentage = 1000;
+        pool.protected_amount = 10_000;
+        // unbought raise, returned protection and unused liquidity reserve
+        assert_eq!(pool.unsold_offer_amount(), 1_000_000 + 100_000 + 100_000);
+        pool.liquidity_seeded = true;
+        assert_eq!(pool.unsold_offer_amount(), 1_200_000);
+        // allocation forfeited by milestone refunds is unsold again
+        pool.forfeited_offer_amount = 50_000;
+        assert_eq!(pool.sold_offer_amount(), 8_850_000);
+        assert_eq!(pool.unsold_offer_amount(), 1_250_000);
+        pool.forfeited_offer_amount = 0;
+        pool.purchased_amount = 1_000_000;
+        pool.protected_amount = 0;
+        assert_eq!(pool.unsold_offer_amount(), 0);
+    }
+
+    #[test]
+    fn pending_unsold_covers_allocation_returned_after_finalize() {
+        let mut pool: Pool = pool();
+        pool.total_funded_amount = 10_000_000;
+        pool.purchased_amount = 900_000;
+        assert_eq!(pool.pending_unsold_offer_amount(), 1_000_000);
+        // first finalize carries out unbought raise
+        pool.total_funded_amount -= 1_000_000;
+        pool.unsold_finalized_amount = 1_000_000;
+        assert_eq!(pool.pending_unsold_offer_amount(), 0);
+        // allocation returned later is carried out by the next finalize
+        pool.protected_amount = 10_000;
+        pool.forfeited_offer_amount = 50_000;
+        assert_eq!(pool.pending_unsold_offer_amount(), 150_000);
+        // never more than funding left over what buyers are owed
+        pool.total_funded_amount = 8_900_000;
+        assert_eq!(pool.pending_unsold_offer_amount(), 50_000);
+    }
+
+    #[test]
+    fn protection_window_starts_at_tge() {
+        let mut pool: Pool = pool();
+        assert!(!pool.in_protection_window(40));
+        pool.protection_window = 100;
+        assert!(!pool.in_protection_window(39));
+        assert!(pool.in_protection_window(40));
+        assert!(pool.in_protection_window(139));
+        assert!(!pool.in_protection_window(140));
+    }
+
+    #[test]
+    fn owed_amounts_shrink_as_buyers_settle() {
+        let mut pool: Pool = pool();
+        pool.purchased_amount = 900_000;
+        pool.fund_claimed_amount = 1_000_000;
+        assert_eq!(pool.owed_offer_amount(), 8_000_000);
+        pool.protection_percentage = 1000;
+        pool.protected_amount = 10_000;
+        assert_eq!(pool.owed_offer_amount(), 7_900_000);
+        assert_eq!(pool.unreclaimed_protection_reserve(), 80_000);
+        pool.protection_reserve_reclaimed = true;
+        assert_eq!(pool.unreclaimed_protection_reserve(), 0);
+    }
+
+    #[test]
+    fn unsold_destination_follows_policy() {
+        let mut pool: Pool = pool();
+        assert_eq!(pool.unsold_destination(), pool.owner);
+        pool.unsold_policy = UnsoldPolicy::Burn;
+        assert_eq!(pool.unsold_destination(), Pubkey::default());
+        pool.unsold_policy = UnsoldPolicy::SendToRecipient;
+        pool.unsold_recipient = Pubkey::new_unique();
+        assert_eq!(pool.unsold_destination(), pool.unsold_recipient);
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{ MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH, MAX_WEBSITE_LENGTH };
+
+#[account]
+pub struct PoolMetadata {
+    // @dev pool described by metadata
+    pub pool: Pubkey,
+    // @dev display name of project
+    pub name: String,
+    // @dev ticker of ido token
+    pub symbol: String,
+    // @dev off-chain json with description, logo and links
+    pub uri: String,
+    // @dev project website
+    pub website: String,
+    // @dev sha256 of off-chain json, lets clients detect a swapped document
+    pub content_hash: [u8; 32],
+    // @dev pda bump of pool metadata
+    pub bump: u8,
+}
+
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct MetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub website: String,
+    pub content_hash: [u8; 32],
+}
+
+impl PoolMetadata {
+    pub fn space() -> usize {
+        8 +
+            32 +
+            (4 + MAX_NAME_LENGTH) +
+            (4 + MAX_SYMBOL_LENGTH) +
+            (4 + MAX_URI_LENGTH) +
+            (4 + MAX_WEBSITE_LENGTH) +
+            32 +
+            1
+    }
+
+    // @dev name required, every field within its length limit
+    pub fn is_valid(args: &MetadataArgs) -> bool {
+        !args.name.is_empty() &&
+            args.name.len() <= MAX_NAME_LENGTH &&
+            args.symbol.len() <= MAX_SYMBOL_LENGTH &&
+            args.uri.len() <= MAX_URI_LENGTH &&
+            args.website.len() <= MAX_WEBSITE_LENGTH
+    }
+
+    // @dev replace every field, unset ones are left empty
+    pub fn set(&mut self, args: MetadataArgs) {
+        self.name = args.name;
+        self.symbol = args.symbol;
+        self.uri = args.uri;
+        self.website = args.website;
+        self.content_hash = args.content_hash;
+    }
+}
@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum PoolStatus {
+    // @dev created, offer not fully funded yet
+    Created,
+    // @dev offer fully funded, waiting for early pool
+    Funded,
+    // @dev early pool is open
+    EarlyOpen,
+    // @dev open pool is open
+    OpenOpen,
+    // @dev sale closed, outcome not decided yet
+    Closed,
+    // @dev sale closed with purchases
+    Succeeded,
+    // @dev not funded in time or closed without purchases
+    Failed,
+    // @dev cancelled by pool operator
+    Cancelled,
+    // @dev buyers are allowed to claim
+    Claiming,
+    // @dev settled, no further lifecycle changes
+    Finalized,
+}
+
+impl PoolStatus {
+    // @dev single source of truth for allowed lifecycle moves
+    pub fn can_transition_to(self, next: PoolStatus) -> bool {
+        use PoolStatus::*;
+        matches!(
+            (self, next),
+            (Created, Funded) |
+                (Created, Failed) |
+                (Funded, EarlyOpen) |
+                (EarlyOpen, OpenOpen) |
+                (OpenOpen, Closed) |
+                (Closed, Succeeded) |
+                (Closed, Failed) |
+                (Succeeded, Claiming) |
+                (Created | Funded | EarlyOpen | OpenOpen | Closed | Succeeded, Cancelled) |
+                (Claiming | Failed | Cancelled, Finalized)
+        )
+    }
+
+    // @dev next status driven by time alone, none if nothing changes
+    pub fn next_timed(
+        self,
+        now: i64,
+        early_pool_open_time: i64,
+        open_pool_open_time: i64,
+        open_pool_close_time: i64,
+        purchased_amount: u64
+    ) -> Option<PoolStatus> {
+        use PoolStatus::*;
+        match self {
+            Created if now >= early_pool_open_time => Some(Failed),
+            Funded if now >= early_pool_open_time => Some(EarlyOpen),
+            EarlyOpen if now >= open_pool_open_time => Some(OpenOpen),
+            OpenOpen if now > open_pool_close_time => Some(Closed),
+            Closed if purchased_amount > 0 => Some(Succeeded),
+            Closed => Some(Failed),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PoolStatus::{ self, * };
+
+    const ALL: [PoolStatus; 10] = [
+        Created,
+        Funded,
+        EarlyOpen,
+        OpenOpen,
+        Closed,
+        Succeeded,
+        Failed,
+        Cancelled,
+        Claiming,
+        Finalized,
+    ];
+
+    fn allowed_from(status: PoolStatus) -> &'static [PoolStatus] {
+        match status {
+            Created => &[Funded, Failed, Cancelled],
+            Funded => &[EarlyOpen, Cancelled],
+            EarlyOpen => &[OpenOpen, Cancelled],
+            OpenOpen => &[Closed, Cancelled],
+            Closed => &[Succeeded, Failed, Cancelled],
+            Succeeded => &[Claiming, Cancelled],
+            Failed => &[Finalized],
+            Cancelled => &[Finalized],
+            Claiming => &[Finalized],
+            Finalized => &[],
+        }
+    }
+
+    #[test]
+    fn transition_table_is_exhaustive() {
+        for from in ALL {
+            for to in ALL {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    allowed_from(from).contains(&to),
+                    "{:?} -> {:?}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn no_self_transitions() {
+        for status in ALL {
+            assert!(!status.can_transition_to(status));
+        }
+    }
+
+    #[test]
+    fn finalized_is_terminal() {
+        for to in ALL {
+            assert!(!Finalized.can_transition_to(to));
+        }
+    }
+
+    #[test]
+    fn timed_steps_are_allowed_transitions() {
+        for from in ALL {
+            for now in [0, 10, 20, 30, 31] {
+                for purchased_amount in [0, 1] {
+                    if let Some(to) = from.next_timed(now, 10, 20, 30, purchased_amount) {
+                        assert!(from.can_transition_to(to), "{:?} -> {:?}", from, to);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn timed_steps_follow_schedule() {
+        assert_eq!(Created.next_timed(9, 10, 20, 30, 0), None);
+        assert_eq!(Created.next_timed(10, 10, 20, 30, 0), Some(Failed));
+        assert_eq!(Funded.next_timed(9, 10, 20, 30, 0), None);
+        assert_eq!(Funded.next_timed(10, 10, 20, 30, 0), Some(EarlyOpen));
+        assert_eq!(EarlyOpen.next_timed(19, 10, 20, 30, 0), None);
+        assert_eq!(EarlyOpen.next_timed(20, 10, 20, 30, 0), Some(OpenOpen));
+        assert_eq!(OpenOpen.next_timed(30, 10, 20, 30, 0), None);
+        assert_eq!(OpenOpen.next_timed(31, 10, 20, 30, 0), Some(Closed));
+        assert_eq!(Closed.next_timed(31, 10, 20, 30, 1), Some(Succeeded));
+        assert_eq!(Closed.next_timed(31, 10, 20, 30, 0), Some(Failed));
+        for status in [Succeeded, Failed, Cancelled, Claiming, Finalized] {
+            assert_eq!(status.next_timed(i64::MAX, 10, 20, 30, 1), None);
+        }
+    }
+}
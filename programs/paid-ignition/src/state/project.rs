@@ -0,0 +1,322 @@
+use anchor_lang::{ prelude::*, solana_program::keccak };
+use std::mem::size_of;
+
+use crate::{ calculate_claimable_amount, MAX_ROUNDS, PERCENTAGE_DENOMINATOR };
+
+#[account]
+pub struct Project {
+    // @dev project owner
+    pub owner: Pubkey,
+    // @dev nominated owner until accepted, default if none
+    pub pending_owner: Pubkey,
+    // @dev payer of project account rent
+    pub rent_payer: Pubkey,
+    // @dev payer of purchase vault rent, first buyer
+    pub purchase_vault_payer: Pubkey,
+    // @dev payer of offer vault rent, first funder
+    pub offer_vault_payer: Pubkey,
+    // @dev mint of purchase token, shared by all rounds
+    pub purchase_mint: Pubkey,
+    // @dev mint of ido token, all rounds draw from one offer vault
+    pub offer_mint: Pubkey,
+    // @dev ido token deposited into offer vault, less withdrawn excess
+    pub total_funded_amount: u64,
+    // @dev ido token sold across all rounds, owed to buyers
+    pub sold_offer_amount: u64,
+    // @dev rounds ordered by open time, each opening after the previous one closed
+    pub rounds: Vec<Round>,
+    // @dev bump of purchase vault
+    pub purchase_bump: u8,
+    // @dev bump of offer vault
+    pub offer_bump: u8,
+}
+
+impl Project {
+    pub fn space() -> usize {
+        8 + 32 + 32 + 32 + 32 + 32 + 32 + 32 + 8 + 8 + 4 + MAX_ROUNDS * size_of::<Round>() + 1 + 1
+    }
+
+    // @dev unsold ido token, free to withdraw once the last round closed
+    pub fn excess_offer_amount(&self) -> u64 {
+        self.total_funded_amount.saturating_sub(self.sold_offer_amount)
+    }
+
+    // @dev true once every round is closed
+    pub fn is_closed(&self, now: i64) -> bool {
+        matches!(self.rounds.last(), Some(round) if now >= round.close_time)
+    }
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct Round {
+    // @dev amount of ido token for 1 purchase token
+    pub rate: u64,
+    // @dev hard cap of round, based on purchase token
+    pub max_raise_amount: u64,
+    // @dev max purchase per wallet, based on purchase token, uncapped if zero
+    pub max_purchase_per_wallet: u64,
+    pub open_time: i64,
+    pub close_time: i64,
+    // @dev who may buy in round
+    pub eligibility: Eligibility,
+    // @dev release schedule of ido token bought in round
+    pub vesting: Vesting,
+    // @dev purchase token raised in round
+    pub purchased_amount: u64,
+    // @dev set once raise is withdrawn by owner
+    pub raise_withdrawn: bool,
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct RoundParams {
+    pub rate: u64,
+    pub max_raise_amount: u64,
+    pub max_purchase_per_wallet: u64,
+    pub open_time: i64,
+    pub close_time: i64,
+    pub eligibility: Eligibility,
+    pub vesting: Vesting,
+}
+
+impl Round {
+    pub fn new(params: RoundParams) -> Self {
+        Self {
+            rate: params.rate,
+            max_raise_amount: params.max_raise_amount,
+            max_purchase_per_wallet: params.max_purchase_per_wallet,
+            open_time: params.open_time,
+            close_time: params.close_time,
+            eligibility: params.eligibility,
+            vesting: params.vesting,
+            purchased_amount: 0,
+            raise_withdrawn: false,
+        }
+    }
+
+    // @dev round opens in the future after previous round closed, vesting starts once it closes
+    pub fn is_valid(&self, previous: Option<&Round>, now: i64) -> bool {
+        self.rate > 0 &&
+            self.max_raise_amount > 0 &&
+            self.max_purchase_per_wallet <= self.max_raise_amount &&
+            self.open_time >= now &&
+            self.open_time < self.close_time &&
+            !matches!(previous, Some(previous) if self.open_time < previous.close_time) &&
+            self.vesting.is_valid(self.close_time)
+    }
+
+    pub fn is_open(&self, now: i64) -> bool {
+        now >= self.open_time && now < self.close_time
+    }
+
+    // @dev largest purchase up to requested one within round and wallet caps
+    pub fn fill_purchase_amount(&self, purchase_amount: u64, wallet_purchased: u64) -> u64 {
+        let mut remaining_amount: u64 = self.max_raise_amount - self.purchased_amount;
+        if self.max_purchase_per_wallet > 0 {
+            remaining_amount = remaining_amount.min(
+                self.max_purchase_per_wallet.saturating_sub(wallet_purchased)
+            );
+        }
+        purchase_amount.min(remaining_amount)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum Eligibility {
+    Public,
+    // @dev stake held in paidStake pool since round opened
+    Stake {
+        stake_pool: Pubkey,
+        min_stake: u64,
+    },
+    // @dev leaf keccak(wallet base58, authority) appended to kycMerkleTree tree by authority
+    Kyc {
+        merkle_tree: Pubkey,
+        authority: Pubkey,
+    },
+    // @dev wallet in merkle tree of keccak(wallet) leaves, sibling pairs hashed in sorted order
+    Allowlist {
+        merkle_root: [u8; 32],
+    },
+}
+
+// @dev proof of eligibility passed with purchase
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub enum EligibilityProof {
+    None,
+    // @dev proof nodes of kyc leaf are passed as remaining accounts
+    Kyc {
+        root: [u8; 32],
+        index: u32,
+    },
+    Allowlist {
+        proof: Vec<[u8; 32]>,
+    },
+}
+
+impl Eligibility {
+    // @dev leaf kyc authority appended for wallet
+    pub fn kyc_leaf(wallet: &Pubkey, authority: &Pubkey) -> [u8; 32] {
+        keccak::hashv(&[wallet.to_string().as_bytes(), authority.as_ref()]).to_bytes()
+    }
+
+    pub fn verify_allowlist(merkle_root: &[u8; 32], proof: &[[u8; 32]], wallet: &Pubkey) -> bool {
+        let leaf: [u8; 32] = keccak::hash(wallet.as_ref()).to_bytes();
+        let root: [u8; 32] = proof.iter().fold(leaf, |node, sibling| {
+            if node <= *sibling {
+                keccak::hashv(&[&node, sibling]).to_bytes()
+            } else {
+                keccak::hashv(&[sibling, &node]).to_bytes()
+            }
+        });
+        root == *merkle_root
+    }
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct Vesting {
+    pub tge_date: i64,
+    // @dev unlocked at tge, based on PERCENTAGE_DENOMINATOR
+    pub tge_percentage: u16,
+    pub vesting_cliff: i64,
+    pub vesting_frequency: i64,
+    pub number_of_vesting: i64,
+}
+
+impl Vesting {
+    pub fn is_valid(&self, close_time: i64) -> bool {
+        self.tge_date >= close_time &&
+            (self.tge_percentage as u64) <= PERCENTAGE_DENOMINATOR &&
+            self.vesting_cliff >= 0 &&
+            self.vesting_frequency > 0 &&
+            self.number_of_vesting > 0
+    }
+
+    pub fn claimable_amount(&self, total_amount: u64, claimed_amount: u64, now: i64) -> u64 {
+        if now < self.tge_date {
+            return 0;
+        }
+        calculate_claimable_amount(
+            total_amount,
+            claimed_amount,
+            self.tge_percentage,
+            self.tge_date,
+            self.vesting_cliff,
+            self.vesting_frequency as u64,
+            self.number_of_vesting as u64,
+            now
+        )
+    }
+}
+
+#[account]
+pub struct RoundBuyer {
+    // @dev project bought into
+    pub project: Pubkey,
+    // @dev buyer wallet
+    pub wallet: Pubkey,
+    // @dev index of round in project
+    pub round: u8,
+    // @dev purchase token paid in round
+    pub purchase_amount: u64,
+    // @dev ido token bought in round
+    pub offer_amount: u64,
+    // @dev ido token claimed from round
+    pub claimed_amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round(open_time: i64, close_time: i64) -> Round {
+        Round::new(RoundParams {
+            rate: 10,
+            max_raise_amount: 1_000,
+            max_purchase_per_wallet: 300,
+            open_time,
+            close_time,
+            eligibility: Eligibility::Public,
+            vesting: Vesting {
+                tge_date: close_time,
+                tge_percentage: 5000,
+                vesting_cliff: 0,
+                vesting_frequency: 10,
+                number_of_vesting: 2,
+            },
+        })
+    }
+
+    #[test]
+    fn rounds_follow_each_other() {
+        let seed: Round = round(100, 200);
+        assert!(seed.is_valid(None, 100));
+        assert!(!seed.is_valid(None, 101));
+        assert!(round(200, 300).is_valid(Some(&seed), 0));
+        assert!(!round(199, 300).is_valid(Some(&seed), 0));
+        assert!(!round(200, 200).is_valid(Some(&seed), 0));
+        let mut early_tge: Round = round(200, 300);
+        early_tge.vesting.tge_date = 299;
+        assert!(!early_tge.is_valid(Some(&seed), 0));
+        assert!(seed.is_open(100) && seed.is_open(199));
+        assert!(!seed.is_open(99) && !seed.is_open(200));
+    }
+
+    #[test]
+    fn fill_respects_round_and_wallet_caps() {
+        let mut round: Round = round(100, 200);
+        assert_eq!(round.fill_purchase_amount(500, 0), 300);
+        assert_eq!(round.fill_purchase_amount(500, 250), 50);
+        assert_eq!(round.fill_purchase_amount(500, 300), 0);
+        round.purchased_amount = 950;
+        assert_eq!(round.fill_purchase_amount(500, 0), 50);
+        round.max_purchase_per_wallet = 0;
+        round.purchased_amount = 0;
+        assert_eq!(round.fill_purchase_amount(5_000, 10_000), 1_000);
+    }
+
+    #[test]
+    fn rounds_vest_from_their_own_tge() {
+        let vesting: Vesting = round(100, 200).vesting;
+        assert_eq!(vesting.claimable_amount(1_000, 0, 199), 0);
+        assert_eq!(vesting.claimable_amount(1_000, 0, 200), 750);
+        assert_eq!(vesting.claimable_amount(1_000, 750, 210), 250);
+    }
+
+    #[test]
+    fn project_closes_with_last_round() {
+        let mut project: Project = Project {
+            owner: Pubkey::default(),
+            pending_owner: Pubkey::default(),
+            rent_payer: Pubkey::default(),
+            purchase_vault_payer: Pubkey::default(),
+            offer_vault_payer: Pubkey::default(),
+            purchase_mint: Pubkey::default(),
+            offer_mint: Pubkey::default(),
+            total_funded_amount: 20_000,
+            sold_offer_amount: 15_000,
+            rounds: Vec::new(),
+            purchase_bump: 0,
+            offer_bump: 0,
+        };
+        assert!(!project.is_closed(i64::MAX));
+        project.rounds = vec![round(100, 200), round(200, 300)];
+        assert!(!project.is_closed(299));
+        assert!(project.is_closed(300));
+        assert_eq!(project.excess_offer_amount(), 5_000);
+    }
+
+    #[test]
+    fn allowlist_proof_hashes_sorted_pairs() {
+        let (wallet, other): (Pubkey, Pubkey) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let leaf: [u8; 32] = keccak::hash(wallet.as_ref()).to_bytes();
+        let sibling: [u8; 32] = keccak::hash(other.as_ref()).to_bytes();
+        let root: [u8; 32] = if leaf <= sibling {
+            keccak::hashv(&[&leaf, &sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[&sibling, &leaf]).to_bytes()
+        };
+        assert!(Eligibility::verify_allowlist(&root, &[sibling], &wallet));
+        assert!(Eligibility::verify_allowlist(&root, &[leaf], &other));
+        assert!(!Eligibility::verify_allowlist(&root, &[sibling], &other));
+    }
+}
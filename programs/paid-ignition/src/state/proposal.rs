@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::PERCENTAGE_DENOMINATOR;
+
+#[account]
+pub struct Proposal {
+    // @dev pool voted on
+    pub pool: Pubkey,
+    // @dev index among proposals of pool, seed of proposal account
+    pub index: u32,
+    // @dev action buyers vote on
+    pub kind: ProposalKind,
+    // @dev pool owner, super admin or buyer asking for refund who opened the proposal
+    pub proposer: Pubkey,
+    // @dev unix time voting ends
+    pub deadline: i64,
+    // @dev share of purchased amount that must vote, based on PERCENTAGE_DENOMINATOR
+    pub quorum: u16,
+    // @dev buyer purchase amount voted for
+    pub votes_for: u64,
+    // @dev buyer purchase amount voted against
+    pub votes_against: u64,
+    // @dev outcome, recorded by finalize_proposal after deadline
+    pub status: ProposalStatus,
+    // @dev set once an instruction acted on the approved proposal
+    pub executed: bool,
+    // @dev pda bump of proposal
+    pub bump: u8,
+}
+
+impl Proposal {
+    // @dev approved if turnout reaches quorum and majority voted for
+    pub fn outcome(&self, purchased_amount: u64) -> ProposalStatus {
+        let turnout: u128 = (self.votes_for as u128) + (self.votes_against as u128);
+        let quorum_reached: bool =
+            turnout * (PERCENTAGE_DENOMINATOR as u128) >=
+            (purchased_amount as u128) * (self.quorum as u128);
+        if quorum_reached && self.votes_for > self.votes_against {
+            ProposalStatus::Approved
+        } else {
+            ProposalStatus::Rejected
+        }
+    }
+
+    // @dev true if approved for given action and not acted on yet
+    pub fn approves(&self, kind: &ProposalKind) -> bool {
+        self.status == ProposalStatus::Approved && !self.executed && self.kind == *kind
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum ProposalKind {
+    // @dev move tge date to given unix time
+    ExtendTge {
+        tge_date: i64,
+    },
+    // @dev refund unreleased raise to buyers and stop milestone releases
+    RefundMilestones,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum ProposalStatus {
+    // @dev open for votes until deadline
+    Active,
+    Approved,
+    Rejected,
+}
+
+#[account]
+pub struct ProposalVote {
+    // @dev proposal voted on
+    pub proposal: Pubkey,
+    // @dev buyer wallet that voted, one vote per wallet
+    pub wallet: Pubkey,
+    // @dev vote weight, total purchase of buyer at vote time
+    pub weight: u64,
+    // @dev true if voted for
+    pub approve: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proposal(votes_for: u64, votes_against: u64) -> Proposal {
+        Proposal {
+            pool: Pubkey::default(),
+            index: 0,
+            kind: ProposalKind::ExtendTge { tge_date: 100 },
+            proposer: Pubkey::default(),
+            deadline: 0,
+            quorum: 2000,
+            votes_for,
+            votes_against,
+            status: ProposalStatus::Active,
+            executed: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn outcome_needs_quorum_and_majority() {
+        assert_eq!(proposal(150, 49).outcome(1_000), ProposalStatus::Rejected);
+        assert_eq!(proposal(150, 50).outcome(1_000), ProposalStatus::Approved);
+        assert_eq!(proposal(100, 100).outcome(1_000), ProposalStatus::Rejected);
+        assert_eq!(proposal(0, 0).outcome(0), ProposalStatus::Rejected);
+        assert_eq!(proposal(u64::MAX, 0).outcome(u64::MAX), ProposalStatus::Approved);
+    }
+
+    #[test]
+    fn approval_is_for_one_unexecuted_action() {
+        let mut proposal: Proposal = proposal(150, 50);
+        let kind: ProposalKind = ProposalKind::ExtendTge { tge_date: 100 };
+        assert!(!proposal.approves(&kind));
+        proposal.status = proposal.outcome(1_000);
+        assert!(proposal.approves(&kind));
+        assert!(!proposal.approves(&(ProposalKind::ExtendTge { tge_date: 101 })));
+        assert!(!proposal.approves(&ProposalKind::RefundMilestones));
+        proposal.executed = true;
+        assert!(!proposal.approves(&kind));
+    }
+}
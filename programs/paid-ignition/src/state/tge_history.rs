@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+use crate::MAX_TGE_DATE_ADJUSTMENT_ATTEMPTS;
+
+#[account]
+pub struct TgeHistory {
+    // @dev pool of recorded changes
+    pub pool: Pubkey,
+    // @dev tge date changes in order, bounded by allowed attempts
+    pub changes: Vec<TgeDateChange>,
+}
+
+impl TgeHistory {
+    pub const SPACE: usize =
+        8 + 32 + 4 + (MAX_TGE_DATE_ADJUSTMENT_ATTEMPTS as usize) * size_of::<TgeDateChange>();
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct TgeDateChange {
+    // @dev tge date before the change
+    pub previous_tge_date: i64,
+    // @dev tge date after the change
+    pub new_tge_date: i64,
+    // @dev unix time of the change
+    pub updated_at: i64,
+}
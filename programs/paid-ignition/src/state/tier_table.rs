@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+use crate::MAX_TIERS;
+
+#[account]
+pub struct TierTable {
+    // @dev pool whose early pool is allocated by tier
+    pub pool: Pubkey,
+    // @dev paidStake pool whose stakers qualify for tiers
+    pub stake_pool: Pubkey,
+    // @dev tiers ordered by stake threshold, highest qualifying one applies
+    pub tiers: Vec<Tier>,
+    // @dev unix time stake is measured at, before early pool opens
+    pub snapshot_time: i64,
+    // @dev true if stake must be held from snapshot time until registration
+    pub continuous_stake: bool,
+    // @dev sum of weights of registered wallets
+    pub total_registered_weight: u64,
+    // @dev number of registered wallets, table is fixed once non zero
+    pub registered_count: u64,
+    // @dev payer of tier table rent
+    pub rent_payer: Pubkey,
+    // @dev pda bump of tier table
+    pub bump: u8,
+}
+
+impl TierTable {
+    pub fn space() -> usize {
+        8 + 32 + 32 + 4 + MAX_TIERS * size_of::<Tier>() + 8 + 1 + 8 + 8 + 32 + 1
+    }
+
+    // @dev stake threshold strictly increasing, weights non zero
+    pub fn is_valid(tiers: &[Tier]) -> bool {
+        !tiers.is_empty() &&
+            tiers.len() <= MAX_TIERS &&
+            tiers.windows(2).all(|pair| pair[0].threshold < pair[1].threshold) &&
+            tiers.iter().all(|tier| tier.weight > 0)
+    }
+
+    // @dev index of highest tier stake amount qualifies for
+    pub fn tier_of(&self, staked_amount: u64) -> Option<usize> {
+        self.tiers.iter().rposition(|tier| staked_amount >= tier.threshold)
+    }
+
+    // @dev early pool allocation of registered weight
+    pub fn allocation(&self, early_pool_total: u64, weight: u16) -> u64 {
+        if self.total_registered_weight == 0 {
+            return 0;
+        }
+        (((early_pool_total as u128) * (weight as u128)) /
+            (self.total_registered_weight as u128)) as u64
+    }
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct Tier {
+    // @dev minimum staked amount, based on stake token
+    pub threshold: u64,
+    // @dev allocation weight relative to other registered wallets
+    pub weight: u16,
+}
+
+#[account]
+pub struct TierRegistration {
+    // @dev pool registered for
+    pub pool: Pubkey,
+    // @dev registered wallet
+    pub wallet: Pubkey,
+    // @dev index of locked in tier
+    pub tier: u8,
+    // @dev weight of locked in tier
+    pub weight: u16,
+    // @dev staked amount tier was derived from
+    pub staked_amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(tiers: &[(u64, u16)]) -> TierTable {
+        TierTable {
+            pool: Pubkey::default(),
+            stake_pool: Pubkey::default(),
+            tiers: tiers
+                .iter()
+                .map(|(threshold, weight)| Tier { threshold: *threshold, weight: *weight })
+                .collect(),
+            snapshot_time: 0,
+            continuous_stake: false,
+            total_registered_weight: 0,
+            registered_count: 0,
+            rent_payer: Pubkey::default(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn tiers_must_increase_with_weight() {
+        assert!(TierTable::is_valid(&table(&[(100, 1), (1_000, 3)]).tiers));
+        assert!(!TierTable::is_valid(&table(&[(1_000, 1), (100, 3)]).tiers));
+        assert!(!TierTable::is_valid(&table(&[(100, 1), (100, 3)]).tiers));
+        assert!(!TierTable::is_valid(&table(&[(100, 0)]).tiers));
+        assert!(!TierTable::is_valid(&[]));
+        let too_many: Vec<(u64, u16)> = (1..=(MAX_TIERS as u64) + 1).map(|n| (n, 1)).collect();
+        assert!(!TierTable::is_valid(&table(&too_many).tiers));
+    }
+
+    #[test]
+    fn highest_qualifying_tier_applies() {
+        let table: TierTable = table(&[(100, 1), (1_000, 3), (10_000, 10)]);
+        assert_eq!(table.tier_of(99), None);
+        assert_eq!(table.tier_of(100), Some(0));
+        assert_eq!(table.tier_of(9_999), Some(1));
+        assert_eq!(table.tier_of(u64::MAX), Some(2));
+    }
+
+    #[test]
+    fn allocation_splits_early_pool_by_weight() {
+        let mut table: TierTable = table(&[(100, 1), (1_000, 3)]);
+        assert_eq!(table.allocation(1_000, 1), 0);
+        table.total_registered_weight = 5;
+        assert_eq!(table.allocation(1_000, 3), 600);
+        assert_eq!(table.allocation(1_000, 1), 200);
+        assert_eq!(table.allocation(u64::MAX, 5), u64::MAX);
+    }
+}
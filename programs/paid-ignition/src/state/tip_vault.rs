@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct TipVault {
+    // @dev pool paying the tips
+    pub pool: Pubkey,
+    // @dev lamports paid to cranker per pushed claim
+    pub tip_per_claim: u64,
+    // @dev pda bump of tip vault
+    pub bump: u8,
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum UnsoldPolicy {
+    // @dev unsold ido token goes back to pool owner
+    ReturnToCreator,
+    // @dev unsold ido token is burned
+    Burn,
+    // @dev unsold ido token goes to configured recipient, e.g. liquidity or ecosystem wallet
+    SendToRecipient,
+}
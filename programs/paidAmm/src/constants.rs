@@ -0,0 +1,59 @@
+pub const FEE_DENOMINATOR: u128 = 10000;
+// @dev swap fee kept in reserves, 0.3%
+pub const SWAP_FEE: u128 = 30;
+pub const LP_DECIMALS: u8 = 6;
+
+// @dev lp minted for a deposit, geometric mean for the first one, pro rata after
+pub fn lp_amount(amount_a: u64, amount_b: u64, reserve_a: u64, reserve_b: u64, supply: u64) -> u64 {
+    if supply == 0 {
+        return integer_sqrt((amount_a as u128) * (amount_b as u128)) as u64;
+    }
+    let lp_a: u128 = ((amount_a as u128) * (supply as u128)) / (reserve_a as u128);
+    let lp_b: u128 = ((amount_b as u128) * (supply as u128)) / (reserve_b as u128);
+    lp_a.min(lp_b) as u64
+}
+
+// @dev output of constant-product swap after fee
+pub fn swap_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> u64 {
+    let amount_in_with_fee: u128 = (amount_in as u128) * (FEE_DENOMINATOR - SWAP_FEE);
+    let numerator: u128 = amount_in_with_fee * (reserve_out as u128);
+    let denominator: u128 = (reserve_in as u128) * FEE_DENOMINATOR + amount_in_with_fee;
+    (numerator / denominator) as u64
+}
+
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x: u128 = value;
+    let mut y: u128 = (x >> 1) + (x & 1);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_deposit_mints_geometric_mean() {
+        assert_eq!(lp_amount(100, 400, 0, 0, 0), 200);
+        assert_eq!(lp_amount(2, 3, 0, 0, 0), 2);
+    }
+
+    #[test]
+    fn later_deposit_mints_smaller_share() {
+        assert_eq!(lp_amount(10, 40, 100, 400, 200), 20);
+        assert_eq!(lp_amount(10, 80, 100, 400, 200), 20);
+    }
+
+    #[test]
+    fn swap_keeps_product() {
+        let out: u64 = swap_amount_out(1_000, 100_000, 100_000);
+        assert_eq!(out, 987);
+        assert!((100_000u128 + 1_000) * (100_000u128 - (out as u128)) >= 100_000u128 * 100_000);
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrCode {
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Slippage exceeded")]
+    SlippageExceeded,
+    #[msg("Invalid mint")]
+    InvalidMint,
+}
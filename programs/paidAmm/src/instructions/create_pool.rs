@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ Mint, Token, TokenAccount };
+use std::mem::size_of;
+
+use crate::{ constants::LP_DECIMALS, error::ErrCode, state::AmmPool };
+
+#[derive(Accounts)]
+pub struct CreatePool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint_a: Box<Account<'info, Mint>>,
+    #[account(constraint = mint_b.key() != mint_a.key() @ ErrCode::InvalidMint)]
+    pub mint_b: Box<Account<'info, Mint>>,
+
+    // @dev one pool per ordered mint pair
+    #[account(
+        init,
+        payer = payer,
+        space = size_of::<AmmPool>() + 8,
+        seeds = [b"amm-pool", mint_a.key().as_ref(), mint_b.key().as_ref()],
+        bump
+    )]
+    pub amm_pool: Box<Account<'info, AmmPool>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"vault", amm_pool.key().as_ref(), mint_a.key().as_ref()],
+        bump,
+        token::mint = mint_a,
+        token::authority = amm_pool
+    )]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"vault", amm_pool.key().as_ref(), mint_b.key().as_ref()],
+        bump,
+        token::mint = mint_b,
+        token::authority = amm_pool
+    )]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"lp-mint", amm_pool.key().as_ref()],
+        bump,
+        mint::decimals = LP_DECIMALS,
+        mint::authority = amm_pool
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// @dev anyone is allowed to open an empty pool for a mint pair
+pub fn create_pool_handler(ctx: Context<CreatePool>) -> Result<()> {
+    let amm_pool: &mut Box<Account<AmmPool>> = &mut ctx.accounts.amm_pool;
+    amm_pool.mint_a = ctx.accounts.mint_a.key();
+    amm_pool.mint_b = ctx.accounts.mint_b.key();
+    amm_pool.bump = ctx.bumps.amm_pool;
+    msg!("Created amm pool");
+    Ok(())
+}
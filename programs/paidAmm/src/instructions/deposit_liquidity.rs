@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Mint, MintTo, Token, TokenAccount, Transfer };
+
+use crate::{ constants::lp_amount, error::ErrCode, state::AmmPool };
+
+// @dev account order is the liquidity interface paid-ignition calls into
+#[derive(Accounts)]
+pub struct DepositLiquidity<'info> {
+    // @dev owner of source_a, may be the same key as authority_b
+    pub authority_a: Signer<'info>,
+    // @dev owner of source_b
+    pub authority_b: Signer<'info>,
+
+    #[account(mut, token::mint = amm_pool.mint_a)]
+    pub source_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut, token::mint = amm_pool.mint_b)]
+    pub source_b: Box<Account<'info, TokenAccount>>,
+
+    // @dev receives minted lp token
+    #[account(mut, token::mint = lp_mint)]
+    pub lp_destination: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"amm-pool", amm_pool.mint_a.as_ref(), amm_pool.mint_b.as_ref()],
+        bump = amm_pool.bump
+    )]
+    pub amm_pool: Box<Account<'info, AmmPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", amm_pool.key().as_ref(), amm_pool.mint_a.as_ref()],
+        bump
+    )]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"vault", amm_pool.key().as_ref(), amm_pool.mint_b.as_ref()],
+        bump
+    )]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"lp-mint", amm_pool.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> DepositLiquidity<'info> {
+    fn transfer_ctx(
+        &self,
+        from: &Account<'info, TokenAccount>,
+        to: &Account<'info, TokenAccount>,
+        authority: &Signer<'info>
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), Transfer {
+            from: from.to_account_info(),
+            to: to.to_account_info(),
+            authority: authority.to_account_info(),
+        })
+    }
+}
+
+// @dev takes both amounts in full, deposit at pool ratio to not donate the excess
+pub fn deposit_liquidity_handler(
+    ctx: Context<DepositLiquidity>,
+    amount_a: u64,
+    amount_b: u64,
+    min_lp: u64
+) -> Result<()> {
+    if amount_a == 0 || amount_b == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+    let accounts: &DepositLiquidity = ctx.accounts;
+    let lp: u64 = lp_amount(
+        amount_a,
+        amount_b,
+        accounts.vault_a.amount,
+        accounts.vault_b.amount,
+        accounts.lp_mint.supply
+    );
+    if lp == 0 || lp < min_lp {
+        return err!(ErrCode::SlippageExceeded);
+    }
+    token::transfer(
+        accounts.transfer_ctx(&accounts.source_a, &accounts.vault_a, &accounts.authority_a),
+        amount_a
+    )?;
+    token::transfer(
+        accounts.transfer_ctx(&accounts.source_b, &accounts.vault_b, &accounts.authority_b),
+        amount_b
+    )?;
+
+    // seeds of amm pool, authority of lp mint
+    let amm_pool: &Account<AmmPool> = &accounts.amm_pool;
+    let seeds: &[&[u8]; 4] = &[
+        b"amm-pool",
+        amm_pool.mint_a.as_ref(),
+        amm_pool.mint_b.as_ref(),
+        &[amm_pool.bump],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    let mint_ctx = CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        MintTo {
+            mint: accounts.lp_mint.to_account_info(),
+            to: accounts.lp_destination.to_account_info(),
+            authority: accounts.amm_pool.to_account_info(),
+        },
+        signer
+    );
+    token::mint_to(mint_ctx, lp)?;
+    msg!("Deposited liquidity");
+    Ok(())
+}
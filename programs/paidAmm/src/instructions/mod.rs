@@ -0,0 +1,7 @@
+pub mod create_pool;
+pub mod deposit_liquidity;
+pub mod swap;
+
+pub use create_pool::*;
+pub use deposit_liquidity::*;
+pub use swap::*;
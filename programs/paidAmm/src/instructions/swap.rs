@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, Token, TokenAccount, Transfer };
+
+use crate::{ constants::swap_amount_out, error::ErrCode, state::AmmPool };
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub user: Signer<'info>,
+
+    // @dev token account of user paying amount in
+    #[account(mut)]
+    pub user_source: Box<Account<'info, TokenAccount>>,
+    // @dev token account of user receiving amount out
+    #[account(mut)]
+    pub user_destination: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"amm-pool", amm_pool.mint_a.as_ref(), amm_pool.mint_b.as_ref()],
+        bump = amm_pool.bump
+    )]
+    pub amm_pool: Box<Account<'info, AmmPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", amm_pool.key().as_ref(), amm_pool.mint_a.as_ref()],
+        bump
+    )]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"vault", amm_pool.key().as_ref(), amm_pool.mint_b.as_ref()],
+        bump
+    )]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// @dev constant-product swap in either direction, fee stays in reserves
+pub fn swap_handler(ctx: Context<Swap>, amount_in: u64, min_out: u64, a_to_b: bool) -> Result<()> {
+    if amount_in == 0 {
+        return err!(ErrCode::InvalidAmount);
+    }
+    let accounts: &Swap = ctx.accounts;
+    let (vault_in, vault_out) = if a_to_b {
+        (&accounts.vault_a, &accounts.vault_b)
+    } else {
+        (&accounts.vault_b, &accounts.vault_a)
+    };
+    if accounts.user_source.mint != vault_in.mint || accounts.user_destination.mint != vault_out.mint {
+        return err!(ErrCode::InvalidMint);
+    }
+    let amount_out: u64 = swap_amount_out(amount_in, vault_in.amount, vault_out.amount);
+    if amount_out == 0 || amount_out < min_out {
+        return err!(ErrCode::SlippageExceeded);
+    }
+
+    let transfer_in = CpiContext::new(accounts.token_program.to_account_info(), Transfer {
+        from: accounts.user_source.to_account_info(),
+        to: vault_in.to_account_info(),
+        authority: accounts.user.to_account_info(),
+    });
+    token::transfer(transfer_in, amount_in)?;
+
+    // seeds of amm pool, authority of vaults
+    let amm_pool: &Account<AmmPool> = &accounts.amm_pool;
+    let seeds: &[&[u8]; 4] = &[
+        b"amm-pool",
+        amm_pool.mint_a.as_ref(),
+        amm_pool.mint_b.as_ref(),
+        &[amm_pool.bump],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    let transfer_out = CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        Transfer {
+            from: vault_out.to_account_info(),
+            to: accounts.user_destination.to_account_info(),
+            authority: accounts.amm_pool.to_account_info(),
+        },
+        signer
+    );
+    token::transfer(transfer_out, amount_out)?;
+    msg!("Swapped");
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod constants;
+pub mod error;
+mod instructions;
+pub mod state;
+
+declare_id!("3X2Zj6Rredu1bdPpfkubXvLcsrWpKJeVrs8Zcrk8PuGq");
+
+#[program]
+pub mod paid_amm {
+    use super::*;
+
+    pub fn create_pool(ctx: Context<CreatePool>) -> Result<()> {
+        create_pool_handler(ctx)
+    }
+
+    pub fn deposit_liquidity(
+        ctx: Context<DepositLiquidity>,
+        amount_a: u64,
+        amount_b: u64,
+        min_lp: u64
+    ) -> Result<()> {
+        deposit_liquidity_handler(ctx, amount_a, amount_b, min_lp)
+    }
+
+    pub fn swap(ctx: Context<Swap>, amount_in: u64, min_out: u64, a_to_b: bool) -> Result<()> {
+        swap_handler(ctx, amount_in, min_out, a_to_b)
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct AmmPool {
+    // @dev mint of first reserve
+    pub mint_a: Pubkey,
+    // @dev mint of second reserve
+    pub mint_b: Pubkey,
+    // @dev pda bump of pool, signs for vaults and lp mint
+    pub bump: u8,
+}
@@ -0,0 +1,3 @@
+pub mod amm_pool;
+
+pub use amm_pool::*;
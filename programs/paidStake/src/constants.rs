@@ -0,0 +1,28 @@
+// @dev reward accrued by staker since last update
+pub fn calculate_reward(
+    total_staked: u64,
+    reward_per_block: u64,
+    stake_decimals: u8,
+    last_update: i64,
+    time_stamp: i64
+) -> u64 {
+    let base: u64 = 10;
+    (((time_stamp - last_update) as u64) * reward_per_block * total_staked) /
+        base.pow(stake_decimals as u32)
+}
+
+// @dev balance records kept per staker, oldest is dropped once full
+pub const MAX_CHECKPOINTS: usize = 32;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reward_accrues_per_second_and_whole_token() {
+        assert_eq!(calculate_reward(1_000_000, 5, 6, 100, 100), 0);
+        assert_eq!(calculate_reward(1_000_000, 5, 6, 100, 110), 50);
+        assert_eq!(calculate_reward(2_500_000, 5, 6, 100, 110), 125);
+        assert_eq!(calculate_reward(999_999, 1, 6, 0, 1), 0);
+    }
+}
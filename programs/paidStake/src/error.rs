@@ -4,4 +4,20 @@ use anchor_lang::prelude::*;
 pub enum ErrCode {
     #[msg("Invalid amount")]
     InvalidAmount,
+    #[msg("Invalid params")]
+    InvalidParam,
+    #[msg("Paused")]
+    Paused,
+    #[msg("Not allowed to revoke own super admin role")]
+    CannotRevokeOwnSuperAdmin,
+    #[msg("No pending owner")]
+    NoPendingOwner,
+    #[msg("Signer is not the pending owner")]
+    NotPendingOwner,
+    #[msg("Account is not fully settled")]
+    NotSettled,
+    #[msg("Vault is not closed")]
+    VaultNotClosed,
+    #[msg("Signer is not the upgrade authority of program")]
+    NotUpgradeAuthority,
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct OwnerProposed {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct OwnerAccepted {
+    pub pool: Pubkey,
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct OwnerTransferCancelled {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
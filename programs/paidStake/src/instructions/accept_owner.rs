@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{ ErrCode, OwnerAccepted, Pool };
+
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    pub pending_owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        constraint = pool.pending_owner != Pubkey::default() @ ErrCode::NoPendingOwner,
+        has_one = pending_owner @ ErrCode::NotPendingOwner
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev it allows to take over the stake pool by the nominated owner
+pub fn accept_owner_handler(ctx: Context<AcceptOwner>) -> Result<()> {
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    let previous_owner: Pubkey = pool.owner;
+    pool.owner = pool.pending_owner;
+    pool.pending_owner = Pubkey::default();
+    emit!(OwnerAccepted {
+        pool: pool.key(),
+        previous_owner,
+        owner: pool.owner,
+    });
+    msg!("Accepted owner");
+    Ok(())
+}
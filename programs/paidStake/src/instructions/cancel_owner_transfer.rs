@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{ ErrCode, OwnerTransferCancelled, Pool };
+
+#[derive(Accounts)]
+pub struct CancelOwnerTransfer<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = pool.pending_owner != Pubkey::default() @ ErrCode::NoPendingOwner
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev it allows to withdraw a pending nomination by current owner
+pub fn cancel_owner_transfer_handler(ctx: Context<CancelOwnerTransfer>) -> Result<()> {
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    let pending_owner: Pubkey = pool.pending_owner;
+    pool.pending_owner = Pubkey::default();
+    emit!(OwnerTransferCancelled {
+        pool: pool.key(),
+        owner: pool.owner,
+        pending_owner,
+    });
+    msg!("Cancelled owner transfer");
+    Ok(())
+}
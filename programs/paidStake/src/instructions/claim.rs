@@ -4,7 +4,7 @@ use anchor_spl::{
     token::{ self, Mint, Token, TokenAccount, Transfer },
 };
 
-use crate::{ Pool, Staker };
+use crate::{ calculate_reward, Config, ErrCode, Pool, Staker };
 
 #[derive(Accounts)]
 pub struct Claim<'info> {
@@ -21,8 +21,16 @@ pub struct Claim<'info> {
     // @dev staker account
     #[account(mut)]
     pub staker: Box<Account<'info, Staker>>,
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
     // pool account
-    #[account(mut)]
+    #[account(mut, constraint = !pool.paused @ ErrCode::Paused)]
     pub pool: Account<'info, Pool>,
 
     // @dev reward pot
@@ -57,14 +65,14 @@ impl<'info> Claim<'info> {
 // @dev claim reward by staker
 pub fn claim_handler(ctx: Context<Claim>) -> Result<()> {
     // calculate reward amount
-    let reward_per_block: u64 = ctx.accounts.pool.reward_per_block;
     let clock: Clock = Clock::get()?;
-    let base: u64 = 10;
-    let reward: u64 =
-        (((clock.unix_timestamp - ctx.accounts.staker.last_update) as u64) *
-            reward_per_block *
-            ctx.accounts.staker.total_staked) /
-        base.pow(ctx.accounts.pool.stake_decimals as u32);
+    let reward: u64 = calculate_reward(
+        ctx.accounts.staker.total_staked,
+        ctx.accounts.pool.reward_per_block,
+        ctx.accounts.pool.stake_decimals,
+        ctx.accounts.staker.last_update,
+        clock.unix_timestamp
+    );
     if ctx.accounts.user_reward_token.data_is_empty() {
         associated_token::create(ctx.accounts.create_ctx())?;
     }
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{ ErrCode, StakeCheckpoints };
+
+#[derive(Accounts)]
+pub struct CloseCheckpoints<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: only used as seed of checkpoints, may be closed already
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: staker account of signer, must be closed already
+    #[account(seeds = [pool.key().as_ref(), signer.key().as_ref()], bump)]
+    pub staker: UncheckedAccount<'info>,
+
+    // @dev balance history of signer, rent goes back to staker who paid it
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"checkpoints", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub checkpoints: Box<Account<'info, StakeCheckpoints>>,
+}
+
+// @dev allowed to close balance history by staker once staker account is closed as fully withdrawn
+pub fn close_checkpoints_handler(ctx: Context<CloseCheckpoints>) -> Result<()> {
+    if ctx.accounts.staker.lamports() != 0 {
+        return err!(ErrCode::NotSettled);
+    }
+    msg!("Closed checkpoints");
+    Ok(())
+}
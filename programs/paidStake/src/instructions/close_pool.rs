@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{ ErrCode, Pool };
+
+#[derive(Accounts)]
+pub struct ClosePool<'info> {
+    pub owner: Signer<'info>,
+
+    /// CHECK: receives rent, checked against pool
+    #[account(mut, address = pool.rent_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        has_one = owner,
+        close = rent_payer
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// CHECK: stake vault, must be closed already
+    #[account(seeds = [b"stake-vault", pool.key().as_ref()], bump)]
+    pub stake_vault: UncheckedAccount<'info>,
+
+    /// CHECK: reward pot, must be closed already
+    #[account(seeds = [b"reward-pot", pool.key().as_ref()], bump)]
+    pub reward_pot: UncheckedAccount<'info>,
+}
+
+// @dev allowed to close pool by owner once all stakers and vaults are closed
+pub fn close_pool_handler(ctx: Context<ClosePool>) -> Result<()> {
+    if ctx.accounts.pool.staker_count != 0 || ctx.accounts.pool.total_staked != 0 {
+        return err!(ErrCode::NotSettled);
+    }
+    if !ctx.accounts.stake_vault.data_is_empty() || !ctx.accounts.reward_pot.data_is_empty() {
+        return err!(ErrCode::VaultNotClosed);
+    }
+    msg!("Closed pool");
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, CloseAccount, Token, TokenAccount };
+
+use crate::{ ErrCode, Pool };
+
+#[derive(Accounts)]
+pub struct CloseRewardPot<'info> {
+    /// CHECK: receives rent, checked against pool
+    #[account(mut, address = pool.reward_pot_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev reward pot
+    #[account(
+        mut,
+        seeds = [b"reward-pot", pool.key().as_ref()],
+        bump
+    )]
+    pub reward_pot: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseRewardPot<'info> {
+    fn close_ctx(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), CloseAccount {
+            account: self.reward_pot.to_account_info(),
+            destination: self.rent_payer.to_account_info(),
+            authority: self.reward_pot.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to close empty reward pot by anyone, rent goes back to payer
+pub fn close_reward_pot_handler(ctx: Context<CloseRewardPot>) -> Result<()> {
+    if ctx.accounts.reward_pot.amount != 0 {
+        return err!(ErrCode::NotSettled);
+    }
+    // seeds of authority pda of reward pot
+    let seeds: &[&[u8]; 3] = &[
+        b"reward-pot",
+        ctx.accounts.pool.to_account_info().key.as_ref(),
+        &[ctx.bumps.reward_pot],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    token::close_account(ctx.accounts.close_ctx().with_signer(signer))?;
+    ctx.accounts.pool.reward_pot_payer = Pubkey::default();
+    msg!("Closed reward pot");
+    Ok(())
+}
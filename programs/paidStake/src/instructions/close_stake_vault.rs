@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ self, CloseAccount, Token, TokenAccount };
+
+use crate::{ ErrCode, Pool };
+
+#[derive(Accounts)]
+pub struct CloseStakeVault<'info> {
+    /// CHECK: receives rent, checked against pool
+    #[account(mut, address = pool.stake_vault_payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev stake vault
+    #[account(
+        mut,
+        seeds = [b"stake-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseStakeVault<'info> {
+    fn close_ctx(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(self.token_program.to_account_info(), CloseAccount {
+            account: self.stake_vault.to_account_info(),
+            destination: self.rent_payer.to_account_info(),
+            authority: self.stake_vault.to_account_info(),
+        })
+    }
+}
+
+// @dev allowed to close empty stake vault by anyone, rent goes back to payer
+pub fn close_stake_vault_handler(ctx: Context<CloseStakeVault>) -> Result<()> {
+    if ctx.accounts.stake_vault.amount != 0 || ctx.accounts.pool.total_staked != 0 {
+        return err!(ErrCode::NotSettled);
+    }
+    // seeds of authority pda of stake vault
+    let seeds: &[&[u8]; 3] = &[
+        b"stake-vault",
+        ctx.accounts.pool.to_account_info().key.as_ref(),
+        &[ctx.bumps.stake_vault],
+    ];
+    let signer: &[&[&[u8]]; 1] = &[&seeds[..]];
+    token::close_account(ctx.accounts.close_ctx().with_signer(signer))?;
+    ctx.accounts.pool.stake_vault_payer = Pubkey::default();
+    msg!("Closed stake vault");
+    Ok(())
+}
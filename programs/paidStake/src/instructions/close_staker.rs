@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{ ErrCode, Pool, Staker };
+
+#[derive(Accounts)]
+pub struct CloseStaker<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev staker account, rent goes back to staker who paid it
+    #[account(
+        mut,
+        close = signer,
+        seeds = [pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub staker: Box<Account<'info, Staker>>,
+}
+
+// @dev allowed to close staker account by staker once fully withdrawn
+pub fn close_staker_handler(ctx: Context<CloseStaker>) -> Result<()> {
+    // nothing staked means nothing pending either
+    if ctx.accounts.staker.total_staked != 0 {
+        return err!(ErrCode::NotSettled);
+    }
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.staker_count -= 1;
+    msg!("Closed staker");
+    Ok(())
+}
@@ -1,13 +1,20 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{ self, Mint, Token, TokenAccount, Transfer };
 
-use crate::Pool;
+use crate::{ Config, ErrCode, Pool, Role, RoleAssignment };
 
 #[derive(Accounts)]
 pub struct FundReward<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    // @dev funder role of signer
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::Funder as u8], signer.key().as_ref()],
+        bump = funder_role.bump
+    )]
+    pub funder_role: Box<Account<'info, RoleAssignment>>,
+
     // mint address of reward token
     pub reward_mint: Box<Account<'info, Mint>>,
 
@@ -15,10 +22,19 @@ pub struct FundReward<'info> {
     #[account(mut,token::mint = reward_mint)]
     pub owner_token: Account<'info, TokenAccount>,
 
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
     // @dev pool account
     #[account(
       mut, 
-      constraint = reward_mint.key() == pool.reward_mint
+      constraint = reward_mint.key() == pool.reward_mint,
+      constraint = !pool.paused @ ErrCode::Paused
     )]
     pub pool: Box<Account<'info, Pool>>,
 
@@ -49,7 +65,7 @@ impl<'info> FundReward<'info> {
     }
 }
 
-// @dev allowed to deposit token
+// @dev allowed to deposit reward token by funder
 pub fn fund_reward_handler(
     ctx: Context<FundReward>,
     amount: u64, 
@@ -60,6 +76,9 @@ pub fn fund_reward_handler(
     // update pool info
     let pool: &mut Account<Pool> = &mut ctx.accounts.pool;
     pool.pot_bump = pot_bump;
+    if pool.reward_pot_payer == Pubkey::default() {
+        pool.reward_pot_payer = ctx.accounts.signer.key();
+    }
     msg!("Init reward");
     Ok(())
 }
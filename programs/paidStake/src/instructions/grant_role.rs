@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+use crate::{ Role, RoleAssignment };
+
+#[derive(Accounts)]
+#[instruction(role: Role, holder: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // @dev super admin role of signer
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::SuperAdmin as u8], admin.key().as_ref()],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Box<Account<'info, RoleAssignment>>,
+
+    // @dev granted role
+    #[account(
+        init,
+        payer = admin,
+        space = size_of::<RoleAssignment>() + 8,
+        seeds = [b"role".as_ref(), &[role as u8], holder.as_ref()],
+        bump
+    )]
+    pub role_assignment: Box<Account<'info, RoleAssignment>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// @dev it allows to grant a role to any key by super admin
+pub fn grant_role_handler(ctx: Context<GrantRole>, role: Role, holder: Pubkey) -> Result<()> {
+    let role_assignment: &mut Box<Account<RoleAssignment>> = &mut ctx.accounts.role_assignment;
+    role_assignment.role = role;
+    role_assignment.holder = holder;
+    role_assignment.granted_by = ctx.accounts.admin.key();
+    role_assignment.bump = ctx.bumps.role_assignment;
+    msg!("Granted role");
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::{ prelude::*, solana_program::bpf_loader_upgradeable };
+use std::mem::size_of;
+
+use crate::{ error::ErrCode, Config, Role, RoleAssignment };
+
+#[derive(Accounts)]
+pub struct InitConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // @dev program data of this program, only its upgrade authority may initialize config
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrCode::NotUpgradeAuthority
+    )]
+    pub program_data: Box<Account<'info, ProgramData>>,
+
+    // @dev global config
+    #[account(
+        init,
+        payer = admin,
+        space = size_of::<Config>() + 8,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev super admin role of deployer
+    #[account(
+        init,
+        payer = admin,
+        space = size_of::<RoleAssignment>() + 8,
+        seeds = [b"role".as_ref(), &[Role::SuperAdmin as u8], admin.key().as_ref()],
+        bump
+    )]
+    pub admin_role: Box<Account<'info, RoleAssignment>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// @dev initialize global config by upgrade authority after deploy, admin is the first guardian
+pub fn init_config_handler(ctx: Context<InitConfig>) -> Result<()> {
+    let config: &mut Box<Account<Config>> = &mut ctx.accounts.config;
+    config.guardian = ctx.accounts.admin.key();
+    config.paused = false;
+    config.bump = ctx.bumps.config;
+    // deployer becomes the first super admin
+    let admin_role: &mut Box<Account<RoleAssignment>> = &mut ctx.accounts.admin_role;
+    admin_role.role = Role::SuperAdmin;
+    admin_role.holder = ctx.accounts.admin.key();
+    admin_role.granted_by = ctx.accounts.admin.key();
+    admin_role.bump = ctx.bumps.admin_role;
+    msg!("Config initialized");
+    Ok(())
+}
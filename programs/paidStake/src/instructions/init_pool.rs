@@ -2,13 +2,20 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 use std::mem::size_of;
 
-use crate::states::Pool;
+use crate::states::{ Pool, Role, RoleAssignment };
 
 #[derive(Accounts)]
 pub struct InitPool<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    // @dev pool operator role of signer
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::PoolOperator as u8], owner.key().as_ref()],
+        bump = owner_role.bump
+    )]
+    pub owner_role: Box<Account<'info, RoleAssignment>>,
+
     // @dev mint address of reward token
     pub reward_mint: Box<Account<'info, Mint>>,
     // @dev mint address of stake token
@@ -21,7 +28,7 @@ pub struct InitPool<'info> {
     pub system_program: Program<'info, System>,
 }
 
-// @dev initialize pool by owner after deploy
+// @dev initialize pool by pool operator after deploy
 pub fn init_pool_handler(
     ctx: Context<InitPool>,
     reward_decimals: u8,
@@ -31,6 +38,7 @@ pub fn init_pool_handler(
     // format pool info
     let pool: &mut Account<Pool> = &mut ctx.accounts.pool;
     pool.owner = ctx.accounts.owner.key();
+    pool.rent_payer = ctx.accounts.owner.key();
     pool.reward_mint = ctx.accounts.reward_mint.key();
     pool.reward_per_block = reward_per_block;
     pool.stake_mint = ctx.accounts.stake_mint.key();
@@ -3,9 +3,39 @@ pub mod fund_reward;
 pub mod stake;
 pub mod claim;
 pub mod withdraw;
+pub mod init_config;
+pub mod set_guardian;
+pub mod set_paused;
+pub mod set_pool_paused;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod propose_owner;
+pub mod accept_owner;
+pub mod cancel_owner_transfer;
+pub mod close_staker;
+pub mod close_checkpoints;
+pub mod close_stake_vault;
+pub mod close_reward_pot;
+pub mod close_pool;
+pub mod pending_rewards;
 
 pub use init_pool::*;
 pub use fund_reward::*;
 pub use stake::*;
 pub use claim::*;
-pub use withdraw::*;
\ No newline at end of file
+pub use withdraw::*;
+pub use init_config::*;
+pub use set_guardian::*;
+pub use set_paused::*;
+pub use set_pool_paused::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use propose_owner::*;
+pub use accept_owner::*;
+pub use cancel_owner_transfer::*;
+pub use close_staker::*;
+pub use close_checkpoints::*;
+pub use close_stake_vault::*;
+pub use close_reward_pot::*;
+pub use close_pool::*;
+pub use pending_rewards::*;
\ No newline at end of file
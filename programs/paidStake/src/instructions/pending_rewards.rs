@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{ calculate_reward, Pool, Staker };
+
+#[derive(Accounts)]
+pub struct PendingRewards<'info> {
+    /// CHECK: wallet of staker, only used as seed of staker account
+    pub wallet: UncheckedAccount<'info>,
+
+    // @dev pool account
+    pub pool: Box<Account<'info, Pool>>,
+
+    // @dev staker account
+    #[account(
+        seeds = [pool.key().as_ref(), wallet.key().as_ref()],
+        bump
+    )]
+    pub staker: Box<Account<'info, Staker>>,
+}
+
+// @dev view only, returns reward claim would pay now
+pub fn pending_rewards_handler(ctx: Context<PendingRewards>) -> Result<u64> {
+    Ok(
+        calculate_reward(
+            ctx.accounts.staker.total_staked,
+            ctx.accounts.pool.reward_per_block,
+            ctx.accounts.pool.stake_decimals,
+            ctx.accounts.staker.last_update,
+            Clock::get()?.unix_timestamp
+        )
+    )
+}
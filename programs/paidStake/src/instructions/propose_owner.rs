@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{ ErrCode, OwnerProposed, Pool };
+
+#[derive(Accounts)]
+pub struct ProposeOwner<'info> {
+    pub owner: Signer<'info>,
+
+    // @dev pool account
+    #[account(
+        mut,
+        has_one = owner
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev it allows to nominate a new owner by current owner
+pub fn propose_owner_handler(ctx: Context<ProposeOwner>, pending_owner: Pubkey) -> Result<()> {
+    if pending_owner == Pubkey::default() || pending_owner == ctx.accounts.pool.owner {
+        return err!(ErrCode::InvalidParam);
+    }
+    let pool: &mut Box<Account<Pool>> = &mut ctx.accounts.pool;
+    pool.pending_owner = pending_owner;
+    emit!(OwnerProposed {
+        pool: pool.key(),
+        owner: pool.owner,
+        pending_owner,
+    });
+    msg!("Proposed owner");
+    Ok(())
+}
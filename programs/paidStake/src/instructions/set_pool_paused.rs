@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{ Config, Pool, Role, RoleAssignment };
+
+#[derive(Accounts)]
+pub struct SetPoolPaused<'info> {
+    pub pauser: Signer<'info>,
+
+    // @dev pauser role of signer, not required for guardian
+    #[account(
+        seeds = [b"role".as_ref(), &[Role::Pauser as u8], pauser.key().as_ref()],
+        bump = pauser_role.bump
+    )]
+    pub pauser_role: Option<Box<Account<'info, RoleAssignment>>>,
+
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = pauser_role.is_some() || pauser.key() == config.guardian
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    // @dev pool account
+    #[account(mut)]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+// @dev it allows to pause or unpause a single stake pool by pauser or guardian
+pub fn set_pool_paused_handler(ctx: Context<SetPoolPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.pool.paused = paused;
+    msg!("Updated pool pause");
+    Ok(())
+}
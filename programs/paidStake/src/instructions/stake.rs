@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{ self, Mint, Token, TokenAccount, Transfer };
 use std::mem::size_of;
 
-use crate::{ Pool, Staker };
+use crate::{ Config, ErrCode, Pool, StakeCheckpoints, Staker };
 
 #[derive(Accounts)]
 pub struct Stake<'info> {
@@ -11,8 +11,16 @@ pub struct Stake<'info> {
 
     // @dev mint address of stake token
     pub stake_mint: Box<Account<'info, Mint>>,
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
     // @dev pool account
-    #[account(mut)]
+    #[account(mut, constraint = !pool.paused @ ErrCode::Paused)]
     pub pool: Box<Account<'info, Pool>>,
 
     // @dev staker account
@@ -25,6 +33,16 @@ pub struct Stake<'info> {
     )]
     pub staker: Account<'info, Staker>,
 
+    // @dev balance history of staker, fixed size so recording never reallocates
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = StakeCheckpoints::space(),
+        seeds = [b"checkpoints", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub checkpoints: Box<Account<'info, StakeCheckpoints>>,
+
     // @dev stake token account of user
     #[account(mut, token::mint = stake_mint)]
     pub user_token: Account<'info, TokenAccount>,
@@ -65,10 +83,27 @@ pub fn stake_handler(ctx: Context<Stake>, amount: u64, bump: u8) -> Result<()> {
     let staker: &mut Account<Staker> = &mut ctx.accounts.staker;
     pool.total_staked += amount;
     pool.vault_bump = bump;
+    if pool.stake_vault_payer == Pubkey::default() {
+        pool.stake_vault_payer = ctx.accounts.signer.key();
+    }
+
+    // new staker account
+    if staker.last_update == 0 {
+        pool.staker_count += 1;
+    }
 
     let clock: Clock = Clock::get()?;
+    let previous_balance: u64 = staker.total_staked;
+    let previous_update: i64 = staker.last_update;
     staker.total_staked += amount;
     staker.last_update = clock.unix_timestamp;
+
+    // record balance in place, oldest record is dropped once history is full
+    let balance: u64 = staker.total_staked;
+    let checkpoints: &mut Box<Account<StakeCheckpoints>> = &mut ctx.accounts.checkpoints;
+    checkpoints.pool = pool.key();
+    checkpoints.wallet = ctx.accounts.signer.key();
+    checkpoints.record(previous_balance, previous_update, clock.unix_timestamp, balance);
     msg!("Staked");
     Ok(())
 }
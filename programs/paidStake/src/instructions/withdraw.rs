@@ -1,21 +1,43 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{ self, Mint, Token, TokenAccount, Transfer };
 
-use crate::{ ErrCode, Pool, Staker };
+use crate::{ Config, ErrCode, Pool, StakeCheckpoints, Staker };
 
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    // @dev global config
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ ErrCode::Paused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
     // @dev pool account
-    #[account(mut)]
+    #[account(mut, constraint = !pool.paused @ ErrCode::Paused)]
     pub pool: Box<Account<'info, Pool>>,
 
-    // @dev staker account
-    #[account(mut)]
+    // @dev staker account of signer
+    #[account(
+        mut,
+        seeds = [pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
     pub staker: Box<Account<'info, Staker>>,
 
+    // @dev balance history of staker, fixed size so recording never reallocates
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = StakeCheckpoints::space(),
+        seeds = [b"checkpoints", pool.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub checkpoints: Box<Account<'info, StakeCheckpoints>>,
+
     // @dev mint address of stake token
     pub stake_mint: Box<Account<'info, Mint>>,
 
@@ -58,8 +80,17 @@ pub fn withdraw_handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     // update staker info
     let clock: Clock = Clock::get()?;
     let staker: &mut Account<Staker> = &mut ctx.accounts.staker;
+    let previous_balance: u64 = staker.total_staked;
+    let previous_update: i64 = staker.last_update;
     staker.last_update = clock.unix_timestamp;
     staker.total_staked -= amount;
+
+    // record balance in place, oldest record is dropped once history is full
+    let balance: u64 = staker.total_staked;
+    let checkpoints: &mut Box<Account<StakeCheckpoints>> = &mut ctx.accounts.checkpoints;
+    checkpoints.pool = ctx.accounts.pool.key();
+    checkpoints.wallet = ctx.accounts.signer.key();
+    checkpoints.record(previous_balance, previous_update, clock.unix_timestamp, balance);
     let pool: &mut Account<Pool> = &mut ctx.accounts.pool;
     pool.total_staked -= amount;
     msg!("Withdraw successfully");
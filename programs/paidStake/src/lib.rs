@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
 use states::*;
 use error::*;
+use constants::*;
+use events::*;
 use instructions::*;
 
 pub mod states;
+pub mod constants;
 mod instructions;
 pub mod error;
+pub mod events;
 
 declare_id!("8FcYYJ38nxLKWD8BN6JYs8b3yFnnXzkrL9Pfx43NNUPj");
 
@@ -50,4 +54,103 @@ pub mod paid_stake {
     ) -> Result<()> {
         withdraw_handler(ctx, amount)
     }
+
+    pub fn init_config(
+        ctx: Context<InitConfig>,
+    ) -> Result<()> {
+        init_config_handler(ctx)
+    }
+
+    pub fn set_guardian(
+        ctx: Context<SetGuardian>,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        set_guardian_handler(ctx, guardian)
+    }
+
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        role: Role,
+        holder: Pubkey,
+    ) -> Result<()> {
+        grant_role_handler(ctx, role, holder)
+    }
+
+    pub fn revoke_role(
+        ctx: Context<RevokeRole>,
+        role: Role,
+        holder: Pubkey,
+    ) -> Result<()> {
+        revoke_role_handler(ctx, role, holder)
+    }
+
+    pub fn set_paused(
+        ctx: Context<SetPaused>,
+        paused: bool,
+    ) -> Result<()> {
+        set_paused_handler(ctx, paused)
+    }
+
+    pub fn set_pool_paused(
+        ctx: Context<SetPoolPaused>,
+        paused: bool,
+    ) -> Result<()> {
+        set_pool_paused_handler(ctx, paused)
+    }
+
+    pub fn propose_owner(
+        ctx: Context<ProposeOwner>,
+        pending_owner: Pubkey,
+    ) -> Result<()> {
+        propose_owner_handler(ctx, pending_owner)
+    }
+
+    pub fn accept_owner(
+        ctx: Context<AcceptOwner>,
+    ) -> Result<()> {
+        accept_owner_handler(ctx)
+    }
+
+    pub fn cancel_owner_transfer(
+        ctx: Context<CancelOwnerTransfer>,
+    ) -> Result<()> {
+        cancel_owner_transfer_handler(ctx)
+    }
+
+    pub fn close_staker(
+        ctx: Context<CloseStaker>,
+    ) -> Result<()> {
+        close_staker_handler(ctx)
+    }
+
+    pub fn close_checkpoints(
+        ctx: Context<CloseCheckpoints>,
+    ) -> Result<()> {
+        close_checkpoints_handler(ctx)
+    }
+
+    pub fn close_stake_vault(
+        ctx: Context<CloseStakeVault>,
+    ) -> Result<()> {
+        close_stake_vault_handler(ctx)
+    }
+
+    pub fn close_reward_pot(
+        ctx: Context<CloseRewardPot>,
+    ) -> Result<()> {
+        close_reward_pot_handler(ctx)
+    }
+
+    pub fn close_pool(
+        ctx: Context<ClosePool>,
+    ) -> Result<()> {
+        close_pool_handler(ctx)
+    }
+
+    // @dev return value is set as return data, read it by simulation
+    pub fn pending_rewards(
+        ctx: Context<PendingRewards>,
+    ) -> Result<u64> {
+        pending_rewards_handler(ctx)
+    }
 }
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+use crate::MAX_CHECKPOINTS;
+
+#[account]
+pub struct StakeCheckpoints {
+  // @dev stake pool of recorded balances
+  pub pool: Pubkey,
+  // @dev wallet of staker
+  pub wallet: Pubkey,
+  // @dev staked balance after each stake or withdraw, ordered by timestamp, latest
+  // @dev MAX_CHECKPOINTS only so balances before the oldest record read as zero
+  pub checkpoints: Vec<Checkpoint>,
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct Checkpoint {
+  // @dev unix time balance changed
+  pub timestamp: i64,
+  // @dev staked balance from timestamp on
+  pub balance: u64,
+}
+
+impl StakeCheckpoints {
+  // @dev fixed size for MAX_CHECKPOINTS records, never reallocated
+  pub fn space() -> usize {
+    8 + 32 + 32 + 4 + MAX_CHECKPOINTS * size_of::<Checkpoint>()
+  }
+
+  // @dev records new balance in place, one record per timestamp, dropping the oldest once full
+  // @dev balance staked before checkpoints existed is carried over from its last update
+  pub fn record(&mut self, previous_balance: u64, previous_update: i64, timestamp: i64, balance: u64) {
+    if self.checkpoints.is_empty() && previous_balance > 0 {
+      self.checkpoints.push(Checkpoint { timestamp: previous_update, balance: previous_balance });
+    }
+    match self.checkpoints.last_mut() {
+      Some(last) if last.timestamp == timestamp => {
+        last.balance = balance;
+        return;
+      }
+      _ => {}
+    }
+    if self.checkpoints.len() >= MAX_CHECKPOINTS {
+      self.checkpoints.remove(0);
+    }
+    self.checkpoints.push(Checkpoint { timestamp, balance });
+  }
+
+  // @dev staked balance as of timestamp, zero if older than the oldest record
+  pub fn balance_at(&self, timestamp: i64) -> u64 {
+    self.checkpoints
+      .iter()
+      .rev()
+      .find(|checkpoint| checkpoint.timestamp <= timestamp)
+      .map_or(0, |checkpoint| checkpoint.balance)
+  }
+
+  // @dev lowest balance held from timestamp until now
+  pub fn min_balance_since(&self, timestamp: i64) -> u64 {
+    self.checkpoints
+      .iter()
+      .filter(|checkpoint| checkpoint.timestamp > timestamp)
+      .fold(self.balance_at(timestamp), |min, checkpoint| min.min(checkpoint.balance))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn history() -> StakeCheckpoints {
+    StakeCheckpoints {
+      pool: Pubkey::default(),
+      wallet: Pubkey::default(),
+      checkpoints: Vec::new(),
+    }
+  }
+
+  #[test]
+  fn balance_follows_recorded_history() {
+    let mut history: StakeCheckpoints = history();
+    // stake from before checkpoints existed is carried over
+    history.record(500, 10, 20, 800);
+    // same timestamp overwrites
+    history.record(800, 20, 20, 700);
+    history.record(700, 20, 30, 50);
+    history.record(50, 30, 40, 900);
+    assert_eq!(history.checkpoints.len(), 4);
+    assert_eq!(history.balance_at(9), 0);
+    assert_eq!(history.balance_at(10), 500);
+    assert_eq!(history.balance_at(25), 700);
+    assert_eq!(history.balance_at(30), 50);
+    assert_eq!(history.balance_at(i64::MAX), 900);
+    // withdrawing and restaking after snapshot does not count as held
+    assert_eq!(history.min_balance_since(15), 50);
+    assert_eq!(history.min_balance_since(35), 50);
+    assert_eq!(history.min_balance_since(40), 900);
+  }
+
+  #[test]
+  fn first_stake_starts_history_at_zero_balance() {
+    let mut history: StakeCheckpoints = history();
+    history.record(0, 0, 100, 1_000);
+    assert_eq!(history.checkpoints.len(), 1);
+    assert_eq!(history.balance_at(99), 0);
+    assert_eq!(history.min_balance_since(50), 0);
+    assert_eq!(history.min_balance_since(100), 1_000);
+  }
+
+  #[test]
+  fn checkpoints_keep_latest_records() {
+    let mut history: StakeCheckpoints = history();
+    for timestamp in 1..(MAX_CHECKPOINTS as i64) + 4 {
+      history.record(0, 0, timestamp, timestamp as u64);
+    }
+    assert_eq!(history.checkpoints.len(), MAX_CHECKPOINTS);
+    assert_eq!(history.checkpoints[0].timestamp, 4);
+    // dropped records read as zero, never as a higher balance
+    assert_eq!(history.balance_at(3), 0);
+    assert_eq!(history.balance_at(10), 10);
+    assert_eq!(history.min_balance_since(3), 0);
+  }
+}
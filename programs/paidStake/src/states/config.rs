@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+
+#[account]
+pub struct Config {
+  // @dev guardian allowed to pause and unpause besides pauser role holders
+  pub guardian: Pubkey,
+  // @dev true if all value-moving instructions are paused
+  pub paused: bool,
+  // @dev pda bump of config
+  pub bump: u8,
+}
@@ -1,5 +1,11 @@
 pub mod pool;
 pub mod staker;
+pub mod config;
+pub mod role;
+pub mod checkpoints;
 
 pub use pool::*;
-pub use staker::*;
\ No newline at end of file
+pub use staker::*;
+pub use config::*;
+pub use role::*;
+pub use checkpoints::*;
\ No newline at end of file
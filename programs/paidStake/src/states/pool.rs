@@ -5,6 +5,14 @@ use anchor_lang::prelude::*;
 pub struct Pool {
   // @dev pool owner
   pub owner: Pubkey,
+  // @dev nominated owner until accepted or cancelled, default if none
+  pub pending_owner: Pubkey,
+  // @dev payer of pool account rent
+  pub rent_payer: Pubkey,
+  // @dev payer of stake vault rent, default if vault not created
+  pub stake_vault_payer: Pubkey,
+  // @dev payer of reward pot rent, default if pot not created
+  pub reward_pot_payer: Pubkey,
   // @dev reward per block
   pub reward_per_block: u64,
   // @dev mint address of reward token
@@ -22,5 +30,7 @@ pub struct Pool {
   // @dev reward token decimals 
   pub reward_decimals:u8,
   // @dev stake token decimals
-  pub stake_decimals: u8
+  pub stake_decimals: u8,
+  // @dev true if guardian paused this pool
+  pub paused: bool,
 }
\ No newline at end of file
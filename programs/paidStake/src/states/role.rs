@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum Role {
+  // @dev grants and revokes roles
+  SuperAdmin,
+  // @dev creates pools and manages their schedule
+  PoolOperator,
+  // @dev deposits offered tokens into pools
+  Funder,
+  // @dev pauses and unpauses the program or single pools
+  Pauser,
+  // @dev updates fee percentages
+  FeeManager,
+}
+
+#[account]
+pub struct RoleAssignment {
+  // @dev granted role
+  pub role: Role,
+  // @dev key holding the role
+  pub holder: Pubkey,
+  // @dev super admin who granted the role
+  pub granted_by: Pubkey,
+  // @dev pda bump of role assignment
+  pub bump: u8,
+}
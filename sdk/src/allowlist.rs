@@ -0,0 +1,50 @@
+// @dev merkle tree of allowlist rounds, same leaves and pair ordering buy_in_round verifies
+use anchor_lang::{ prelude::Pubkey, solana_program::keccak };
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    if left <= right {
+        keccak::hashv(&[left, right]).to_bytes()
+    } else {
+        keccak::hashv(&[right, left]).to_bytes()
+    }
+}
+
+// @dev leaves up to root, odd node of a layer moves up unhashed
+fn layers(wallets: &[Pubkey]) -> Vec<Vec<[u8; 32]>> {
+    let leaves: Vec<[u8; 32]> = wallets
+        .iter()
+        .map(|wallet| keccak::hash(wallet.as_ref()).to_bytes())
+        .collect();
+    let mut layers: Vec<Vec<[u8; 32]>> = vec![leaves];
+    while let Some(layer) = layers.last().filter(|layer| layer.len() > 1) {
+        let next: Vec<[u8; 32]> = layer
+            .chunks(2)
+            .map(|pair| if pair.len() == 2 { hash_pair(&pair[0], &pair[1]) } else { pair[0] })
+            .collect();
+        layers.push(next);
+    }
+    layers
+}
+
+// @dev zero if no wallets
+pub fn root(wallets: &[Pubkey]) -> [u8; 32] {
+    layers(wallets)
+        .last()
+        .and_then(|layer| layer.first())
+        .copied()
+        .unwrap_or_default()
+}
+
+// @dev none if wallet is not in allowlist
+pub fn proof(wallets: &[Pubkey], wallet: &Pubkey) -> Option<Vec<[u8; 32]>> {
+    let mut index: usize = wallets.iter().position(|listed| listed == wallet)?;
+    let layers: Vec<Vec<[u8; 32]>> = layers(wallets);
+    let mut proof: Vec<[u8; 32]> = Vec::new();
+    for layer in &layers[..layers.len() - 1] {
+        if let Some(sibling) = layer.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        index /= 2;
+    }
+    Some(proof)
+}
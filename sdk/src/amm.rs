@@ -0,0 +1,89 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{ instruction::Instruction, system_program, sysvar },
+    InstructionData,
+    ToAccountMetas,
+};
+use anchor_spl::associated_token::get_associated_token_address;
+use paid_amm::{ accounts, instruction };
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: paid_amm::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn create_pool(payer: &Pubkey, mint_a: &Pubkey, mint_b: &Pubkey) -> Instruction {
+    let amm_pool: Pubkey = pda::amm_pool(mint_a, mint_b).0;
+    build(
+        accounts::CreatePool {
+            payer: *payer,
+            mint_a: *mint_a,
+            mint_b: *mint_b,
+            amm_pool,
+            vault_a: pda::amm_vault(&amm_pool, mint_a).0,
+            vault_b: pda::amm_vault(&amm_pool, mint_b).0,
+            lp_mint: pda::lp_mint(&amm_pool).0,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::CreatePool {}
+    )
+}
+
+// @dev sources and lp destination are associated token accounts of owner
+pub fn deposit_liquidity(
+    owner: &Pubkey,
+    mint_a: &Pubkey,
+    mint_b: &Pubkey,
+    amount_a: u64,
+    amount_b: u64,
+    min_lp: u64
+) -> Instruction {
+    let amm_pool: Pubkey = pda::amm_pool(mint_a, mint_b).0;
+    build(
+        accounts::DepositLiquidity {
+            authority_a: *owner,
+            authority_b: *owner,
+            source_a: get_associated_token_address(owner, mint_a),
+            source_b: get_associated_token_address(owner, mint_b),
+            lp_destination: get_associated_token_address(owner, &pda::lp_mint(&amm_pool).0),
+            amm_pool,
+            vault_a: pda::amm_vault(&amm_pool, mint_a).0,
+            vault_b: pda::amm_vault(&amm_pool, mint_b).0,
+            lp_mint: pda::lp_mint(&amm_pool).0,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::DepositLiquidity { amount_a, amount_b, min_lp }
+    )
+}
+
+// @dev source and destination are associated token accounts of user
+pub fn swap(
+    user: &Pubkey,
+    mint_a: &Pubkey,
+    mint_b: &Pubkey,
+    amount_in: u64,
+    min_out: u64,
+    a_to_b: bool
+) -> Instruction {
+    let amm_pool: Pubkey = pda::amm_pool(mint_a, mint_b).0;
+    let (mint_in, mint_out) = if a_to_b { (mint_a, mint_b) } else { (mint_b, mint_a) };
+    build(
+        accounts::Swap {
+            user: *user,
+            user_source: get_associated_token_address(user, mint_in),
+            user_destination: get_associated_token_address(user, mint_out),
+            amm_pool,
+            vault_a: pda::amm_vault(&amm_pool, mint_a).0,
+            vault_b: pda::amm_vault(&amm_pool, mint_b).0,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::Swap { amount_in, min_out, a_to_b }
+    )
+}
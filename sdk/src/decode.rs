@@ -0,0 +1,108 @@
+use anchor_lang::{ error::ErrorCode, AccountDeserialize, AnchorDeserialize, Result };
+
+// @dev decode raw account data, checks anchor discriminator
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    let mut data: &[u8] = data;
+    T::try_deserialize(&mut data)
+}
+
+// @dev decode return data of a view instruction
+pub fn return_data<T: AnchorDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_from_slice(data).map_err(|_| ErrorCode::InstructionDidNotDeserialize.into())
+}
+
+pub fn purchase_quote(data: &[u8]) -> Result<paid_ignition::PurchaseQuote> {
+    return_data(data)
+}
+
+pub fn claim_quote(data: &[u8]) -> Result<paid_ignition::ClaimQuote> {
+    return_data(data)
+}
+
+pub fn pending_rewards(data: &[u8]) -> Result<u64> {
+    return_data(data)
+}
+
+pub fn ignition_pool(data: &[u8]) -> Result<paid_ignition::Pool> {
+    decode(data)
+}
+
+pub fn buyer(data: &[u8]) -> Result<paid_ignition::Buyer> {
+    decode(data)
+}
+
+pub fn ignition_config(data: &[u8]) -> Result<paid_ignition::Config> {
+    decode(data)
+}
+
+pub fn ignition_role(data: &[u8]) -> Result<paid_ignition::RoleAssignment> {
+    decode(data)
+}
+
+pub fn tge_history(data: &[u8]) -> Result<paid_ignition::TgeHistory> {
+    decode(data)
+}
+
+pub fn claim_history(data: &[u8]) -> Result<paid_ignition::ClaimHistory> {
+    decode(data)
+}
+
+pub fn tip_vault(data: &[u8]) -> Result<paid_ignition::TipVault> {
+    decode(data)
+}
+
+pub fn lp_lock(data: &[u8]) -> Result<paid_ignition::LpLock> {
+    decode(data)
+}
+
+pub fn milestone_plan(data: &[u8]) -> Result<paid_ignition::MilestonePlan> {
+    decode(data)
+}
+
+pub fn proposal(data: &[u8]) -> Result<paid_ignition::Proposal> {
+    decode(data)
+}
+
+pub fn tier_table(data: &[u8]) -> Result<paid_ignition::TierTable> {
+    decode(data)
+}
+
+pub fn pool_metadata(data: &[u8]) -> Result<paid_ignition::PoolMetadata> {
+    decode(data)
+}
+
+pub fn project(data: &[u8]) -> Result<paid_ignition::Project> {
+    decode(data)
+}
+
+pub fn round_buyer(data: &[u8]) -> Result<paid_ignition::RoundBuyer> {
+    decode(data)
+}
+
+pub fn tier_registration(data: &[u8]) -> Result<paid_ignition::TierRegistration> {
+    decode(data)
+}
+
+pub fn amm_pool(data: &[u8]) -> Result<paid_amm::state::AmmPool> {
+    decode(data)
+}
+
+pub fn stake_pool(data: &[u8]) -> Result<paid_stake::states::Pool> {
+    decode(data)
+}
+
+pub fn staker(data: &[u8]) -> Result<paid_stake::states::Staker> {
+    decode(data)
+}
+
+pub fn stake_checkpoints(data: &[u8]) -> Result<paid_stake::states::StakeCheckpoints> {
+    decode(data)
+}
+
+pub fn stake_config(data: &[u8]) -> Result<paid_stake::states::Config> {
+    decode(data)
+}
+
+pub fn stake_role(data: &[u8]) -> Result<paid_stake::states::RoleAssignment> {
+    decode(data)
+}
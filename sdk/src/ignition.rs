@@ -0,0 +1,1310 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{ instruction::{ AccountMeta, Instruction }, system_program, sysvar },
+    InstructionData,
+    ToAccountMetas,
+};
+use anchor_spl::associated_token::{ self, get_associated_token_address };
+use paid_ignition::{
+    accounts,
+    compression,
+    instruction,
+    EligibilityProof,
+    MetadataArgs,
+    Milestone,
+    ProposalKind,
+    Role,
+    RoundParams,
+    Tier,
+    UnsoldPolicy,
+};
+use serde::{ Deserialize, Serialize };
+
+use crate::pda;
+
+// @dev named form of create_pool uints
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolParams {
+    pub max_purchase_amount_for_kyc_user: u64,
+    pub max_purchase_amount_for_not_kyc_user: u64,
+    pub token_fee_percentage: u16,
+    pub early_pool_participation_fee_percentage: u16,
+    pub open_pool_participation_fee_percentage: u16,
+    pub early_pool_proportion: u16,
+    pub open_pool_proportion: u16,
+    pub total_raise_amount: u64,
+    pub early_pool_open_time: i64,
+    // @dev open pool opens when early pool closes
+    pub early_pool_close_time: i64,
+    pub open_pool_close_time: i64,
+    // @dev amount of ido token for 1 purchase token
+    pub rate: u64,
+    pub offer_decimals: u8,
+    pub tge_date: i64,
+    pub tge_percentage: u16,
+    pub vesting_cliff: i64,
+    pub vesting_frequency: i64,
+    pub number_of_vesting: i64,
+}
+
+impl PoolParams {
+    // @dev order expected by create_pool
+    pub fn to_uints(&self) -> [u64; 18] {
+        [
+            self.max_purchase_amount_for_kyc_user,
+            self.max_purchase_amount_for_not_kyc_user,
+            self.token_fee_percentage as u64,
+            self.early_pool_participation_fee_percentage as u64,
+            self.open_pool_participation_fee_percentage as u64,
+            self.early_pool_proportion as u64,
+            self.open_pool_proportion as u64,
+            self.total_raise_amount,
+            self.early_pool_open_time as u64,
+            self.early_pool_close_time as u64,
+            self.open_pool_close_time as u64,
+            self.rate,
+            self.offer_decimals as u64,
+            self.tge_date as u64,
+            self.tge_percentage as u64,
+            self.vesting_cliff as u64,
+            self.vesting_frequency as u64,
+            self.number_of_vesting as u64,
+        ]
+    }
+}
+
+// @dev accounts and proof a round purchase needs, by eligibility mode of round
+#[derive(Debug, Clone)]
+pub enum RoundEligibility {
+    Public,
+    // @dev checkpoints is false if wallet has not staked or withdrawn since checkpoints exist
+    Stake {
+        stake_pool: Pubkey,
+        checkpoints: bool,
+    },
+    // @dev proof nodes ordered from leaf up, none if the tree canopy holds them
+    Kyc {
+        merkle_tree: Pubkey,
+        root: [u8; 32],
+        index: u32,
+        proof: Vec<Pubkey>,
+    },
+    Allowlist {
+        proof: Vec<[u8; 32]>,
+    },
+}
+
+// @dev standing a proposal is opened with, buyers only open refund proposals
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Proposer {
+    Owner,
+    SuperAdmin,
+    Buyer,
+}
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: paid_ignition::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// @dev pool is a fresh keypair and must sign as well
+pub fn create_pool(
+    creator: &Pubkey,
+    pool: &Pubkey,
+    purchase_mint: &Pubkey,
+    offer_mint: &Pubkey,
+    uints: [u64; 18],
+    unsold_policy: UnsoldPolicy,
+    unsold_recipient: &Pubkey,
+    metadata: MetadataArgs
+) -> Instruction {
+    build(
+        accounts::CreatePool {
+            creator: *creator,
+            creator_role: pda::ignition_role(Role::PoolOperator, creator).0,
+            purchase_mint: *purchase_mint,
+            offer_mint: *offer_mint,
+            pool: *pool,
+            pool_metadata: pda::pool_metadata(pool).0,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreatePool {
+            uints,
+            unsold_policy,
+            unsold_recipient: *unsold_recipient,
+            metadata,
+        }
+    )
+}
+
+pub fn fund_offer(
+    owner: &Pubkey,
+    pool: &Pubkey,
+    offer_mint: &Pubkey,
+    owner_token: &Pubkey,
+    amount: u64
+) -> Instruction {
+    build(
+        accounts::FundOffer {
+            owner: *owner,
+            funder_role: pda::ignition_role(Role::Funder, owner).0,
+            offer_mint: *offer_mint,
+            owner_token: *owner_token,
+            config: pda::ignition_config().0,
+            pool: *pool,
+            offer_vault: pda::offer_vault(pool).0,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::FundOffer { amount }
+    )
+}
+
+// @dev proposal is an approved extend tge proposal, required if pool asks for one
+// @dev operator is true if authority acts through its pool operator role, false if pool owner
+pub fn update_tge_date(
+    authority: &Pubkey,
+    pool: &Pubkey,
+    tge_date: i64,
+    proposal: Option<&Pubkey>,
+    operator: bool
+) -> Instruction {
+    build(
+        accounts::UpdateTgeDate {
+            authority: *authority,
+            operator_role: operator.then(|| pda::ignition_role(Role::PoolOperator, authority).0),
+            pool: *pool,
+            tge_history: pda::tge_history(pool).0,
+            proposal: proposal.copied(),
+            system_program: system_program::ID,
+        },
+        instruction::UpdateTgeDate { tge_date }
+    )
+}
+
+// @dev operator is true if authority acts through its pool operator role, false if pool owner
+pub fn update_time(
+    authority: &Pubkey,
+    pool: &Pubkey,
+    early_pool_close_time: i64,
+    open_pool_close_time: i64,
+    operator: bool
+) -> Instruction {
+    build(
+        accounts::UpdateTime {
+            authority: *authority,
+            operator_role: operator.then(|| pda::ignition_role(Role::PoolOperator, authority).0),
+            pool: *pool,
+        },
+        instruction::UpdateTime { early_pool_close_time, open_pool_close_time }
+    )
+}
+
+// @dev tiered is true if pool allocates early pool by registered stake tiers
+pub fn buy_in_early_pool(
+    signer: &Pubkey,
+    pool: &Pubkey,
+    purchase_mint: &Pubkey,
+    user_purchase_token: &Pubkey,
+    purchase_amount: u64,
+    tiered: bool
+) -> Instruction {
+    build(
+        accounts::BuyInEarlyPool {
+            signer: *signer,
+            purchase_mint: *purchase_mint,
+            user_purchase_token: *user_purchase_token,
+            config: pda::ignition_config().0,
+            pool: *pool,
+            purchase_vault: pda::purchase_vault(pool).0,
+            buyer: pda::buyer(pool, signer).0,
+            tier_table: tiered.then(|| pda::tier_table(pool).0),
+            tier_registration: tiered.then(|| pda::tier_registration(pool, signer).0),
+            token_program: anchor_spl::token::ID,
+            clock: sysvar::clock::ID,
+            system_program: system_program::ID,
+        },
+        instruction::BuyInEarlyPool { purchase_amount }
+    )
+}
+
+pub fn buy_in_open_pool(
+    signer: &Pubkey,
+    pool: &Pubkey,
+    purchase_mint: &Pubkey,
+    user_purchase_token: &Pubkey,
+    purchase_amount: u64
+) -> Instruction {
+    build(
+        accounts::BuyInOpenPool {
+            signer: *signer,
+            purchase_mint: *purchase_mint,
+            user_purchase_token: *user_purchase_token,
+            config: pda::ignition_config().0,
+            pool: *pool,
+            purchase_vault: pda::purchase_vault(pool).0,
+            buyer: pda::buyer(pool, signer).0,
+            token_program: anchor_spl::token::ID,
+            clock: sysvar::clock::ID,
+            system_program: system_program::ID,
+        },
+        instruction::BuyInOpenPool { purchase_amount }
+    )
+}
+
+pub fn cancel_purchase(
+    signer: &Pubkey,
+    pool: &Pubkey,
+    user_purchase_token: &Pubkey
+) -> Instruction {
+    build(
+        accounts::CancelPurchase {
+            signer: *signer,
+            user_purchase_token: *user_purchase_token,
+            config: pda::ignition_config().0,
+            pool: *pool,
+            buyer: pda::buyer(pool, signer).0,
+            purchase_vault: pda::purchase_vault(pool).0,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CancelPurchase {}
+    )
+}
+
+pub fn user_withdraw_purchase(
+    signer: &Pubkey,
+    pool: &Pubkey,
+    user_purchase_token: &Pubkey,
+    amount: u64
+) -> Instruction {
+    build(
+        accounts::UserWithdrawPurchase {
+            signer: *signer,
+            user_purchase_token: *user_purchase_token,
+            pool: *pool,
+            buyer: pda::buyer(pool, signer).0,
+            purchase_vault: pda::purchase_vault(pool).0,
+            associated_token_program: associated_token::ID,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::UserWithdrawPurchase { amount }
+    )
+}
+
+// @dev owner_offer_token belongs to pool owner whoever signs
+// @dev operator is true if authority acts through its pool operator role, false if pool owner
+pub fn withdraw_offer(
+    authority: &Pubkey,
+    pool: &Pubkey,
+    offer_mint: &Pubkey,
+    owner_offer_token: &Pubkey,
+    amount: u64,
+    operator: bool
+) -> Instruction {
+    build(
+        accounts::WithdrawOffer {
+            authority: *authority,
+            operator_role: operator.then(|| pda::ignition_role(Role::PoolOperator, authority).0),
+            owner_offer_token: *owner_offer_token,
+            offer_mint: *offer_mint,
+            offer_vault: pda::offer_vault(pool).0,
+            config: pda::ignition_config().0,
+            pool: *pool,
+            associated_token_program: associated_token::ID,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::WithdrawOffer { amount }
+    )
+}
+
+// @dev ido token lands in associated token account of signer
+// @dev destination is the ido token account of unsold destination, none if burned
+pub fn finalize_unsold(pool: &Pubkey, offer_mint: &Pubkey, destination: Option<&Pubkey>) -> Instruction {
+    build(
+        accounts::FinalizeUnsold {
+            offer_mint: *offer_mint,
+            config: pda::ignition_config().0,
+            pool: *pool,
+            offer_vault: pda::offer_vault(pool).0,
+            destination: destination.copied(),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::FinalizeUnsold {}
+    )
+}
+
+// @dev zero percentage with default amm program turns seeding off
+pub fn set_liquidity_config(
+    owner: &Pubkey,
+    pool: &Pubkey,
+    liquidity_percentage: u16,
+    amm_program: &Pubkey,
+    lp_lock_duration: i64
+) -> Instruction {
+    build(
+        accounts::SetLiquidityConfig {
+            owner: *owner,
+            config: pda::ignition_config().0,
+            pool: *pool,
+        },
+        instruction::SetLiquidityConfig {
+            liquidity_percentage,
+            amm_program: *amm_program,
+            lp_lock_duration,
+        }
+    )
+}
+
+// @dev seeds the paidAmm pool of purchase and ido token, it must exist already
+pub fn seed_liquidity(
+    owner: &Pubkey,
+    pool: &Pubkey,
+    purchase_mint: &Pubkey,
+    offer_mint: &Pubkey,
+    min_lp: u64
+) -> Instruction {
+    let amm_pool: Pubkey = pda::amm_pool(purchase_mint, offer_mint).0;
+    build(
+        accounts::SeedLiquidity {
+            owner: *owner,
+            config: pda::ignition_config().0,
+            pool: *pool,
+            purchase_mint: *purchase_mint,
+            offer_mint: *offer_mint,
+            purchase_vault: pda::purchase_vault(pool).0,
+            offer_vault: pda::offer_vault(pool).0,
+            liquidity_authority: pda::liquidity_authority(pool).0,
+            purchase_escrow: pda::liquidity_escrow(pool, purchase_mint).0,
+            offer_escrow: pda::liquidity_escrow(pool, offer_mint).0,
+            amm_program: paid_amm::ID,
+            amm_pool,
+            amm_vault_a: pda::amm_vault(&amm_pool, purchase_mint).0,
+            amm_vault_b: pda::amm_vault(&amm_pool, offer_mint).0,
+            lp_mint: pda::lp_mint(&amm_pool).0,
+            lp_lock: pda::lp_lock(pool).0,
+            lp_vault: pda::lp_vault(pool).0,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::SeedLiquidity { min_lp }
+    )
+}
+
+// @dev lp token goes to associated account of owner
+pub fn withdraw_locked_lp(owner: &Pubkey, pool: &Pubkey, lp_mint: &Pubkey) -> Instruction {
+    build(
+        accounts::WithdrawLockedLp {
+            owner: *owner,
+            pool: *pool,
+            lp_lock: pda::lp_lock(pool).0,
+            lp_vault: pda::lp_vault(pool).0,
+            lp_mint: *lp_mint,
+            owner_lp_token: get_associated_token_address(owner, lp_mint),
+            token_program: anchor_spl::token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::WithdrawLockedLp {}
+    )
+}
+
+pub fn declare_milestones(owner: &Pubkey, pool: &Pubkey, milestones: Vec<Milestone>) -> Instruction {
+    build(
+        accounts::DeclareMilestones {
+            owner: *owner,
+            pool: *pool,
+            milestone_plan: pda::milestone_plan(pool).0,
+            system_program: system_program::ID,
+        },
+        instruction::DeclareMilestones { milestones }
+    )
+}
+
+// @dev purchase token goes to associated account of owner, creates single tranche plan if none declared
+pub fn release_milestone(owner: &Pubkey, pool: &Pubkey, purchase_mint: &Pubkey) -> Instruction {
+    build(
+        accounts::ReleaseMilestone {
+            owner: *owner,
+            pool: *pool,
+            milestone_plan: pda::milestone_plan(pool).0,
+            purchase_vault: pda::purchase_vault(pool).0,
+            owner_purchase_token: get_associated_token_address(owner, purchase_mint),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ReleaseMilestone {}
+    )
+}
+
+pub fn approve_refund(signer: &Pubkey, pool: &Pubkey, proposal: &Pubkey) -> Instruction {
+    build(
+        accounts::ApproveRefund {
+            signer: *signer,
+            pool: *pool,
+            milestone_plan: pda::milestone_plan(pool).0,
+            proposal: *proposal,
+            system_program: system_program::ID,
+        },
+        instruction::ApproveRefund {}
+    )
+}
+
+// @dev purchase token goes to associated account of wallet
+pub fn claim_milestone_refund(wallet: &Pubkey, pool: &Pubkey, purchase_mint: &Pubkey) -> Instruction {
+    build(
+        accounts::ClaimMilestoneRefund {
+            signer: *wallet,
+            pool: *pool,
+            buyer: pda::buyer(pool, wallet).0,
+            milestone_plan: pda::milestone_plan(pool).0,
+            purchase_vault: pda::purchase_vault(pool).0,
+            user_purchase_token: get_associated_token_address(wallet, purchase_mint),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ClaimMilestoneRefund {}
+    )
+}
+
+// @dev index is proposal count of pool, admin is true if proposer opens it as super admin
+pub fn create_proposal(
+    proposer: &Pubkey,
+    pool: &Pubkey,
+    index: u32,
+    role: Proposer,
+    kind: ProposalKind,
+    deadline: i64,
+    quorum: u16
+) -> Instruction {
+    build(
+        accounts::CreateProposal {
+            proposer: *proposer,
+            admin_role: (role == Proposer::SuperAdmin).then(|| {
+                pda::ignition_role(Role::SuperAdmin, proposer).0
+            }),
+            pool: *pool,
+            buyer: (role == Proposer::Buyer).then(|| pda::buyer(pool, proposer).0),
+            proposal: pda::proposal(pool, index).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateProposal { kind, deadline, quorum }
+    )
+}
+
+pub fn cast_vote(wallet: &Pubkey, pool: &Pubkey, proposal: &Pubkey, approve: bool) -> Instruction {
+    build(
+        accounts::CastVote {
+            signer: *wallet,
+            pool: *pool,
+            buyer: pda::buyer(pool, wallet).0,
+            proposal: *proposal,
+            proposal_vote: pda::proposal_vote(proposal, wallet).0,
+            system_program: system_program::ID,
+        },
+        instruction::CastVote { approve }
+    )
+}
+
+pub fn finalize_proposal(pool: &Pubkey, proposal: &Pubkey) -> Instruction {
+    build(
+        accounts::FinalizeProposal { pool: *pool, proposal: *proposal },
+        instruction::FinalizeProposal {}
+    )
+}
+
+// @dev proposer must match proposal.proposer
+pub fn close_proposal(proposer: &Pubkey, pool: &Pubkey, proposal: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseProposal { proposer: *proposer, pool: *pool, proposal: *proposal },
+        instruction::CloseProposal {}
+    )
+}
+
+pub fn close_proposal_vote(wallet: &Pubkey, proposal: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseProposalVote {
+            signer: *wallet,
+            proposal: *proposal,
+            proposal_vote: pda::proposal_vote(proposal, wallet).0,
+        },
+        instruction::CloseProposalVote {}
+    )
+}
+
+pub fn set_tge_governance(owner: &Pubkey, pool: &Pubkey, requires_proposal: bool) -> Instruction {
+    build(
+        accounts::SetTgeGovernance { owner: *owner, pool: *pool },
+        instruction::SetTgeGovernance { requires_proposal }
+    )
+}
+
+// @dev zero window with zero percentage turns protection off
+pub fn set_protection_config(
+    owner: &Pubkey,
+    pool: &Pubkey,
+    protection_window: i64,
+    protection_percentage: u16
+) -> Instruction {
+    build(
+        accounts::SetProtectionConfig { owner: *owner, pool: *pool },
+        instruction::SetProtectionConfig { protection_window, protection_percentage }
+    )
+}
+
+// @dev purchase token goes to associated account of wallet
+pub fn claim_price_protection(wallet: &Pubkey, pool: &Pubkey, purchase_mint: &Pubkey) -> Instruction {
+    build(
+        accounts::ClaimPriceProtection {
+            signer: *wallet,
+            config: pda::ignition_config().0,
+            pool: *pool,
+            buyer: pda::buyer(pool, wallet).0,
+            purchase_vault: pda::purchase_vault(pool).0,
+            user_purchase_token: get_associated_token_address(wallet, purchase_mint),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ClaimPriceProtection {}
+    )
+}
+
+// @dev purchase token goes to associated account of owner
+pub fn reclaim_protection_reserve(
+    owner: &Pubkey,
+    pool: &Pubkey,
+    purchase_mint: &Pubkey
+) -> Instruction {
+    build(
+        accounts::ReclaimProtectionReserve {
+            owner: *owner,
+            pool: *pool,
+            purchase_vault: pda::purchase_vault(pool).0,
+            owner_purchase_token: get_associated_token_address(owner, purchase_mint),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ReclaimProtectionReserve {}
+    )
+}
+
+// @dev empty tiers turn tiered allocation off, otherwise snapshot time before early pool opens
+pub fn set_tier_table(
+    owner: &Pubkey,
+    pool: &Pubkey,
+    stake_pool: &Pubkey,
+    tiers: Vec<Tier>,
+    snapshot_time: i64,
+    continuous_stake: bool
+) -> Instruction {
+    build(
+        accounts::SetTierTable {
+            owner: *owner,
+            pool: *pool,
+            tier_table: pda::tier_table(pool).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetTierTable {
+            stake_pool: *stake_pool,
+            tiers,
+            snapshot_time,
+            continuous_stake,
+        }
+    )
+}
+
+// @dev metadata is replaced as a whole
+pub fn update_pool_metadata(owner: &Pubkey, pool: &Pubkey, metadata: MetadataArgs) -> Instruction {
+    build(
+        accounts::UpdatePoolMetadata {
+            owner: *owner,
+            pool: *pool,
+            pool_metadata: pda::pool_metadata(pool).0,
+        },
+        instruction::UpdatePoolMetadata { metadata }
+    )
+}
+
+// @dev stake pool is the paidStake pool of the tier table
+// @dev checkpoints is false if wallet has not staked or withdrawn since checkpoints exist
+pub fn register_tier(
+    wallet: &Pubkey,
+    pool: &Pubkey,
+    stake_pool: &Pubkey,
+    checkpoints: bool
+) -> Instruction {
+    build(
+        accounts::RegisterTier {
+            signer: *wallet,
+            pool: *pool,
+            tier_table: pda::tier_table(pool).0,
+            staker: pda::staker(stake_pool, wallet).0,
+            checkpoints: checkpoints.then(|| pda::stake_checkpoints(stake_pool, wallet).0),
+            tier_registration: pda::tier_registration(pool, wallet).0,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterTier {}
+    )
+}
+
+// @dev signer is the buyer wallet or its delegate, destination wallet is the buyer claim destination
+pub fn claim_offer(
+    signer: &Pubkey,
+    wallet: &Pubkey,
+    destination_wallet: &Pubkey,
+    pool: &Pubkey,
+    offer_mint: &Pubkey,
+    amount: u64
+) -> Instruction {
+    build(
+        accounts::ClaimOffer {
+            signer: *signer,
+            wallet: *wallet,
+            offer_mint: *offer_mint,
+            config: pda::ignition_config().0,
+            pool: *pool,
+            buyer: pda::buyer(pool, wallet).0,
+            claim_history: pda::claim_history(pool, wallet).0,
+            destination_wallet: *destination_wallet,
+            user_token: get_associated_token_address(destination_wallet, offer_mint),
+            offer_vault: pda::offer_vault(pool).0,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: associated_token::ID,
+            clock: sysvar::clock::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimOffer { amount }
+    )
+}
+
+// @dev default pubkey clears delegate or destination
+pub fn set_claim_settings(
+    wallet: &Pubkey,
+    pool: &Pubkey,
+    delegate: &Pubkey,
+    destination: &Pubkey
+) -> Instruction {
+    build(
+        accounts::SetClaimSettings {
+            signer: *wallet,
+            pool: *pool,
+            buyer: pda::buyer(pool, wallet).0,
+        },
+        instruction::SetClaimSettings { delegate: *delegate, destination: *destination }
+    )
+}
+
+// @dev admin must be the upgrade authority of program
+pub fn init_config(admin: &Pubkey) -> Instruction {
+    build(
+        accounts::InitConfig {
+            admin: *admin,
+            program_data: pda::program_data(&paid_ignition::ID).0,
+            config: pda::ignition_config().0,
+            admin_role: pda::ignition_role(Role::SuperAdmin, admin).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitConfig {}
+    )
+}
+
+pub fn grant_role(admin: &Pubkey, role: Role, holder: &Pubkey) -> Instruction {
+    build(
+        accounts::GrantRole {
+            admin: *admin,
+            admin_role: pda::ignition_role(Role::SuperAdmin, admin).0,
+            role_assignment: pda::ignition_role(role, holder).0,
+            system_program: system_program::ID,
+        },
+        instruction::GrantRole { role, holder: *holder }
+    )
+}
+
+pub fn revoke_role(admin: &Pubkey, role: Role, holder: &Pubkey) -> Instruction {
+    build(
+        accounts::RevokeRole {
+            admin: *admin,
+            admin_role: pda::ignition_role(Role::SuperAdmin, admin).0,
+            role_assignment: pda::ignition_role(role, holder).0,
+        },
+        instruction::RevokeRole { role, holder: *holder }
+    )
+}
+
+pub fn set_guardian(admin: &Pubkey, guardian: &Pubkey) -> Instruction {
+    build(
+        accounts::SetGuardian {
+            admin: *admin,
+            admin_role: pda::ignition_role(Role::SuperAdmin, admin).0,
+            config: pda::ignition_config().0,
+        },
+        instruction::SetGuardian { guardian: *guardian }
+    )
+}
+
+// @dev replaces the whole allowlist of amm programs
+pub fn set_amm_programs(admin: &Pubkey, amm_programs: Vec<Pubkey>) -> Instruction {
+    build(
+        accounts::SetAmmPrograms {
+            admin: *admin,
+            admin_role: pda::ignition_role(Role::SuperAdmin, admin).0,
+            config: pda::ignition_config().0,
+        },
+        instruction::SetAmmPrograms { amm_programs }
+    )
+}
+
+// @dev signed by pauser role holder
+pub fn set_paused(pauser: &Pubkey, paused: bool) -> Instruction {
+    build(
+        accounts::SetPaused {
+            pauser: *pauser,
+            pauser_role: Some(pda::ignition_role(Role::Pauser, pauser).0),
+            config: pda::ignition_config().0,
+        },
+        instruction::SetPaused { paused }
+    )
+}
+
+pub fn set_pool_paused(pauser: &Pubkey, pool: &Pubkey, paused: bool) -> Instruction {
+    build(
+        accounts::SetPoolPaused {
+            pauser: *pauser,
+            pauser_role: Some(pda::ignition_role(Role::Pauser, pauser).0),
+            config: pda::ignition_config().0,
+            pool: *pool,
+        },
+        instruction::SetPoolPaused { paused }
+    )
+}
+
+// @dev fee_manager is true if authority acts through its fee manager role, false if pool owner
+pub fn update_fees(
+    authority: &Pubkey,
+    pool: &Pubkey,
+    token_fee_percentage: u16,
+    early_pool_participation_fee_percentage: u16,
+    open_pool_participation_fee_percentage: u16,
+    fee_manager: bool
+) -> Instruction {
+    build(
+        accounts::UpdateFees {
+            authority: *authority,
+            fee_manager_role: fee_manager.then(|| {
+                pda::ignition_role(Role::FeeManager, authority).0
+            }),
+            pool: *pool,
+        },
+        instruction::UpdateFees {
+            token_fee_percentage,
+            early_pool_participation_fee_percentage,
+            open_pool_participation_fee_percentage,
+        }
+    )
+}
+
+pub fn propose_owner(owner: &Pubkey, pool: &Pubkey, pending_owner: &Pubkey) -> Instruction {
+    build(
+        accounts::ProposeOwner { owner: *owner, pool: *pool },
+        instruction::ProposeOwner { pending_owner: *pending_owner }
+    )
+}
+
+pub fn accept_owner(pending_owner: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptOwner { pending_owner: *pending_owner, pool: *pool },
+        instruction::AcceptOwner {}
+    )
+}
+
+pub fn cancel_owner_transfer(owner: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelOwnerTransfer { owner: *owner, pool: *pool },
+        instruction::CancelOwnerTransfer {}
+    )
+}
+
+pub fn close_buyer(signer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseBuyer {
+            signer: *signer,
+            pool: *pool,
+            buyer: pda::buyer(pool, signer).0,
+        },
+        instruction::CloseBuyer {}
+    )
+}
+
+// @dev buyer account of signer must be closed first, rent_payer must match claim_history.rent_payer
+pub fn close_claim_history(signer: &Pubkey, rent_payer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseClaimHistory {
+            signer: *signer,
+            rent_payer: *rent_payer,
+            pool: *pool,
+            buyer: pda::buyer(pool, signer).0,
+            claim_history: pda::claim_history(pool, signer).0,
+        },
+        instruction::CloseClaimHistory {}
+    )
+}
+
+// @dev rent_payer must match pool.purchase_vault_payer
+pub fn close_purchase_vault(rent_payer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::ClosePurchaseVault {
+            rent_payer: *rent_payer,
+            pool: *pool,
+            purchase_vault: pda::purchase_vault(pool).0,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ClosePurchaseVault {}
+    )
+}
+
+// @dev rent_payer must match pool.offer_vault_payer
+pub fn close_offer_vault(rent_payer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseOfferVault {
+            rent_payer: *rent_payer,
+            pool: *pool,
+            offer_vault: pda::offer_vault(pool).0,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CloseOfferVault {}
+    )
+}
+
+// @dev rent_payer must match milestone_plan.rent_payer, purchase vault must be closed first
+pub fn close_milestone_plan(rent_payer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseMilestonePlan {
+            rent_payer: *rent_payer,
+            pool: *pool,
+            milestone_plan: pda::milestone_plan(pool).0,
+            purchase_vault: pda::purchase_vault(pool).0,
+        },
+        instruction::CloseMilestonePlan {}
+    )
+}
+
+// @dev rent_payer must match tier_table.rent_payer
+pub fn close_tier_table(rent_payer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseTierTable {
+            rent_payer: *rent_payer,
+            pool: *pool,
+            tier_table: pda::tier_table(pool).0,
+        },
+        instruction::CloseTierTable {}
+    )
+}
+
+pub fn close_tier_registration(signer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseTierRegistration {
+            signer: *signer,
+            pool: *pool,
+            tier_registration: pda::tier_registration(pool, signer).0,
+        },
+        instruction::CloseTierRegistration {}
+    )
+}
+
+// @dev remainder goes to associated accounts of owner, pass milestone_plan once the plan exists
+pub fn sweep_vaults(
+    owner: &Pubkey,
+    pool: &Pubkey,
+    purchase_mint: &Pubkey,
+    offer_mint: &Pubkey,
+    milestone_plan: bool
+) -> Instruction {
+    build(
+        accounts::SweepVaults {
+            owner: *owner,
+            pool: *pool,
+            milestone_plan: milestone_plan.then(|| pda::milestone_plan(pool).0),
+            offer_vault: pda::offer_vault(pool).0,
+            purchase_vault: pda::purchase_vault(pool).0,
+            owner_offer_token: get_associated_token_address(owner, offer_mint),
+            owner_purchase_token: get_associated_token_address(owner, purchase_mint),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::SweepVaults {}
+    )
+}
+
+// @dev rent_payer must match pool.rent_payer
+pub fn close_pool(owner: &Pubkey, rent_payer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::ClosePool {
+            owner: *owner,
+            rent_payer: *rent_payer,
+            pool: *pool,
+            pool_metadata: pda::pool_metadata(pool).0,
+            purchase_vault: pda::purchase_vault(pool).0,
+            offer_vault: pda::offer_vault(pool).0,
+        },
+        instruction::ClosePool {}
+    )
+}
+
+// @dev operator is true if authority acts through its pool operator role, false if pool owner
+pub fn enable_claims(authority: &Pubkey, pool: &Pubkey, operator: bool) -> Instruction {
+    build(
+        accounts::EnableClaims {
+            authority: *authority,
+            operator_role: operator.then(|| pda::ignition_role(Role::PoolOperator, authority).0),
+            pool: *pool,
+        },
+        instruction::EnableClaims {}
+    )
+}
+
+// @dev operator is true if authority acts through its pool operator role, false if pool owner
+pub fn cancel_pool(authority: &Pubkey, pool: &Pubkey, operator: bool) -> Instruction {
+    build(
+        accounts::CancelPool {
+            authority: *authority,
+            operator_role: operator.then(|| pda::ignition_role(Role::PoolOperator, authority).0),
+            pool: *pool,
+        },
+        instruction::CancelPool {}
+    )
+}
+
+// @dev operator is true if authority acts through its pool operator role, false if pool owner
+pub fn finalize_pool(authority: &Pubkey, pool: &Pubkey, operator: bool) -> Instruction {
+    build(
+        accounts::FinalizePool {
+            authority: *authority,
+            operator_role: operator.then(|| pda::ignition_role(Role::PoolOperator, authority).0),
+            pool: *pool,
+        },
+        instruction::FinalizePool {}
+    )
+}
+
+pub fn refresh_pool_status(pool: &Pubkey) -> Instruction {
+    build(accounts::RefreshPoolStatus { pool: *pool }, instruction::RefreshPoolStatus {})
+}
+
+// @dev pushes vested offer token of wallets to their associated offer token accounts
+// @dev wallets without one are skipped with CrankClaimSkipped, create it first to include them
+pub fn crank_claims(
+    cranker: &Pubkey,
+    pool: &Pubkey,
+    offer_mint: &Pubkey,
+    wallets: &[Pubkey],
+    tip_vault: bool
+) -> Instruction {
+    let mut ix: Instruction = build(
+        accounts::CrankClaims {
+            cranker: *cranker,
+            offer_mint: *offer_mint,
+            config: pda::ignition_config().0,
+            pool: *pool,
+            offer_vault: pda::offer_vault(pool).0,
+            tip_vault: tip_vault.then(|| pda::tip_vault(pool).0),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CrankClaims {}
+    );
+    for wallet in wallets {
+        ix.accounts.push(AccountMeta::new(pda::buyer(pool, wallet).0, false));
+        ix.accounts.push(
+            AccountMeta::new(get_associated_token_address(wallet, offer_mint), false)
+        );
+    }
+    ix
+}
+
+pub fn fund_tip_vault(owner: &Pubkey, pool: &Pubkey, tip_per_claim: u64, amount: u64) -> Instruction {
+    build(
+        accounts::FundTipVault {
+            owner: *owner,
+            pool: *pool,
+            tip_vault: pda::tip_vault(pool).0,
+            system_program: system_program::ID,
+        },
+        instruction::FundTipVault { tip_per_claim, amount }
+    )
+}
+
+pub fn close_tip_vault(owner: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseTipVault {
+            owner: *owner,
+            pool: *pool,
+            tip_vault: pda::tip_vault(pool).0,
+        },
+        instruction::CloseTipVault {}
+    )
+}
+
+// @dev simulate and decode return data as PurchaseQuote
+// @dev wallet adds its per-wallet remaining amount, pass bought and registered once its buyer and tier registration exist
+pub fn quote_purchase(
+    pool: &Pubkey,
+    purchase_amount: u64,
+    wallet: Option<&Pubkey>,
+    bought: bool,
+    registered: bool
+) -> Instruction {
+    build(
+        accounts::QuotePurchase {
+            pool: *pool,
+            buyer: wallet.filter(|_| bought).map(|wallet| pda::buyer(pool, wallet).0),
+            tier_table: wallet.filter(|_| registered).map(|_| pda::tier_table(pool).0),
+            tier_registration: wallet
+                .filter(|_| registered)
+                .map(|wallet| pda::tier_registration(pool, wallet).0),
+        },
+        instruction::QuotePurchase { purchase_amount }
+    )
+}
+
+// @dev simulate and decode return data as ClaimQuote
+pub fn quote_claimable(pool: &Pubkey, wallet: &Pubkey) -> Instruction {
+    build(
+        accounts::QuoteClaimable {
+            wallet: *wallet,
+            pool: *pool,
+            buyer: pda::buyer(pool, wallet).0,
+        },
+        instruction::QuoteClaimable {}
+    )
+}
+
+// @dev project is a fresh keypair and must sign as well
+pub fn create_project(
+    creator: &Pubkey,
+    project: &Pubkey,
+    purchase_mint: &Pubkey,
+    offer_mint: &Pubkey
+) -> Instruction {
+    build(
+        accounts::CreateProject {
+            creator: *creator,
+            creator_role: pda::ignition_role(Role::PoolOperator, creator).0,
+            purchase_mint: *purchase_mint,
+            offer_mint: *offer_mint,
+            project: *project,
+            system_program: system_program::ID,
+        },
+        instruction::CreateProject {}
+    )
+}
+
+pub fn add_round(owner: &Pubkey, project: &Pubkey, params: RoundParams) -> Instruction {
+    build(
+        accounts::AddRound { owner: *owner, project: *project },
+        instruction::AddRound { params }
+    )
+}
+
+pub fn fund_project(
+    owner: &Pubkey,
+    project: &Pubkey,
+    offer_mint: &Pubkey,
+    owner_token: &Pubkey,
+    amount: u64
+) -> Instruction {
+    build(
+        accounts::FundProject {
+            owner: *owner,
+            funder_role: pda::ignition_role(Role::Funder, owner).0,
+            offer_mint: *offer_mint,
+            owner_token: *owner_token,
+            config: pda::ignition_config().0,
+            project: *project,
+            offer_vault: pda::project_offer_vault(project).0,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::FundProject { amount }
+    )
+}
+
+// @dev purchase token is paid from associated token account of wallet
+pub fn buy_in_round(
+    wallet: &Pubkey,
+    project: &Pubkey,
+    purchase_mint: &Pubkey,
+    round: u8,
+    purchase_amount: u64,
+    eligibility: RoundEligibility
+) -> Instruction {
+    let (staker, checkpoints) = match &eligibility {
+        RoundEligibility::Stake { stake_pool, checkpoints } =>
+            (
+                Some(pda::staker(stake_pool, wallet).0),
+                checkpoints.then(|| pda::stake_checkpoints(stake_pool, wallet).0),
+            ),
+        _ => (None, None),
+    };
+    let (merkle_tree, compression_program) = match &eligibility {
+        RoundEligibility::Kyc { merkle_tree, .. } => (Some(*merkle_tree), Some(compression::ID)),
+        _ => (None, None),
+    };
+    let (proof, proof_nodes) = match eligibility {
+        RoundEligibility::Kyc { root, index, proof, .. } => (EligibilityProof::Kyc { root, index }, proof),
+        RoundEligibility::Allowlist { proof } => (EligibilityProof::Allowlist { proof }, Vec::new()),
+        _ => (EligibilityProof::None, Vec::new()),
+    };
+    let mut ix: Instruction = build(
+        accounts::BuyInRound {
+            signer: *wallet,
+            purchase_mint: *purchase_mint,
+            user_purchase_token: get_associated_token_address(wallet, purchase_mint),
+            config: pda::ignition_config().0,
+            project: *project,
+            purchase_vault: pda::project_purchase_vault(project).0,
+            round_buyer: pda::round_buyer(project, round, wallet).0,
+            staker,
+            checkpoints,
+            merkle_tree,
+            compression_program,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::BuyInRound { round, purchase_amount, proof }
+    );
+    ix.accounts.extend(proof_nodes.iter().map(|node| AccountMeta::new_readonly(*node, false)));
+    ix
+}
+
+// @dev ido token lands in associated token account of wallet
+pub fn claim_round_offer(
+    wallet: &Pubkey,
+    project: &Pubkey,
+    offer_mint: &Pubkey,
+    round: u8,
+    amount: u64
+) -> Instruction {
+    build(
+        accounts::ClaimRoundOffer {
+            signer: *wallet,
+            offer_mint: *offer_mint,
+            config: pda::ignition_config().0,
+            project: *project,
+            round_buyer: pda::round_buyer(project, round, wallet).0,
+            user_token: get_associated_token_address(wallet, offer_mint),
+            offer_vault: pda::project_offer_vault(project).0,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimRoundOffer { round, amount }
+    )
+}
+
+// @dev purchase token goes to associated account of owner
+pub fn withdraw_round_raise(
+    owner: &Pubkey,
+    project: &Pubkey,
+    purchase_mint: &Pubkey,
+    round: u8
+) -> Instruction {
+    build(
+        accounts::WithdrawRoundRaise {
+            owner: *owner,
+            config: pda::ignition_config().0,
+            project: *project,
+            purchase_vault: pda::project_purchase_vault(project).0,
+            owner_purchase_token: get_associated_token_address(owner, purchase_mint),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::WithdrawRoundRaise { round }
+    )
+}
+
+// @dev ido token goes to associated account of owner
+pub fn withdraw_project_offer(owner: &Pubkey, project: &Pubkey, offer_mint: &Pubkey) -> Instruction {
+    build(
+        accounts::WithdrawProjectOffer {
+            owner: *owner,
+            config: pda::ignition_config().0,
+            project: *project,
+            offer_vault: pda::project_offer_vault(project).0,
+            owner_token: get_associated_token_address(owner, offer_mint),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::WithdrawProjectOffer {}
+    )
+}
+
+// @dev round allocation of signer must be fully claimed
+pub fn close_round_buyer(signer: &Pubkey, project: &Pubkey, round: u8) -> Instruction {
+    build(
+        accounts::CloseRoundBuyer {
+            signer: *signer,
+            project: *project,
+            round_buyer: pda::round_buyer(project, round, signer).0,
+        },
+        instruction::CloseRoundBuyer { round }
+    )
+}
+
+// @dev rent_payer must match project.purchase_vault_payer
+pub fn close_project_purchase_vault(rent_payer: &Pubkey, project: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseProjectPurchaseVault {
+            rent_payer: *rent_payer,
+            project: *project,
+            purchase_vault: pda::project_purchase_vault(project).0,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CloseProjectPurchaseVault {}
+    )
+}
+
+// @dev rent_payer must match project.offer_vault_payer
+pub fn close_project_offer_vault(rent_payer: &Pubkey, project: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseProjectOfferVault {
+            rent_payer: *rent_payer,
+            project: *project,
+            offer_vault: pda::project_offer_vault(project).0,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CloseProjectOfferVault {}
+    )
+}
+
+// @dev both project vaults must be closed or never created
+pub fn close_project(owner: &Pubkey, rent_payer: &Pubkey, project: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseProject {
+            owner: *owner,
+            rent_payer: *rent_payer,
+            project: *project,
+            purchase_vault: pda::project_purchase_vault(project).0,
+            offer_vault: pda::project_offer_vault(project).0,
+        },
+        instruction::CloseProject {}
+    )
+}
+
+// @dev default pending owner withdraws the nomination
+pub fn propose_project_owner(
+    owner: &Pubkey,
+    project: &Pubkey,
+    pending_owner: &Pubkey
+) -> Instruction {
+    build(
+        accounts::ProposeProjectOwner { owner: *owner, project: *project },
+        instruction::ProposeProjectOwner { pending_owner: *pending_owner }
+    )
+}
+
+pub fn accept_project_owner(pending_owner: &Pubkey, project: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptProjectOwner { pending_owner: *pending_owner, project: *project },
+        instruction::AcceptProjectOwner {}
+    )
+}
@@ -0,0 +1,67 @@
+use anchor_lang::{ prelude::Pubkey, solana_program::instruction::Instruction };
+use kyc_anchor_lang::{ InstructionData, ToAccountMetas };
+use kyc_merkle_tree::{ accounts, instruction };
+
+use crate::pda;
+
+// @dev account size for a tree without canopy
+pub fn merkle_tree_account_size(max_depth: u32, max_buffer_size: u32) -> usize {
+    let depth: usize = max_depth as usize;
+    // root, path, index and padding
+    let change_log: usize = 32 + 32 * depth + 8;
+    // proof, leaf, index and padding
+    let rightmost_proof: usize = 32 * depth + 32 + 8;
+    spl_account_compression::state::CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1 +
+        24 +
+        (max_buffer_size as usize) * change_log +
+        rightmost_proof
+}
+
+fn note_accounts(owner: &Pubkey, merkle_tree: &Pubkey) -> accounts::NoteAccounts {
+    accounts::NoteAccounts {
+        owner: *owner,
+        tree_authority: pda::tree_authority(merkle_tree).0,
+        merkle_tree: *merkle_tree,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+    }
+}
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: kyc_merkle_tree::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// @dev merkle_tree must be allocated for depth and buffer size beforehand
+pub fn create_note_tree(
+    owner: &Pubkey,
+    merkle_tree: &Pubkey,
+    max_depth: u32,
+    max_buffer_size: u32
+) -> Instruction {
+    build(
+        note_accounts(owner, merkle_tree),
+        instruction::CreateNoteTree { max_depth, max_buffer_size }
+    )
+}
+
+pub fn append_note(owner: &Pubkey, merkle_tree: &Pubkey, note: String) -> Instruction {
+    build(note_accounts(owner, merkle_tree), instruction::AppendNote { note })
+}
+
+pub fn update_note(
+    owner: &Pubkey,
+    merkle_tree: &Pubkey,
+    index: u32,
+    root: [u8; 32],
+    old_note: String,
+    new_note: String
+) -> Instruction {
+    build(
+        note_accounts(owner, merkle_tree),
+        instruction::UpdateNote { index, root, old_note, new_note }
+    )
+}
@@ -0,0 +1,14 @@
+// @dev rust client for paid-ignition, paidStake, paidAmm and kycMerkleTree
+pub mod allowlist;
+pub mod amm;
+pub mod decode;
+pub mod ignition;
+pub mod kyc;
+pub mod math;
+pub mod pda;
+pub mod stake;
+
+pub use kyc_merkle_tree;
+pub use paid_amm;
+pub use paid_ignition;
+pub use paid_stake;
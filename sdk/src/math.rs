@@ -0,0 +1,52 @@
+// @dev same functions the programs run on chain
+pub use paid_ignition::{
+    calculate_claimable_amount,
+    calculate_participiant_fee,
+    fill_purchase_amount,
+    max_purchase_amount_for_early_access,
+    required_offer_amount,
+    vesting_end_time,
+};
+pub use paid_stake::constants::calculate_reward;
+
+// @dev ido token claimable by buyer at time_stamp, as claim_offer computes it
+pub fn claimable_amount(
+    pool: &paid_ignition::Pool,
+    buyer: &paid_ignition::Buyer,
+    time_stamp: i64
+) -> u64 {
+    calculate_claimable_amount(
+        buyer.total_amount,
+        buyer.cliamed_amount,
+        pool.tge_percentage,
+        pool.tge_date,
+        pool.vesting_cliff,
+        pool.vesting_frequency as u64,
+        pool.number_of_vesting as u64,
+        time_stamp
+    )
+}
+
+// @dev ido token claimable from round allocation at time_stamp, as claim_round_offer computes it
+pub fn round_claimable_amount(
+    round: &paid_ignition::Round,
+    round_buyer: &paid_ignition::RoundBuyer,
+    time_stamp: i64
+) -> u64 {
+    round.vesting.claimable_amount(round_buyer.offer_amount, round_buyer.claimed_amount, time_stamp)
+}
+
+// @dev reward claimable by staker at time_stamp, as claim computes it
+pub fn pending_reward(
+    pool: &paid_stake::states::Pool,
+    staker: &paid_stake::states::Staker,
+    time_stamp: i64
+) -> u64 {
+    calculate_reward(
+        staker.total_staked,
+        pool.reward_per_block,
+        pool.stake_decimals,
+        staker.last_update,
+        time_stamp
+    )
+}
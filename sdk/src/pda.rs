@@ -0,0 +1,197 @@
+use anchor_lang::{ prelude::Pubkey, solana_program::bpf_loader_upgradeable };
+
+// @dev program data of upgradeable program, holds its upgrade authority
+pub fn program_data(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID)
+}
+
+// @dev global config of paid-ignition
+pub fn ignition_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &paid_ignition::ID)
+}
+
+// @dev role assignment of holder in paid-ignition
+pub fn ignition_role(role: paid_ignition::Role, holder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"role".as_ref(), &[role as u8], holder.as_ref()],
+        &paid_ignition::ID
+    )
+}
+
+// @dev buyer account of signer in pool
+pub fn buyer(pool: &Pubkey, signer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"buyer", pool.as_ref(), signer.as_ref()],
+        &paid_ignition::ID
+    )
+}
+
+// @dev purchase token vault of pool
+pub fn purchase_vault(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"purchase-vault", pool.as_ref()], &paid_ignition::ID)
+}
+
+// @dev ido token vault of pool
+pub fn offer_vault(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"offer-vault", pool.as_ref()], &paid_ignition::ID)
+}
+
+// @dev tge date change history of pool
+pub fn tge_history(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"tge-history", pool.as_ref()], &paid_ignition::ID)
+}
+
+// @dev claim_offer records of wallet in pool
+pub fn claim_history(pool: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"claim-history", pool.as_ref(), wallet.as_ref()],
+        &paid_ignition::ID
+    )
+}
+
+// @dev lamport vault paying claim crankers of pool
+pub fn tip_vault(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"tip-vault", pool.as_ref()], &paid_ignition::ID)
+}
+
+// @dev timelock of lp token seeded by pool
+pub fn lp_lock(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"lp-lock", pool.as_ref()], &paid_ignition::ID)
+}
+
+// @dev lp token vault of pool, owned by lp lock
+pub fn lp_vault(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"lp-vault", pool.as_ref()], &paid_ignition::ID)
+}
+
+// @dev signer of amm deposit of pool, owns liquidity escrows
+pub fn liquidity_authority(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"liquidity-authority", pool.as_ref()], &paid_ignition::ID)
+}
+
+// @dev one-off escrow of mint side of amm deposit of pool
+pub fn liquidity_escrow(pool: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"liquidity-escrow", pool.as_ref(), mint.as_ref()],
+        &paid_ignition::ID
+    )
+}
+
+// @dev milestone plan of pool
+pub fn milestone_plan(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"milestones", pool.as_ref()], &paid_ignition::ID)
+}
+
+// @dev proposal of pool at index
+pub fn proposal(pool: &Pubkey, index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"proposal", pool.as_ref(), &index.to_le_bytes()],
+        &paid_ignition::ID
+    )
+}
+
+// @dev vote of wallet on proposal
+pub fn proposal_vote(proposal: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"proposal-vote", proposal.as_ref(), wallet.as_ref()],
+        &paid_ignition::ID
+    )
+}
+
+// @dev stake tier table of pool
+pub fn tier_table(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"tiers", pool.as_ref()], &paid_ignition::ID)
+}
+
+// @dev ido token vault shared by rounds of project
+pub fn project_offer_vault(project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"project-offer-vault", project.as_ref()], &paid_ignition::ID)
+}
+
+// @dev purchase token vault shared by rounds of project
+pub fn project_purchase_vault(project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"project-purchase-vault", project.as_ref()],
+        &paid_ignition::ID
+    )
+}
+
+// @dev allocation of wallet in round of project
+pub fn round_buyer(project: &Pubkey, round: u8, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"round-buyer", project.as_ref(), &[round], wallet.as_ref()],
+        &paid_ignition::ID
+    )
+}
+
+// @dev display metadata of pool
+pub fn pool_metadata(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"metadata", pool.as_ref()], &paid_ignition::ID)
+}
+
+// @dev tier registration of wallet in pool
+pub fn tier_registration(pool: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"tier-registration", pool.as_ref(), wallet.as_ref()],
+        &paid_ignition::ID
+    )
+}
+
+// @dev global config of paidStake
+pub fn stake_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &paid_stake::ID)
+}
+
+// @dev role assignment of holder in paidStake
+pub fn stake_role(role: paid_stake::states::Role, holder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"role".as_ref(), &[role as u8], holder.as_ref()],
+        &paid_stake::ID
+    )
+}
+
+// @dev staker account of signer in pool
+pub fn staker(pool: &Pubkey, signer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[pool.as_ref(), signer.as_ref()], &paid_stake::ID)
+}
+
+// @dev stake balance history of signer in pool
+pub fn stake_checkpoints(pool: &Pubkey, signer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"checkpoints", pool.as_ref(), signer.as_ref()],
+        &paid_stake::ID
+    )
+}
+
+// @dev stake token vault of pool
+pub fn stake_vault(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"stake-vault", pool.as_ref()], &paid_stake::ID)
+}
+
+// @dev reward token pot of pool
+pub fn reward_pot(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"reward-pot", pool.as_ref()], &paid_stake::ID)
+}
+
+// @dev signing authority of kyc merkle tree
+pub fn tree_authority(merkle_tree: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &kyc_merkle_tree::ID)
+}
+
+// @dev amm pool of ordered mint pair
+pub fn amm_pool(mint_a: &Pubkey, mint_b: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"amm-pool", mint_a.as_ref(), mint_b.as_ref()],
+        &paid_amm::ID
+    )
+}
+
+// @dev reserve of mint in amm pool
+pub fn amm_vault(amm_pool: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault", amm_pool.as_ref(), mint.as_ref()], &paid_amm::ID)
+}
+
+// @dev lp token mint of amm pool
+pub fn lp_mint(amm_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"lp-mint", amm_pool.as_ref()], &paid_amm::ID)
+}
@@ -0,0 +1,297 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{ instruction::Instruction, system_program },
+    InstructionData,
+    ToAccountMetas,
+};
+use anchor_spl::associated_token::{ self, get_associated_token_address };
+use paid_stake::{ accounts, instruction, states::Role };
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: paid_stake::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// @dev pool is a fresh keypair and must sign as well
+pub fn init_pool(
+    owner: &Pubkey,
+    pool: &Pubkey,
+    reward_mint: &Pubkey,
+    stake_mint: &Pubkey,
+    reward_decimals: u8,
+    farm_decimals: u8,
+    reward_per_block: u64
+) -> Instruction {
+    build(
+        accounts::InitPool {
+            owner: *owner,
+            owner_role: pda::stake_role(Role::PoolOperator, owner).0,
+            reward_mint: *reward_mint,
+            stake_mint: *stake_mint,
+            pool: *pool,
+            system_program: system_program::ID,
+        },
+        instruction::InitPool { reward_decimals, farm_decimals, reward_per_block }
+    )
+}
+
+pub fn fund_reward(
+    signer: &Pubkey,
+    pool: &Pubkey,
+    reward_mint: &Pubkey,
+    owner_token: &Pubkey,
+    amount: u64
+) -> Instruction {
+    let (reward_pot, pot_bump) = pda::reward_pot(pool);
+    build(
+        accounts::FundReward {
+            signer: *signer,
+            funder_role: pda::stake_role(Role::Funder, signer).0,
+            reward_mint: *reward_mint,
+            owner_token: *owner_token,
+            config: pda::stake_config().0,
+            pool: *pool,
+            reward_pot,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitReward { amount, pot_bump }
+    )
+}
+
+pub fn stake(
+    signer: &Pubkey,
+    pool: &Pubkey,
+    stake_mint: &Pubkey,
+    user_token: &Pubkey,
+    amount: u64
+) -> Instruction {
+    let (stake_vault, bump) = pda::stake_vault(pool);
+    build(
+        accounts::Stake {
+            signer: *signer,
+            stake_mint: *stake_mint,
+            config: pda::stake_config().0,
+            pool: *pool,
+            staker: pda::staker(pool, signer).0,
+            checkpoints: pda::stake_checkpoints(pool, signer).0,
+            user_token: *user_token,
+            stake_vault,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::Stake { amount, bump }
+    )
+}
+
+// @dev reward lands in associated token account of signer
+pub fn claim(signer: &Pubkey, pool: &Pubkey, reward_mint: &Pubkey) -> Instruction {
+    build(
+        accounts::Claim {
+            signer: *signer,
+            reward_mint: *reward_mint,
+            user_reward_token: get_associated_token_address(signer, reward_mint),
+            staker: pda::staker(pool, signer).0,
+            config: pda::stake_config().0,
+            pool: *pool,
+            reward_pot: pda::reward_pot(pool).0,
+            associated_token_program: associated_token::ID,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::Claim {}
+    )
+}
+
+// @dev stake token returns to associated token account of signer
+pub fn withdraw(signer: &Pubkey, pool: &Pubkey, stake_mint: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::Withdraw {
+            signer: *signer,
+            config: pda::stake_config().0,
+            pool: *pool,
+            staker: pda::staker(pool, signer).0,
+            checkpoints: pda::stake_checkpoints(pool, signer).0,
+            stake_mint: *stake_mint,
+            stake_vault: pda::stake_vault(pool).0,
+            user_stake_token: get_associated_token_address(signer, stake_mint),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::Withdraw { amount }
+    )
+}
+
+// @dev admin must be the upgrade authority of program
+pub fn init_config(admin: &Pubkey) -> Instruction {
+    build(
+        accounts::InitConfig {
+            admin: *admin,
+            program_data: pda::program_data(&paid_stake::ID).0,
+            config: pda::stake_config().0,
+            admin_role: pda::stake_role(Role::SuperAdmin, admin).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitConfig {}
+    )
+}
+
+pub fn grant_role(admin: &Pubkey, role: Role, holder: &Pubkey) -> Instruction {
+    build(
+        accounts::GrantRole {
+            admin: *admin,
+            admin_role: pda::stake_role(Role::SuperAdmin, admin).0,
+            role_assignment: pda::stake_role(role, holder).0,
+            system_program: system_program::ID,
+        },
+        instruction::GrantRole { role, holder: *holder }
+    )
+}
+
+pub fn revoke_role(admin: &Pubkey, role: Role, holder: &Pubkey) -> Instruction {
+    build(
+        accounts::RevokeRole {
+            admin: *admin,
+            admin_role: pda::stake_role(Role::SuperAdmin, admin).0,
+            role_assignment: pda::stake_role(role, holder).0,
+        },
+        instruction::RevokeRole { role, holder: *holder }
+    )
+}
+
+pub fn set_guardian(admin: &Pubkey, guardian: &Pubkey) -> Instruction {
+    build(
+        accounts::SetGuardian {
+            admin: *admin,
+            admin_role: pda::stake_role(Role::SuperAdmin, admin).0,
+            config: pda::stake_config().0,
+        },
+        instruction::SetGuardian { guardian: *guardian }
+    )
+}
+
+// @dev signed by pauser role holder
+pub fn set_paused(pauser: &Pubkey, paused: bool) -> Instruction {
+    build(
+        accounts::SetPaused {
+            pauser: *pauser,
+            pauser_role: Some(pda::stake_role(Role::Pauser, pauser).0),
+            config: pda::stake_config().0,
+        },
+        instruction::SetPaused { paused }
+    )
+}
+
+pub fn set_pool_paused(pauser: &Pubkey, pool: &Pubkey, paused: bool) -> Instruction {
+    build(
+        accounts::SetPoolPaused {
+            pauser: *pauser,
+            pauser_role: Some(pda::stake_role(Role::Pauser, pauser).0),
+            config: pda::stake_config().0,
+            pool: *pool,
+        },
+        instruction::SetPoolPaused { paused }
+    )
+}
+
+pub fn propose_owner(owner: &Pubkey, pool: &Pubkey, pending_owner: &Pubkey) -> Instruction {
+    build(
+        accounts::ProposeOwner { owner: *owner, pool: *pool },
+        instruction::ProposeOwner { pending_owner: *pending_owner }
+    )
+}
+
+pub fn accept_owner(pending_owner: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptOwner { pending_owner: *pending_owner, pool: *pool },
+        instruction::AcceptOwner {}
+    )
+}
+
+pub fn cancel_owner_transfer(owner: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelOwnerTransfer { owner: *owner, pool: *pool },
+        instruction::CancelOwnerTransfer {}
+    )
+}
+
+pub fn close_staker(signer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseStaker {
+            signer: *signer,
+            pool: *pool,
+            staker: pda::staker(pool, signer).0,
+        },
+        instruction::CloseStaker {}
+    )
+}
+
+// @dev staker account of signer must be closed first
+pub fn close_checkpoints(signer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseCheckpoints {
+            signer: *signer,
+            pool: *pool,
+            staker: pda::staker(pool, signer).0,
+            checkpoints: pda::stake_checkpoints(pool, signer).0,
+        },
+        instruction::CloseCheckpoints {}
+    )
+}
+
+// @dev rent_payer must match pool.stake_vault_payer
+pub fn close_stake_vault(rent_payer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseStakeVault {
+            rent_payer: *rent_payer,
+            pool: *pool,
+            stake_vault: pda::stake_vault(pool).0,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CloseStakeVault {}
+    )
+}
+
+// @dev rent_payer must match pool.reward_pot_payer
+pub fn close_reward_pot(rent_payer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseRewardPot {
+            rent_payer: *rent_payer,
+            pool: *pool,
+            reward_pot: pda::reward_pot(pool).0,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CloseRewardPot {}
+    )
+}
+
+// @dev rent_payer must match pool.rent_payer
+pub fn close_pool(owner: &Pubkey, rent_payer: &Pubkey, pool: &Pubkey) -> Instruction {
+    build(
+        accounts::ClosePool {
+            owner: *owner,
+            rent_payer: *rent_payer,
+            pool: *pool,
+            stake_vault: pda::stake_vault(pool).0,
+            reward_pot: pda::reward_pot(pool).0,
+        },
+        instruction::ClosePool {}
+    )
+}
+
+// @dev simulate and decode return data as u64
+pub fn pending_rewards(pool: &Pubkey, wallet: &Pubkey) -> Instruction {
+    build(
+        accounts::PendingRewards {
+            wallet: *wallet,
+            pool: *pool,
+            staker: pda::staker(pool, wallet).0,
+        },
+        instruction::PendingRewards {}
+    )
+}